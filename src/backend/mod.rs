@@ -45,6 +45,9 @@ pub trait QueryBuilder {
     
     /// Translate [`SelectDistinct`] into SQL statement.
     fn prepare_select_distinct(&self, select_distinct: &SelectDistinct, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate `DISTINCT ON` expressions into SQL statement.
+    fn prepare_select_distinct_on(&self, exprs: &[SimpleExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
     
     /// Translate [`SelectExpr`] into SQL statement.
     fn prepare_select_expr(&self, select_expr: &SelectExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
@@ -57,6 +60,9 @@ pub trait QueryBuilder {
     
     /// Translate [`UnOper`] into SQL statement.
     fn prepare_un_oper(&self, un_oper: &UnOper, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate [`SubQueryOper`] into SQL statement.
+    fn prepare_sub_query_oper(&self, oper: &SubQueryOper, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
     
     /// Translate [`BinOper`] into SQL statement.
     fn prepare_bin_oper(&self, bin_oper: &BinOper, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
@@ -64,6 +70,12 @@ pub trait QueryBuilder {
     /// Translate [`LogicalChainOper`] into SQL statement.
     fn prepare_logical_chain_oper(&self, log_chain_oper: &LogicalChainOper, i: usize, length: usize, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
     
+    /// Translate [`ConditionHolder`] into SQL statement, prefixed by `keyword` unless there is nothing to filter.
+    fn prepare_condition(&self, condition: &ConditionHolder, keyword: &str, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate [`Condition`] into SQL expression.
+    fn prepare_condition_where(&self, condition: &Condition, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+    
     /// Translate [`Function`] into SQL statement.
     fn prepare_function(&self, function: &Function, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
     
@@ -72,9 +84,18 @@ pub trait QueryBuilder {
     
     /// Translate [`OrderExpr`] into SQL statement.
     fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate [`GroupingSet`] into SQL statement.
+    fn prepare_grouping_set(&self, grouping_set: &GroupingSet, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
     
     /// Translate [`JoinOn`] into SQL statement.
     fn prepare_join_on(&self, join_on: &JoinOn, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate [`LockClause`] into SQL statement.
+    fn prepare_select_lock(&self, lock: &LockClause, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate the `RETURNING` expressions of insert, update and delete statements into SQL statement.
+    fn prepare_returning(&self, returning: &[SelectExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
     
     /// Translate [`Order`] into SQL statement.
     fn prepare_order(&self, order: &Order, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
@@ -85,6 +106,51 @@ pub trait QueryBuilder {
     /// Translate [`Keyword`] into SQL statement.
     fn prepare_keyword(&self, keyword: &Keyword, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
 
+    /// Translate [`CaseStatement`] into SQL expression.
+    fn prepare_case_statement(&self, case: &CaseStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value));
+
+    /// Translate [`ColumnType`] into the type name of a `CAST` expression.
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut SqlWriter);
+
+    /// Substitute the numbered placeholders of a custom expression, also within square brackets as in `ARRAY[$1, $2]`
+    fn prepare_custom_with_exprs(&self, template: &str, exprs: &[SimpleExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let tokens: Vec<Token> = Tokenizer::new(template).iter().collect();
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                Token::Punctuation(mark) if mark == "$" => {
+                    if let Some(Token::Unquoted(next)) = tokens.get(i + 1) {
+                        if let Ok(num) = next.parse::<usize>() {
+                            let expr = exprs
+                                .get(num.wrapping_sub(1))
+                                .unwrap_or_else(|| panic!("Custom expression has no expression for placeholder ${}", num));
+                            if expr.is_binary() || matches!(expr, SimpleExpr::Unary(_, _)) {
+                                write!(sql, "(").unwrap();
+                                self.prepare_simple_expr(expr, sql, collector);
+                                write!(sql, ")").unwrap();
+                            } else {
+                                self.prepare_simple_expr(expr, sql, collector);
+                            }
+                            i += 2;
+                            continue;
+                        }
+                    }
+                    write!(sql, "{}", mark).unwrap();
+                },
+                Token::Quoted(string) if string.starts_with('[') && string.ends_with(']') => {
+                    write!(sql, "[").unwrap();
+                    self.prepare_custom_with_exprs(&string[1..string.len() - 1], exprs, sql, collector);
+                    write!(sql, "]").unwrap();
+                },
+                tok => write!(sql, "{}", tok).unwrap(),
+            }
+            i += 1;
+        }
+    }
+
+    /// The placeholder sign of query parameters, and whether it is followed by the parameter number
+    fn placeholder(&self) -> (&str, bool);
+
     /// Convert a SQL value into syntax-specific string
     fn value_to_string(&self, v: &Value) -> String;
 }
@@ -121,6 +187,9 @@ pub trait TableBuilder {
     /// Translate [`TableAlterStatement`] into SQL statement.
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter);
 
+    /// Split [`TableAlterStatement`] into the statements the backend can execute one by one.
+    fn split_table_alter_statement(&self, alter: &TableAlterStatement) -> Vec<TableAlterStatement>;
+
     /// Translate [`TableRenameStatement`] into SQL statement.
     fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter);
 }
@@ -0,0 +1,153 @@
+use super::*;
+use crate::discovery::*;
+
+impl DiscoveryBuilder for MysqlQueryBuilder {
+    fn query_tables(&self, schema: &str) -> SelectStatement {
+        Query::select()
+            .column(InformationSchema::TableName)
+            .from((InformationSchema::Schema, InformationSchema::Tables))
+            .and_where(Expr::col(InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::col(InformationSchema::TableType).eq("BASE TABLE"))
+            .order_by(InformationSchema::TableName, Order::Asc)
+            .take()
+    }
+
+    fn query_table_exists(&self, table: &str) -> SelectStatement {
+        Query::select()
+            .column(InformationSchema::TableName)
+            .from((InformationSchema::Schema, InformationSchema::Tables))
+            .and_where(Expr::col(InformationSchema::TableSchema).into_simple_expr().binary(BinOper::Equal, Expr::cust("DATABASE()")))
+            .and_where(Expr::col(InformationSchema::TableName).eq(table))
+            .take()
+    }
+
+    fn query_columns(&self, schema: &str, table: &str) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                InformationSchema::ColumnName,
+                InformationSchema::ColumnType,
+                InformationSchema::IsNullable,
+                InformationSchema::ColumnDefault,
+                InformationSchema::Extra,
+            ])
+            .from((InformationSchema::Schema, InformationSchema::Columns))
+            .and_where(Expr::col(InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::col(InformationSchema::TableName).eq(table))
+            .order_by(InformationSchema::OrdinalPosition, Order::Asc)
+            .take()
+    }
+
+    fn query_indexes(&self, schema: &str, table: &str) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                InformationSchema::IndexName,
+                InformationSchema::NonUnique,
+                InformationSchema::ColumnName,
+                InformationSchema::SubPart,
+                InformationSchema::IndexType,
+            ])
+            .from((InformationSchema::Schema, InformationSchema::Statistics))
+            .and_where(Expr::col(InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::col(InformationSchema::TableName).eq(table))
+            .order_by(InformationSchema::IndexName, Order::Asc)
+            .order_by(InformationSchema::SeqInIndex, Order::Asc)
+            .take()
+    }
+
+    fn query_foreign_keys(&self, schema: &str, table: &str) -> SelectStatement {
+        let kcu = Alias::new("kcu");
+        let rc = Alias::new("rc");
+        Query::select()
+            .column((kcu.clone(), InformationSchema::ConstraintName))
+            .column((kcu.clone(), InformationSchema::ColumnName))
+            .column((kcu.clone(), InformationSchema::ReferencedTableName))
+            .column((kcu.clone(), InformationSchema::ReferencedColumnName))
+            .column((rc.clone(), InformationSchema::UpdateRule))
+            .column((rc.clone(), InformationSchema::DeleteRule))
+            .from_as((InformationSchema::Schema, InformationSchema::KeyColumnUsage), kcu.clone())
+            .join_as(
+                JoinType::Join,
+                (InformationSchema::Schema, InformationSchema::ReferentialConstraints),
+                rc.clone(),
+                Expr::tbl(rc.clone(), InformationSchema::ConstraintSchema).equals(kcu.clone(), InformationSchema::ConstraintSchema)
+                    .and(Expr::tbl(rc.clone(), InformationSchema::ConstraintName).equals(kcu.clone(), InformationSchema::ConstraintName))
+            )
+            .and_where(Expr::tbl(kcu.clone(), InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::tbl(kcu.clone(), InformationSchema::TableName).eq(table))
+            .order_by((kcu.clone(), InformationSchema::ConstraintName), Order::Asc)
+            .order_by((kcu, InformationSchema::OrdinalPosition), Order::Asc)
+            .take()
+    }
+
+    fn parse_table(&self, table: &str, columns: &[Vec<Value>], indexes: &[Vec<Value>], foreign_keys: &[Vec<Value>]) -> TableDef {
+        let mut table_def = TableDef::new(Alias::new(table));
+
+        for row in columns.iter() {
+            let name = row_string(row, 0).unwrap_or_default();
+            let mut column = ColumnDef::new(Alias::new(&name));
+            column.types = row_string(row, 1).map(|column_type| parse_column_type(&column_type));
+            if !row_bool(row, 2) {
+                column.spec.push(ColumnSpec::NotNull);
+            }
+            let extra = row_string(row, 4).unwrap_or_default().to_lowercase();
+            if let Some(default) = row_string(row, 3) {
+                column.spec.push(if extra.contains("default_generated") {
+                    if default.to_lowercase().starts_with("current_timestamp") {
+                        ColumnSpec::Extra(format!("DEFAULT {}", default))
+                    } else {
+                        ColumnSpec::Extra(format!("DEFAULT ({})", default))
+                    }
+                } else if is_string_type(column.types.as_ref()) {
+                    // MySQL reports string defaults without quotes, so `123` on a varchar is still a string
+                    ColumnSpec::Default(default.into())
+                } else if let ColumnSpec::Default(value) = parse_default(&default) {
+                    ColumnSpec::Default(value)
+                } else {
+                    ColumnSpec::Default(default.into())
+                });
+            }
+            if extra.contains("auto_increment") {
+                column.spec.push(ColumnSpec::AutoIncrement);
+            }
+            if let Some(pos) = extra.find("on update ") {
+                column.spec.push(ColumnSpec::Extra(extra[pos..].to_uppercase()));
+            }
+            table_def.columns.push(column);
+        }
+
+        for (name, rows) in group_rows(indexes, 0) {
+            let columns = rows.iter()
+                .filter_map(|row| row_string(row, 2).map(|column| index_column(&column, row_u32(row, 3))))
+                .collect();
+            if name.as_deref() == Some("PRIMARY") {
+                let mut index = index_create(table, None, columns);
+                index.primary = true;
+                table_def.primary_key = Some(index);
+                continue;
+            }
+            let mut index = index_create(table, name, columns);
+            index.unique = !row_bool(rows[0], 1);
+            index.index_type = match row_string(rows[0], 4).unwrap_or_default().to_uppercase().as_str() {
+                "FULLTEXT" => Some(IndexType::FullText),
+                "HASH" => Some(IndexType::Hash),
+                _ => None,
+            };
+            table_def.indexes.push(index);
+        }
+
+        table_def.foreign_keys = parse_foreign_keys(table, foreign_keys);
+        table_def
+    }
+}
+
+/// Whether MySQL reports the defaults of this column type as unquoted strings
+fn is_string_type(column_type: Option<&ColumnType>) -> bool {
+    match column_type {
+        Some(ColumnType::Char(_)) | Some(ColumnType::String(_)) | Some(ColumnType::Text) => true,
+        Some(ColumnType::Custom(iden)) => {
+            let name = iden.to_string().to_lowercase();
+            ["enum", "set", "tinytext", "mediumtext", "longtext"].iter().any(|prefix| name.starts_with(prefix))
+        },
+        _ => false,
+    }
+}
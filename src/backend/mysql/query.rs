@@ -42,11 +42,15 @@ impl QueryBuilder for MysqlQueryBuilder {
         write!(sql, "SELECT ").unwrap();
 
         if let Some(distinct) = &select.distinct {
-            write!(sql, " ").unwrap();
             self.prepare_select_distinct(distinct, sql, collector);
             write!(sql, " ").unwrap();
         }
 
+        if !select.distinct_on.is_empty() {
+            self.prepare_select_distinct_on(&select.distinct_on, sql, collector);
+            write!(sql, " ").unwrap();
+        }
+
         select.selects.iter().fold(true, |first, expr| {
             if !first {
                 write!(sql, ", ").unwrap()
@@ -67,30 +71,31 @@ impl QueryBuilder for MysqlQueryBuilder {
             }
         }
 
-        if !select.wherei.is_empty() {
-            write!(sql, " WHERE ").unwrap();
-        }
-        for (i, log_chain_oper) in select.wherei.iter().enumerate() {
-            self.prepare_logical_chain_oper(log_chain_oper, i, select.wherei.len(), sql, collector);
+        self.prepare_condition(&select.wherei, "WHERE", sql, collector);
+
+        if !select.grouping_sets.is_empty() && (!select.groups.is_empty() || select.grouping_sets.len() > 1) {
+            panic!("Mysql only supports a single ROLLUP as the whole GROUP BY clause");
         }
 
-        if !select.groups.is_empty() {
+        if !select.groups.is_empty() || !select.grouping_sets.is_empty() {
             write!(sql, " GROUP BY ").unwrap();
-            select.groups.iter().fold(true, |first, expr| {
+            let first = select.groups.iter().fold(true, |first, expr| {
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
                 self.prepare_simple_expr(expr, sql, collector);
                 false
             });
+            select.grouping_sets.iter().fold(first, |first, grouping_set| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_grouping_set(grouping_set, sql, collector);
+                false
+            });
         }
 
-        if !select.having.is_empty() {
-            write!(sql, " HAVING ").unwrap();
-        }
-        for (i, log_chain_oper) in select.having.iter().enumerate() {
-            self.prepare_logical_chain_oper(log_chain_oper, i, select.having.len(), sql, collector);
-        }
+        self.prepare_condition(&select.having, "HAVING", sql, collector);
 
         if !select.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -112,6 +117,11 @@ impl QueryBuilder for MysqlQueryBuilder {
             write!(sql, " OFFSET ").unwrap();
             self.prepare_value(offset, sql, collector);
         }
+
+        if let Some(lock) = &select.lock {
+            write!(sql, " ").unwrap();
+            self.prepare_select_lock(lock, sql, collector);
+        }
     }
 
     fn prepare_update_statement(&self, update: &UpdateStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
@@ -121,6 +131,11 @@ impl QueryBuilder for MysqlQueryBuilder {
             self.prepare_table_ref(table, sql, collector);
         }
 
+        for expr in update.join.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_join_expr(expr, sql, collector);
+        }
+
         write!(sql, " SET ").unwrap();
 
         update.values.iter().fold(true, |first, row| {
@@ -128,15 +143,19 @@ impl QueryBuilder for MysqlQueryBuilder {
                 write!(sql, ", ").unwrap()
             }
             let (k, v) = row;
+            // Columns of a multiple-table update may be ambiguous
+            if let (Some(table), false) = (&update.table, update.join.is_empty()) {
+                self.prepare_table_qualifier(table, sql);
+                write!(sql, ".").unwrap();
+            }
             write!(sql, "`{}` = ", k).unwrap();
             self.prepare_simple_expr(v, sql, collector);
             false
         });
 
-        if let Some(wherei) = &update.wherei {
-            write!(sql, " WHERE ").unwrap();
-            self.prepare_simple_expr(wherei, sql, collector);
-        }
+        self.prepare_condition(&update.wherei, "WHERE", sql, collector);
+
+        self.prepare_returning(&update.returning, sql, collector);
 
         if !update.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -157,17 +176,25 @@ impl QueryBuilder for MysqlQueryBuilder {
 
     fn prepare_delete_statement(&self, delete: &DeleteStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         write!(sql, "DELETE ").unwrap();
-        
+
         if let Some(table) = &delete.table {
+            if !delete.join.is_empty() {
+                self.prepare_table_qualifier(table, sql);
+                write!(sql, " ").unwrap();
+            }
             write!(sql, "FROM ").unwrap();
             self.prepare_table_ref(table, sql, collector);
         }
 
-        if let Some(wherei) = &delete.wherei {
-            write!(sql, " WHERE ").unwrap();
-            self.prepare_simple_expr(wherei, sql, collector);
+        for expr in delete.join.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_join_expr(expr, sql, collector);
         }
 
+        self.prepare_condition(&delete.wherei, "WHERE", sql, collector);
+
+        self.prepare_returning(&delete.returning, sql, collector);
+
         if !delete.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
             delete.orders.iter().fold(true, |first, expr| {
@@ -200,30 +227,46 @@ impl QueryBuilder for MysqlQueryBuilder {
             SimpleExpr::Unary(op, expr) => {
                 self.prepare_un_oper(op, sql, collector);
                 write!(sql, " ").unwrap();
+                let paren = expr.need_parentheses_under(op);
+                if paren {
+                    write!(sql, "(").unwrap();
+                }
                 self.prepare_simple_expr(expr, sql, collector);
+                if paren {
+                    write!(sql, ")").unwrap();
+                }
             },
-            SimpleExpr::FunctionCall(func, exprs) => {
-                self.prepare_function(func, sql, collector);
-                write!(sql, "(").unwrap();
-                exprs.iter().fold(true, |first, expr| {
-                    if !first {
-                        write!(sql, ", ").unwrap();
-                    }
-                    self.prepare_simple_expr(expr, sql, collector);
-                    false
-                });
-                write!(sql, ")").unwrap();
+            SimpleExpr::FunctionCall(Function::MatchRank, exprs, _) => {
+                self.prepare_match_against(&exprs[0], &BinOper::Matches, &exprs[1], sql, collector);
+            },
+            SimpleExpr::FunctionCall(func, exprs, modifiers) => {
+                self.prepare_function_call(func, exprs, modifiers, sql, collector);
             },
             SimpleExpr::Binary(left, op, right) => {
-                if *op == BinOper::In && right.is_values() && right.get_values().is_empty() {
+                if *op == BinOper::In && right.is_empty_list() {
                     self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(2.into()), sql, collector);
-                } else if *op == BinOper::NotIn && right.is_values() && right.get_values().is_empty() {
+                } else if *op == BinOper::NotIn && right.is_empty_list() {
                     self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(1.into()), sql, collector);
+                } else if matches!(op, BinOper::Matches | BinOper::MatchesBoolean) {
+                    self.prepare_match_against(left, op, right, sql, collector);
+                } else if matches!(op, BinOper::ILike | BinOper::NotILike) {
+                    self.prepare_ilike(left, op, right, sql, collector);
+                } else if *op == BinOper::Concat {
+                    self.prepare_concat(left, right, sql, collector);
+                } else if *op == BinOper::IsDistinctFrom {
+                    write!(sql, "NOT (").unwrap();
+                    self.binary_expr(left, &BinOper::IsNotDistinctFrom, right, sql, collector);
+                    write!(sql, ")").unwrap();
+                } else if SimpleExpr::is_json_oper(op) {
+                    self.prepare_json_expr(left, op, right, sql, collector);
                 } else {
                     self.binary_expr(left, op, right, sql, collector);
                 }
             },
-            SimpleExpr::SubQuery(sel) => {
+            SimpleExpr::SubQuery(oper, sel) => {
+                if let Some(oper) = oper {
+                    self.prepare_sub_query_oper(oper, sql, collector);
+                }
                 write!(sql, "(").unwrap();
                 self.prepare_select_statement(sel, sql, collector);
                 write!(sql, ")").unwrap();
@@ -231,6 +274,17 @@ impl QueryBuilder for MysqlQueryBuilder {
             SimpleExpr::Value(val) => {
                 self.prepare_value(val, sql, collector);
             },
+            SimpleExpr::Tuple(exprs) => {
+                write!(sql, "(").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            },
             SimpleExpr::Values(list) => {
                 write!(sql, "(").unwrap();
                 list.iter().fold(true, |first, val| {
@@ -262,9 +316,22 @@ impl QueryBuilder for MysqlQueryBuilder {
                     }
                 }
             },
+            SimpleExpr::CustomWithExprs(expr, exprs) => {
+                self.prepare_custom_with_exprs(expr, exprs, sql, collector);
+            },
             SimpleExpr::Keyword(keyword) => {
                 self.prepare_keyword(keyword, sql, collector);
             },
+            SimpleExpr::Case(case) => {
+                self.prepare_case_statement(case, sql, collector);
+            },
+            SimpleExpr::Cast(expr, column_type) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_simple_expr(expr, sql, collector);
+                write!(sql, " AS ").unwrap();
+                self.prepare_cast_type(column_type, sql);
+                write!(sql, ")").unwrap();
+            },
         }
     }
 
@@ -276,6 +343,10 @@ impl QueryBuilder for MysqlQueryBuilder {
         }).unwrap();
     }
 
+    fn prepare_select_distinct_on(&self, _exprs: &[SimpleExpr], _sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        panic!("Mysql does not support DISTINCT ON");
+    }
+
     fn prepare_select_expr(&self, select_expr: &SelectExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         self.prepare_simple_expr(&select_expr.expr, sql, collector);
         match &select_expr.alias {
@@ -288,11 +359,16 @@ impl QueryBuilder for MysqlQueryBuilder {
     }
 
     fn prepare_join_expr(&self, join_expr: &JoinExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if join_expr.natural {
+            write!(sql, "NATURAL ").unwrap();
+        }
         self.prepare_join_type(&join_expr.join, sql, collector);
         write!(sql, " ").unwrap();
+        if join_expr.lateral {
+            write!(sql, "LATERAL ").unwrap();
+        }
         self.prepare_table_ref(&join_expr.table, sql, collector);
         if let Some(on) = &join_expr.on {
-            write!(sql, " ").unwrap();
             self.prepare_join_on(on, sql, collector);
         }
     }
@@ -332,6 +408,16 @@ impl QueryBuilder for MysqlQueryBuilder {
     fn prepare_un_oper(&self, un_oper: &UnOper, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         write!(sql, "{}", match un_oper {
             UnOper::Not => "NOT",
+            UnOper::Neg => "-",
+        }).unwrap();
+    }
+
+    fn prepare_sub_query_oper(&self, oper: &SubQueryOper, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        write!(sql, "{}", match oper {
+            SubQueryOper::Exists => "EXISTS",
+            SubQueryOper::Any => "ANY",
+            SubQueryOper::Some => "SOME",
+            SubQueryOper::All => "ALL",
         }).unwrap();
     }
 
@@ -357,6 +443,27 @@ impl QueryBuilder for MysqlQueryBuilder {
             BinOper::Sub => "-",
             BinOper::Mul => "*",
             BinOper::Div => "/",
+            BinOper::Mod => "%",
+            BinOper::BitAnd => "&",
+            BinOper::BitOr => "|",
+            BinOper::LShift => "<<",
+            BinOper::RShift => ">>",
+            BinOper::ILike => "LIKE",
+            BinOper::NotILike => "NOT LIKE",
+            BinOper::Escape => "ESCAPE",
+            BinOper::SimilarTo | BinOper::NotSimilarTo => panic!("Mysql does not support SIMILAR TO"),
+            BinOper::Regexp => "REGEXP",
+            BinOper::NotRegexp => "NOT REGEXP",
+            BinOper::IsNotDistinctFrom => "<=>",
+            BinOper::Concat | BinOper::IsDistinctFrom => {
+                unreachable!("Concatenation and IS DISTINCT FROM are expressed by CONCAT and NOT <=> in Mysql")
+            },
+            BinOper::JsonGetText | BinOper::JsonGetPathText => "->>",
+            BinOper::JsonGet | BinOper::JsonGetPath | BinOper::JsonContains | BinOper::JsonContainedBy |
+            BinOper::JsonHasKey | BinOper::JsonHasAnyKey | BinOper::JsonHasAllKeys => {
+                unreachable!("JSON operators are expressed by functions in Mysql")
+            },
+            BinOper::Matches | BinOper::MatchesBoolean => "AGAINST",
         }).unwrap();
     }
 
@@ -382,6 +489,55 @@ impl QueryBuilder for MysqlQueryBuilder {
         }
     }
 
+    fn prepare_condition(&self, condition: &ConditionHolder, keyword: &str, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if condition.is_empty() {
+            return;
+        }
+        write!(sql, " {} ", keyword).unwrap();
+        match &condition.contents {
+            ConditionHolderContents::Empty => {},
+            ConditionHolderContents::Condition(c) => {
+                self.prepare_condition_where(c, sql, collector);
+            },
+        }
+    }
+
+    fn prepare_condition_where(&self, condition: &Condition, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if condition.negate {
+            write!(sql, "NOT (").unwrap();
+        }
+        if condition.is_empty() {
+            let always = match condition.condition_type {
+                ConditionType::All => 1,
+                ConditionType::Any => 2,
+            };
+            self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(always.into()), sql, collector);
+        }
+        condition.conditions.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, " {} ", match condition.condition_type {
+                    ConditionType::All => "AND",
+                    ConditionType::Any => "OR",
+                }).unwrap();
+            }
+            let need_parentheses = condition.len() > 1 && expr.need_parentheses(condition.condition_type);
+            if need_parentheses {
+                write!(sql, "(").unwrap();
+            }
+            match expr {
+                ConditionExpression::Condition(c) => self.prepare_condition_where(c, sql, collector),
+                ConditionExpression::SimpleExpr(e) => self.prepare_simple_expr(e, sql, collector),
+            }
+            if need_parentheses {
+                write!(sql, ")").unwrap();
+            }
+            false
+        });
+        if condition.negate {
+            write!(sql, ")").unwrap();
+        }
+    }
+
     fn prepare_function(&self, function: &Function, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         if let Function::Custom(iden) = function {
             iden.unquoted(sql);
@@ -394,6 +550,29 @@ impl QueryBuilder for MysqlQueryBuilder {
                 Function::Count => "COUNT",
                 Function::IfNull => "IFNULL",
                 Function::CharLength => "CHAR_LENGTH",
+                Function::Grouping => "GROUPING",
+                Function::JsonBuildObject => "JSON_OBJECT",
+                Function::JsonAgg => "JSON_ARRAYAGG",
+                Function::JsonbSet => panic!("Mysql does not support jsonb_set function"),
+                Function::StringAgg => "GROUP_CONCAT",
+                Function::ArrayAgg => panic!("Mysql does not support arrays, use json_agg instead"),
+                Function::MatchRank => "MATCH",
+                Function::Coalesce => "COALESCE",
+                Function::Lower => "LOWER",
+                Function::Upper => "UPPER",
+                Function::Trim => "TRIM",
+                Function::Substring => "SUBSTRING",
+                Function::Concat => "CONCAT",
+                Function::Replace => "REPLACE",
+                Function::Abs => "ABS",
+                Function::Round => "ROUND",
+                Function::Greatest => "GREATEST",
+                Function::Least => "LEAST",
+                Function::Now => "NOW",
+                Function::CurrentDate => "CURRENT_DATE",
+                Function::DateTrunc(_) => "DATE_FORMAT",
+                Function::Extract(_) => "EXTRACT",
+                Function::Random => "RAND",
                 Function::Custom(_) => "",
             }).unwrap();
         }
@@ -405,22 +584,106 @@ impl QueryBuilder for MysqlQueryBuilder {
             JoinType::InnerJoin => "INNER JOIN",
             JoinType::LeftJoin => "LEFT JOIN",
             JoinType::RightJoin => "RIGHT JOIN",
+            JoinType::FullOuterJoin => panic!("Mysql does not support FULL OUTER JOIN"),
+            JoinType::CrossJoin => "CROSS JOIN",
         }).unwrap()
     }
 
     fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        self.prepare_simple_expr(&order_expr.expr, sql, collector);
+        if let Some(nulls) = order_expr.nulls {
+            write!(sql, "ISNULL(").unwrap();
+            self.prepare_simple_expr(&order_expr.expr, sql, collector);
+            write!(sql, ") {}, ", match nulls {
+                NullOrdering::First => "DESC",
+                NullOrdering::Last => "ASC",
+            }).unwrap();
+        }
+        if let Some(values) = &order_expr.field {
+            write!(sql, "FIELD(").unwrap();
+            self.prepare_collated_expr(order_expr, sql, collector);
+            for value in values.iter() {
+                write!(sql, ", ").unwrap();
+                self.prepare_value(value, sql, collector);
+            }
+            write!(sql, ")").unwrap();
+        } else {
+            self.prepare_collated_expr(order_expr, sql, collector);
+        }
         write!(sql, " ").unwrap();
         self.prepare_order(&order_expr.order, sql, collector);
     }
 
+    fn prepare_grouping_set(&self, grouping_set: &GroupingSet, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match grouping_set {
+            GroupingSet::Rollup(exprs) => {
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, " WITH ROLLUP").unwrap();
+            },
+            GroupingSet::Cube(_) | GroupingSet::Sets(_) => {
+                panic!("Mysql does not support CUBE and GROUPING SETS")
+            },
+        }
+    }
+
     fn prepare_join_on(&self, join_on: &JoinOn, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         match join_on {
             JoinOn::Condition(c) => {
-                write!(sql, "ON ").unwrap();
-                self.prepare_simple_expr(c, sql, collector);
+                if c.is_empty() {
+                    // The syntax requires a join condition even if nothing is filtered
+                    write!(sql, " ON ").unwrap();
+                    self.prepare_condition_where(&Condition::all(), sql, collector);
+                } else {
+                    self.prepare_condition(c, "ON", sql, collector);
+                }
+            },
+            JoinOn::Columns(columns) => {
+                write!(sql, " USING (").unwrap();
+                columns.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
             },
-            JoinOn::Columns(_c) => unimplemented!(),
+        }
+    }
+
+    fn prepare_select_lock(&self, lock: &LockClause, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        write!(sql, "FOR {}", match lock.r#type {
+            LockType::Update => "UPDATE",
+            LockType::Share => "SHARE",
+            LockType::NoKeyUpdate => panic!("Mysql does not support FOR NO KEY UPDATE"),
+            LockType::KeyShare => panic!("Mysql does not support FOR KEY SHARE"),
+        }).unwrap();
+        if !lock.tables.is_empty() {
+            write!(sql, " OF ").unwrap();
+            lock.tables.iter().fold(true, |first, table| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                table.prepare(sql, '`');
+                false
+            });
+        }
+        if let Some(behavior) = lock.behavior {
+            write!(sql, " {}", match behavior {
+                LockBehavior::Nowait => "NOWAIT",
+                LockBehavior::SkipLocked => "SKIP LOCKED",
+            }).unwrap();
+        }
+    }
+
+    fn prepare_returning(&self, returning: &[SelectExpr], _sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        if !returning.is_empty() {
+            panic!("Mysql does not support RETURNING in update and delete statements");
         }
     }
 
@@ -436,7 +699,8 @@ impl QueryBuilder for MysqlQueryBuilder {
     }
 
     fn prepare_value(&self, value: &Value, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        sql.push_param("?", false);
+        let (sign, numbered) = self.placeholder();
+        sql.push_param(sign, numbered);
         collector(value.clone());
     }
 
@@ -451,12 +715,288 @@ impl QueryBuilder for MysqlQueryBuilder {
         }
     }
 
+    fn prepare_case_statement(&self, case: &CaseStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if case.when.is_empty() {
+            panic!("No case condition found")
+        }
+        write!(sql, "CASE").unwrap();
+        for when in case.when.iter() {
+            write!(sql, " WHEN ").unwrap();
+            self.prepare_simple_expr(&when.condition, sql, collector);
+            write!(sql, " THEN ").unwrap();
+            self.prepare_simple_expr(&when.result, sql, collector);
+        }
+        if let Some(r#else) = &case.r#else {
+            write!(sql, " ELSE ").unwrap();
+            self.prepare_simple_expr(r#else, sql, collector);
+        }
+        write!(sql, " END").unwrap();
+    }
+
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
+        write!(sql, "{}", match column_type {
+            ColumnType::Char(length) | ColumnType::String(length) => match length {
+                Some(length) => format!("CHAR({})", length),
+                None => "CHAR".into(),
+            },
+            ColumnType::Text => "CHAR".into(),
+            ColumnType::TinyInteger(_)
+            | ColumnType::SmallInteger(_)
+            | ColumnType::Integer(_)
+            | ColumnType::BigInteger(_)
+            | ColumnType::Boolean => "SIGNED".into(),
+            ColumnType::Float(_) => "FLOAT".into(),
+            ColumnType::Double(_) => "DOUBLE".into(),
+            ColumnType::Decimal(precision) | ColumnType::Money(precision) => match precision {
+                Some((precision, scale)) => format!("DECIMAL({}, {})", precision, scale),
+                None => "DECIMAL".into(),
+            },
+            ColumnType::DateTime(precision) | ColumnType::Timestamp(precision) => match precision {
+                Some(precision) => format!("DATETIME({})", precision),
+                None => "DATETIME".into(),
+            },
+            ColumnType::Time(precision) => match precision {
+                Some(precision) => format!("TIME({})", precision),
+                None => "TIME".into(),
+            },
+            ColumnType::Date => "DATE".into(),
+            ColumnType::Binary(length) => match length {
+                Some(length) => format!("BINARY({})", length),
+                None => "BINARY".into(),
+            },
+            ColumnType::Json | ColumnType::JsonBinary => "JSON".into(),
+            ColumnType::Custom(iden) => iden.to_string(),
+        }).unwrap()
+    }
+
+    fn placeholder(&self) -> (&str, bool) {
+        ("?", false)
+    }
+
     fn value_to_string(&self, v: &Value) -> String {
         mysql_value_to_string(v)
     }
 }
 
 impl MysqlQueryBuilder {
+    /// The expression of an [`OrderExpr`], followed by its collation
+    fn prepare_collated_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        self.prepare_simple_expr(&order_expr.expr, sql, collector);
+        if let Some(collation) = &order_expr.collation {
+            write!(sql, " COLLATE ").unwrap();
+            collation.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_function_call(&self, func: &Function, exprs: &[SimpleExpr], modifiers: &AggregateModifiers,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match func {
+            Function::CurrentDate => self.prepare_function(func, sql, collector),
+            Function::DateTrunc(field) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_function(func, sql, collector);
+                write!(sql, "(").unwrap();
+                self.prepare_simple_expr(&exprs[0], sql, collector);
+                write!(sql, ", '{}') AS DATETIME)", match field {
+                    DateTimeField::Year => "%Y-01-01 00:00:00",
+                    DateTimeField::Month => "%Y-%m-01 00:00:00",
+                    DateTimeField::Day => "%Y-%m-%d 00:00:00",
+                    DateTimeField::Hour => "%Y-%m-%d %H:00:00",
+                    DateTimeField::Minute => "%Y-%m-%d %H:%i:00",
+                    DateTimeField::Second => "%Y-%m-%d %H:%i:%s",
+                }).unwrap();
+            },
+            Function::Extract(field) => {
+                self.prepare_function(func, sql, collector);
+                write!(sql, "({} FROM ", match field {
+                    DateTimeField::Year => "YEAR",
+                    DateTimeField::Month => "MONTH",
+                    DateTimeField::Day => "DAY",
+                    DateTimeField::Hour => "HOUR",
+                    DateTimeField::Minute => "MINUTE",
+                    DateTimeField::Second => "SECOND",
+                }).unwrap();
+                self.prepare_simple_expr(&exprs[0], sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            _ => {
+                if !modifiers.order_by.is_empty() && !matches!(func, Function::StringAgg) {
+                    panic!("Mysql only supports ORDER BY in GROUP_CONCAT among aggregate functions");
+                }
+                let (args, separator) = match func {
+                    Function::StringAgg => (&exprs[..1], exprs.get(1)),
+                    _ => (exprs, None),
+                };
+                self.prepare_function(func, sql, collector);
+                write!(sql, "(").unwrap();
+                if modifiers.distinct {
+                    write!(sql, "DISTINCT ").unwrap();
+                }
+                args.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    match &modifiers.filter {
+                        // `FILTER (WHERE ...)` is emulated by nulling out the rows not matching the condition
+                        Some(filter) if first => {
+                            write!(sql, "CASE WHEN ").unwrap();
+                            self.prepare_simple_expr(filter, sql, collector);
+                            write!(sql, " THEN ").unwrap();
+                            match expr {
+                                SimpleExpr::Custom(s) if s == "*" => write!(sql, "1").unwrap(),
+                                _ => self.prepare_simple_expr(expr, sql, collector),
+                            }
+                            write!(sql, " END").unwrap();
+                        },
+                        _ => self.prepare_simple_expr(expr, sql, collector),
+                    }
+                    false
+                });
+                if !modifiers.order_by.is_empty() {
+                    write!(sql, " ORDER BY ").unwrap();
+                    modifiers.order_by.iter().fold(true, |first, expr| {
+                        if !first {
+                            write!(sql, ", ").unwrap();
+                        }
+                        self.prepare_order_expr(expr, sql, collector);
+                        false
+                    });
+                }
+                if let Some(separator) = separator {
+                    write!(sql, " SEPARATOR ").unwrap();
+                    match separator {
+                        // The separator has to be a literal
+                        SimpleExpr::Value(value) => write!(sql, "{}", self.value_to_string(value)).unwrap(),
+                        _ => self.prepare_simple_expr(separator, sql, collector),
+                    }
+                }
+                write!(sql, ")").unwrap();
+            },
+        }
+    }
+
+    /// `ILIKE` by comparing both sides in lower case
+    fn prepare_ilike(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let (pattern, escape) = match right {
+            SimpleExpr::Binary(pattern, BinOper::Escape, escape) => (pattern.as_ref(), Some(escape)),
+            _ => (right, None),
+        };
+        write!(sql, "LOWER(").unwrap();
+        self.prepare_simple_expr(left, sql, collector);
+        write!(sql, ") ").unwrap();
+        self.prepare_bin_oper(op, sql, collector);
+        write!(sql, " LOWER(").unwrap();
+        self.prepare_simple_expr(pattern, sql, collector);
+        write!(sql, ")").unwrap();
+        if let Some(escape) = escape {
+            write!(sql, " ").unwrap();
+            self.prepare_bin_oper(&BinOper::Escape, sql, collector);
+            write!(sql, " ").unwrap();
+            self.prepare_simple_expr(escape, sql, collector);
+        }
+    }
+
+    /// A chain of concatenations as a single `CONCAT` call
+    fn prepare_concat(&self, left: &SimpleExpr, right: &SimpleExpr,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let mut operands = vec![right];
+        let mut left = left;
+        while let SimpleExpr::Binary(l, BinOper::Concat, r) = left {
+            operands.push(r);
+            left = l;
+        }
+        operands.push(left);
+        write!(sql, "CONCAT(").unwrap();
+        operands.into_iter().rev().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_match_against(&self, columns: &SimpleExpr, op: &BinOper, query: &SimpleExpr,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        self.prepare_function(&Function::MatchRank, sql, collector);
+        write!(sql, " (").unwrap();
+        columns.full_text_columns().into_iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        write!(sql, ") ").unwrap();
+        self.prepare_bin_oper(op, sql, collector);
+        write!(sql, " (").unwrap();
+        self.prepare_simple_expr(query, sql, collector);
+        if *op == BinOper::MatchesBoolean {
+            write!(sql, " IN BOOLEAN MODE").unwrap();
+        }
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_json_expr(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match op {
+            BinOper::JsonGet | BinOper::JsonGetPath => {
+                write!(sql, "JSON_EXTRACT(").unwrap();
+                self.prepare_simple_expr(left, sql, collector);
+                write!(sql, ", ").unwrap();
+                self.prepare_value(&right.json_paths(op).remove(0).into(), sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            BinOper::JsonGetText | BinOper::JsonGetPathText => {
+                // Not the `->>` shorthand, which only accepts a literal path rather than a bound parameter
+                write!(sql, "JSON_UNQUOTE(JSON_EXTRACT(").unwrap();
+                self.prepare_simple_expr(left, sql, collector);
+                write!(sql, ", ").unwrap();
+                self.prepare_value(&right.json_paths(op).remove(0).into(), sql, collector);
+                write!(sql, "))").unwrap();
+            },
+            BinOper::JsonContains | BinOper::JsonContainedBy => {
+                let (target, candidate) = if *op == BinOper::JsonContains { (left, right) } else { (right, left) };
+                write!(sql, "JSON_CONTAINS(").unwrap();
+                self.prepare_simple_expr(target, sql, collector);
+                write!(sql, ", ").unwrap();
+                self.prepare_simple_expr(candidate, sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            _ => {
+                write!(sql, "JSON_CONTAINS_PATH(").unwrap();
+                self.prepare_simple_expr(left, sql, collector);
+                write!(sql, ", '{}'", if *op == BinOper::JsonHasAllKeys { "all" } else { "one" }).unwrap();
+                for path in right.json_paths(op) {
+                    write!(sql, ", ").unwrap();
+                    self.prepare_value(&path.into(), sql, collector);
+                }
+                write!(sql, ")").unwrap();
+            },
+        }
+    }
+
+    /// Name a table is referred by in other clauses, i.e. its alias if it has one
+    fn prepare_table_qualifier(&self, table_ref: &TableRef, sql: &mut SqlWriter) {
+        match table_ref {
+            TableRef::Table(table) => {
+                table.prepare(sql, '`');
+            },
+            TableRef::SchemaTable(schema, table) => {
+                schema.prepare(sql, '`');
+                write!(sql, ".").unwrap();
+                table.prepare(sql, '`');
+            },
+            TableRef::TableAlias(_, alias) |
+            TableRef::SchemaTableAlias(_, _, alias) |
+            TableRef::SubQuery(_, alias) => {
+                alias.prepare(sql, '`');
+            },
+        }
+    }
+
     fn binary_expr(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
         sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         let no_paren = matches!(op, BinOper::Equal | BinOper::NotEqual);
@@ -474,10 +1014,11 @@ impl MysqlQueryBuilder {
         write!(sql, " ").unwrap();
         self.prepare_bin_oper(op, sql, collector);
         write!(sql, " ").unwrap();
-        let no_right_paren = matches!(op, BinOper::Between | BinOper::NotBetween);
+        let no_right_paren = matches!(op, BinOper::Between | BinOper::NotBetween) ||
+            right.get_bin_oper() == Some(BinOper::Escape);
         let right_paren =
             (right.need_parentheses() ||
-                right.is_binary() && *op != left.get_bin_oper().unwrap()) &&
+                right.is_binary() && left.get_bin_oper() != Some(*op)) &&
             !no_right_paren &&
             !no_paren;
         if right_paren {
@@ -3,6 +3,10 @@ use super::query::mysql_value_to_string;
 
 impl TableBuilder for MysqlQueryBuilder {
     fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        if create.fts5.is_some() {
+            panic!("Mysql does not support FTS5 virtual tables");
+        }
+
         write!(sql, "CREATE TABLE ").unwrap();
 
         if create.if_not_exists {
@@ -189,36 +193,56 @@ impl TableBuilder for MysqlQueryBuilder {
         }
     }
 
+    fn split_table_alter_statement(&self, alter: &TableAlterStatement) -> Vec<TableAlterStatement> {
+        vec![alter.clone()]
+    }
+
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
-        let alter_option = match &alter.alter_option {
-            Some(alter_option) => alter_option,
-            None => panic!("No alter option found"),
+        if alter.options.is_empty() {
+            panic!("No alter option found")
         };
         write!(sql, "ALTER TABLE ").unwrap();
         if let Some(table) = &alter.table {
             table.prepare(sql, '`');
             write!(sql, " ").unwrap();
         }
-        match alter_option {
-            TableAlterOption::AddColumn(column_def) => {
-                write!(sql, "ADD COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            },
-            TableAlterOption::ModifyColumn(column_def) => {
-                write!(sql, "MODIFY COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            },
-            TableAlterOption::RenameColumn(from_name, to_name) => {
-                write!(sql, "RENAME COLUMN ").unwrap();
-                from_name.prepare(sql, '`');
-                write!(sql, " TO ").unwrap();
-                to_name.prepare(sql, '`');
-            },
-            TableAlterOption::DropColumn(column_name) => {
-                write!(sql, "DROP COLUMN ").unwrap();
-                column_name.prepare(sql, '`');
-            },
-        }
+        alter.options.iter().fold(true, |first, alter_option| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            match alter_option {
+                TableAlterOption::AddColumn(column_def) => {
+                    write!(sql, "ADD COLUMN ").unwrap();
+                    self.prepare_column_def(column_def, sql);
+                },
+                TableAlterOption::ModifyColumn(column_def) => {
+                    write!(sql, "MODIFY COLUMN ").unwrap();
+                    self.prepare_column_def(column_def, sql);
+                },
+                TableAlterOption::RenameColumn(from_name, to_name) => {
+                    write!(sql, "RENAME COLUMN ").unwrap();
+                    from_name.prepare(sql, '`');
+                    write!(sql, " TO ").unwrap();
+                    to_name.prepare(sql, '`');
+                },
+                TableAlterOption::DropColumn(column_name) => {
+                    write!(sql, "DROP COLUMN ").unwrap();
+                    column_name.prepare(sql, '`');
+                },
+                TableAlterOption::AddForeignKey(foreign_key) => {
+                    write!(sql, "ADD ").unwrap();
+                    self.prepare_foreign_key_create_statement_internal(foreign_key, sql, true);
+                },
+                TableAlterOption::DropForeignKey(name) => {
+                    write!(sql, "DROP FOREIGN KEY `{}`", name).unwrap();
+                },
+                TableAlterOption::AddIndex(index) => {
+                    write!(sql, "ADD ").unwrap();
+                    self.prepare_table_index_expression(index, sql);
+                },
+            }
+            false
+        });
     }
 
     fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
@@ -0,0 +1,258 @@
+use std::fmt;
+use std::rc::Rc;
+use super::*;
+use crate::discovery::*;
+
+/// Identifiers of `pg_catalog` used for index discovery
+#[derive(Debug, Clone, Copy)]
+enum PgCatalog {
+    Schema,
+    PgClass,
+    PgIndex,
+    PgAm,
+    PgNamespace,
+    PgAttribute,
+    Oid,
+    Relname,
+    Relam,
+    Relnamespace,
+    Nspname,
+    Indrelid,
+    Indexrelid,
+    Indisunique,
+    Indisprimary,
+    Amname,
+    Attrelid,
+    Attname,
+}
+
+impl Iden for PgCatalog {
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Schema => "pg_catalog",
+            Self::PgClass => "pg_class",
+            Self::PgIndex => "pg_index",
+            Self::PgAm => "pg_am",
+            Self::PgNamespace => "pg_namespace",
+            Self::PgAttribute => "pg_attribute",
+            Self::Oid => "oid",
+            Self::Relname => "relname",
+            Self::Relam => "relam",
+            Self::Relnamespace => "relnamespace",
+            Self::Nspname => "nspname",
+            Self::Indrelid => "indrelid",
+            Self::Indexrelid => "indexrelid",
+            Self::Indisunique => "indisunique",
+            Self::Indisprimary => "indisprimary",
+            Self::Amname => "amname",
+            Self::Attrelid => "attrelid",
+            Self::Attname => "attname",
+        }).unwrap();
+    }
+}
+
+impl DiscoveryBuilder for PostgresQueryBuilder {
+    fn query_tables(&self, schema: &str) -> SelectStatement {
+        Query::select()
+            .column(InformationSchema::TableName)
+            .from((InformationSchema::Schema, InformationSchema::Tables))
+            .and_where(Expr::col(InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::col(InformationSchema::TableType).eq("BASE TABLE"))
+            .order_by(InformationSchema::TableName, Order::Asc)
+            .take()
+    }
+
+    fn query_table_exists(&self, table: &str) -> SelectStatement {
+        Query::select()
+            .column(InformationSchema::TableName)
+            .from((InformationSchema::Schema, InformationSchema::Tables))
+            .and_where(Expr::col(InformationSchema::TableSchema).into_simple_expr().binary(BinOper::Equal, Expr::cust("current_schema()")))
+            .and_where(Expr::col(InformationSchema::TableName).eq(table))
+            .take()
+    }
+
+    fn query_columns(&self, schema: &str, table: &str) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                InformationSchema::ColumnName,
+                InformationSchema::DataType,
+                InformationSchema::UdtName,
+                InformationSchema::CharacterMaximumLength,
+                InformationSchema::NumericPrecision,
+                InformationSchema::NumericScale,
+                InformationSchema::IsNullable,
+                InformationSchema::ColumnDefault,
+            ])
+            .from((InformationSchema::Schema, InformationSchema::Columns))
+            .and_where(Expr::col(InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::col(InformationSchema::TableName).eq(table))
+            .order_by(InformationSchema::OrdinalPosition, Order::Asc)
+            .take()
+    }
+
+    fn query_indexes(&self, schema: &str, table: &str) -> SelectStatement {
+        let t = Alias::new("t");
+        let ix = Alias::new("ix");
+        let i = Alias::new("i");
+        let am = Alias::new("am");
+        let n = Alias::new("n");
+        let a = Alias::new("a");
+        Query::select()
+            .column((i.clone(), PgCatalog::Relname))
+            .column((ix.clone(), PgCatalog::Indisunique))
+            .column((ix.clone(), PgCatalog::Indisprimary))
+            .column((a.clone(), PgCatalog::Attname))
+            .column((am.clone(), PgCatalog::Amname))
+            .from_as((PgCatalog::Schema, PgCatalog::PgClass), t.clone())
+            .join_as(
+                JoinType::Join,
+                (PgCatalog::Schema, PgCatalog::PgIndex),
+                ix.clone(),
+                Expr::tbl(ix.clone(), PgCatalog::Indrelid).equals(t.clone(), PgCatalog::Oid)
+            )
+            .join_as(
+                JoinType::Join,
+                (PgCatalog::Schema, PgCatalog::PgClass),
+                i.clone(),
+                Expr::tbl(i.clone(), PgCatalog::Oid).equals(ix.clone(), PgCatalog::Indexrelid)
+            )
+            .join_as(
+                JoinType::Join,
+                (PgCatalog::Schema, PgCatalog::PgAm),
+                am.clone(),
+                Expr::tbl(am.clone(), PgCatalog::Oid).equals(i.clone(), PgCatalog::Relam)
+            )
+            .join_as(
+                JoinType::Join,
+                (PgCatalog::Schema, PgCatalog::PgNamespace),
+                n.clone(),
+                Expr::tbl(n.clone(), PgCatalog::Oid).equals(t.clone(), PgCatalog::Relnamespace)
+            )
+            .join_as(
+                JoinType::Join,
+                (PgCatalog::Schema, PgCatalog::PgAttribute),
+                a.clone(),
+                Expr::tbl(a.clone(), PgCatalog::Attrelid).equals(t.clone(), PgCatalog::Oid)
+                    .and(Expr::cust(r#""a"."attnum" = ANY("ix"."indkey")"#))
+            )
+            .and_where(Expr::tbl(n, PgCatalog::Nspname).eq(schema))
+            .and_where(Expr::tbl(t, PgCatalog::Relname).eq(table))
+            .order_by((i, PgCatalog::Relname), Order::Asc)
+            .order_by_expr(Expr::cust(r#"array_position("ix"."indkey"::int2[], "a"."attnum")"#), Order::Asc)
+            .take()
+    }
+
+    fn query_foreign_keys(&self, schema: &str, table: &str) -> SelectStatement {
+        let kcu = Alias::new("kcu");
+        let rc = Alias::new("rc");
+        let rcu = Alias::new("rcu");
+        Query::select()
+            .column((kcu.clone(), InformationSchema::ConstraintName))
+            .column((kcu.clone(), InformationSchema::ColumnName))
+            .column((rcu.clone(), InformationSchema::TableName))
+            .column((rcu.clone(), InformationSchema::ColumnName))
+            .column((rc.clone(), InformationSchema::UpdateRule))
+            .column((rc.clone(), InformationSchema::DeleteRule))
+            .from_as((InformationSchema::Schema, InformationSchema::ReferentialConstraints), rc.clone())
+            .join_as(
+                JoinType::Join,
+                (InformationSchema::Schema, InformationSchema::KeyColumnUsage),
+                kcu.clone(),
+                Expr::tbl(kcu.clone(), InformationSchema::ConstraintSchema).equals(rc.clone(), InformationSchema::ConstraintSchema)
+                    .and(Expr::tbl(kcu.clone(), InformationSchema::ConstraintName).equals(rc.clone(), InformationSchema::ConstraintName))
+            )
+            .join_as(
+                JoinType::Join,
+                (InformationSchema::Schema, InformationSchema::KeyColumnUsage),
+                rcu.clone(),
+                Expr::tbl(rcu.clone(), InformationSchema::ConstraintSchema).equals(rc.clone(), InformationSchema::UniqueConstraintSchema)
+                    .and(Expr::tbl(rcu.clone(), InformationSchema::ConstraintName).equals(rc, InformationSchema::UniqueConstraintName))
+                    .and(Expr::tbl(rcu, InformationSchema::OrdinalPosition).equals(kcu.clone(), InformationSchema::PositionInUniqueConstraint))
+            )
+            .and_where(Expr::tbl(kcu.clone(), InformationSchema::TableSchema).eq(schema))
+            .and_where(Expr::tbl(kcu.clone(), InformationSchema::TableName).eq(table))
+            .order_by((kcu.clone(), InformationSchema::ConstraintName), Order::Asc)
+            .order_by((kcu, InformationSchema::OrdinalPosition), Order::Asc)
+            .take()
+    }
+
+    fn parse_table(&self, table: &str, columns: &[Vec<Value>], indexes: &[Vec<Value>], foreign_keys: &[Vec<Value>]) -> TableDef {
+        let mut table_def = TableDef::new(Alias::new(table));
+
+        for row in columns.iter() {
+            let name = row_string(row, 0).unwrap_or_default();
+            let mut column = ColumnDef::new(Alias::new(&name));
+            column.types = Some(self.parse_column_type(row));
+            if !row_bool(row, 6) {
+                column.spec.push(ColumnSpec::NotNull);
+            }
+            if let Some(default) = row_string(row, 7) {
+                if default.starts_with("nextval(") {
+                    column.spec.push(ColumnSpec::AutoIncrement);
+                } else {
+                    column.spec.push(parse_default(strip_cast(&default)));
+                }
+            }
+            table_def.columns.push(column);
+        }
+
+        for (name, rows) in group_rows(indexes, 0) {
+            let columns = rows.iter()
+                .filter_map(|row| row_string(row, 3).map(|column| index_column(&column, None)))
+                .collect();
+            if row_bool(rows[0], 2) {
+                let mut index = index_create(table, None, columns);
+                index.primary = true;
+                table_def.primary_key = Some(index);
+                continue;
+            }
+            let mut index = index_create(table, name, columns);
+            index.unique = row_bool(rows[0], 1);
+            index.index_type = match row_string(rows[0], 4).unwrap_or_default().as_str() {
+                "btree" => None,
+                "hash" => Some(IndexType::Hash),
+                "gin" => Some(IndexType::FullText),
+                other => Some(IndexType::Custom(Rc::new(Alias::new(&other.to_uppercase())))),
+            };
+            table_def.indexes.push(index);
+        }
+
+        table_def.foreign_keys = parse_foreign_keys(table, foreign_keys);
+        table_def
+    }
+}
+
+impl PostgresQueryBuilder {
+    fn parse_column_type(&self, row: &[Value]) -> ColumnType {
+        let data_type = row_string(row, 1).unwrap_or_default();
+        let length = row_u32(row, 3);
+        match data_type.as_str() {
+            "character" => ColumnType::Char(length),
+            "character varying" => ColumnType::String(length),
+            "text" => ColumnType::Text,
+            "smallint" => ColumnType::SmallInteger(None),
+            "integer" => ColumnType::Integer(None),
+            "bigint" => ColumnType::BigInteger(None),
+            "real" => ColumnType::Float(None),
+            "double precision" => ColumnType::Double(None),
+            "numeric" => ColumnType::Decimal(row_u32(row, 4).zip(row_u32(row, 5))),
+            "timestamp without time zone" => ColumnType::Timestamp(None),
+            "time without time zone" => ColumnType::Time(None),
+            "date" => ColumnType::Date,
+            "boolean" => ColumnType::Boolean,
+            "money" => ColumnType::Money(None),
+            "json" => ColumnType::Json,
+            "jsonb" => ColumnType::JsonBinary,
+            "USER-DEFINED" => ColumnType::Custom(Rc::new(Alias::new(&row_string(row, 2).unwrap_or_default()))),
+            _ => ColumnType::Custom(Rc::new(Alias::new(&data_type))),
+        }
+    }
+}
+
+/// Strip the type cast Postgres appends to literal defaults, e.g. `'abc'::character varying`
+fn strip_cast(default: &str) -> &str {
+    match default.rfind("::") {
+        Some(pos) if !default[pos..].contains('\'') && !default[pos..].contains(')') => &default[..pos],
+        _ => default,
+    }
+}
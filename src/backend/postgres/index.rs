@@ -64,7 +64,7 @@ impl PostgresQueryBuilder {
         }
     }
 
-    fn prepare_index_columns(&self, columns: &[IndexColumn], sql: &mut SqlWriter) {
+    pub(crate) fn prepare_index_columns(&self, columns: &[IndexColumn], sql: &mut SqlWriter) {
         write!(sql, " (").unwrap();
         columns.iter().fold(true, |first, col| {
             if !first {
@@ -37,27 +37,22 @@ impl QueryBuilder for PostgresQueryBuilder {
             false
         });
 
-        if !insert.returning.is_empty() {
-            write!(sql, " RETURNING ").unwrap();
-            insert.returning.iter().fold(true, |first, expr| {
-                if !first {
-                    write!(sql, ", ").unwrap()
-                }
-                self.prepare_select_expr(expr, sql, collector);
-                false
-            });
-        }
+        self.prepare_returning(&insert.returning, sql, collector);
     }
 
     fn prepare_select_statement(&self, select: &SelectStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         write!(sql, "SELECT ").unwrap();
 
         if let Some(distinct) = &select.distinct {
-            write!(sql, " ").unwrap();
             self.prepare_select_distinct(distinct, sql, collector);
             write!(sql, " ").unwrap();
         }
 
+        if !select.distinct_on.is_empty() {
+            self.prepare_select_distinct_on(&select.distinct_on, sql, collector);
+            write!(sql, " ").unwrap();
+        }
+
         select.selects.iter().fold(true, |first, expr| {
             if !first {
                 write!(sql, ", ").unwrap()
@@ -78,30 +73,27 @@ impl QueryBuilder for PostgresQueryBuilder {
             }
         }
 
-        if !select.wherei.is_empty() {
-            write!(sql, " WHERE ").unwrap();
-        }
-        for (i, log_chain_oper) in select.wherei.iter().enumerate() {
-            self.prepare_logical_chain_oper(log_chain_oper, i, select.wherei.len(), sql, collector);
-        }
+        self.prepare_condition(&select.wherei, "WHERE", sql, collector);
 
-        if !select.groups.is_empty() {
+        if !select.groups.is_empty() || !select.grouping_sets.is_empty() {
             write!(sql, " GROUP BY ").unwrap();
-            select.groups.iter().fold(true, |first, expr| {
+            let first = select.groups.iter().fold(true, |first, expr| {
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
                 self.prepare_simple_expr(expr, sql, collector);
                 false
             });
+            select.grouping_sets.iter().fold(first, |first, grouping_set| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_grouping_set(grouping_set, sql, collector);
+                false
+            });
         }
 
-        if !select.having.is_empty() {
-            write!(sql, " HAVING ").unwrap();
-        }
-        for (i, log_chain_oper) in select.having.iter().enumerate() {
-            self.prepare_logical_chain_oper(log_chain_oper, i, select.having.len(), sql, collector);
-        }
+        self.prepare_condition(&select.having, "HAVING", sql, collector);
 
         if !select.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -123,6 +115,11 @@ impl QueryBuilder for PostgresQueryBuilder {
             write!(sql, " OFFSET ").unwrap();
             self.prepare_value(offset, sql, collector);
         }
+
+        if let Some(lock) = &select.lock {
+            write!(sql, " ").unwrap();
+            self.prepare_select_lock(lock, sql, collector);
+        }
     }
 
     fn prepare_update_statement(&self, update: &UpdateStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
@@ -144,11 +141,14 @@ impl QueryBuilder for PostgresQueryBuilder {
             false
         });
 
-        if let Some(wherei) = &update.wherei {
-            write!(sql, " WHERE ").unwrap();
-            self.prepare_simple_expr(wherei, sql, collector);
+        if update.join.is_empty() {
+            self.prepare_condition(&update.wherei, "WHERE", sql, collector);
+        } else {
+            self.prepare_join_tables(&update.join, &update.wherei, "FROM", sql, collector);
         }
 
+        self.prepare_returning(&update.returning, sql, collector);
+
         if !update.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
             update.orders.iter().fold(true, |first, expr| {
@@ -168,17 +168,20 @@ impl QueryBuilder for PostgresQueryBuilder {
 
     fn prepare_delete_statement(&self, delete: &DeleteStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         write!(sql, "DELETE ").unwrap();
-        
+
         if let Some(table) = &delete.table {
             write!(sql, "FROM ").unwrap();
             self.prepare_table_ref(table, sql, collector);
         }
 
-        if let Some(wherei) = &delete.wherei {
-            write!(sql, " WHERE ").unwrap();
-            self.prepare_simple_expr(wherei, sql, collector);
+        if delete.join.is_empty() {
+            self.prepare_condition(&delete.wherei, "WHERE", sql, collector);
+        } else {
+            self.prepare_join_tables(&delete.join, &delete.wherei, "USING", sql, collector);
         }
 
+        self.prepare_returning(&delete.returning, sql, collector);
+
         if !delete.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
             delete.orders.iter().fold(true, |first, expr| {
@@ -211,30 +214,65 @@ impl QueryBuilder for PostgresQueryBuilder {
             SimpleExpr::Unary(op, expr) => {
                 self.prepare_un_oper(op, sql, collector);
                 write!(sql, " ").unwrap();
+                let paren = expr.need_parentheses_under(op);
+                if paren {
+                    write!(sql, "(").unwrap();
+                }
                 self.prepare_simple_expr(expr, sql, collector);
+                if paren {
+                    write!(sql, ")").unwrap();
+                }
             },
-            SimpleExpr::FunctionCall(func, exprs) => {
-                self.prepare_function(func, sql, collector);
+            SimpleExpr::FunctionCall(Function::MatchRank, exprs, _) => {
+                self.prepare_function(&Function::MatchRank, sql, collector);
                 write!(sql, "(").unwrap();
-                exprs.iter().fold(true, |first, expr| {
-                    if !first {
-                        write!(sql, ", ").unwrap();
-                    }
-                    self.prepare_simple_expr(expr, sql, collector);
-                    false
-                });
+                self.prepare_ts_vector(&exprs[0], sql, collector);
+                write!(sql, ", ").unwrap();
+                self.prepare_ts_query(&BinOper::Matches, &exprs[1], sql, collector);
                 write!(sql, ")").unwrap();
             },
+            SimpleExpr::FunctionCall(func, exprs, modifiers) => {
+                self.prepare_function_call(func, exprs, modifiers, sql, collector);
+            },
             SimpleExpr::Binary(left, op, right) => {
-                if *op == BinOper::In && right.is_values() && right.get_values().is_empty() {
+                if *op == BinOper::In && right.is_empty_list() {
                     self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(2.into()), sql, collector);
-                } else if *op == BinOper::NotIn && right.is_values() && right.get_values().is_empty() {
+                } else if *op == BinOper::NotIn && right.is_empty_list() {
                     self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(1.into()), sql, collector);
+                } else if matches!(op, BinOper::Matches | BinOper::MatchesBoolean) {
+                    self.prepare_ts_vector(left, sql, collector);
+                    write!(sql, " ").unwrap();
+                    self.prepare_bin_oper(op, sql, collector);
+                    write!(sql, " ").unwrap();
+                    self.prepare_ts_query(op, right, sql, collector);
+                } else if SimpleExpr::is_json_keys_oper(op) {
+                    let left_paren = left.need_parentheses() && left.get_bin_oper() != Some(*op);
+                    if left_paren {
+                        write!(sql, "(").unwrap();
+                    }
+                    self.prepare_simple_expr(left, sql, collector);
+                    if left_paren {
+                        write!(sql, ")").unwrap();
+                    }
+                    write!(sql, " ").unwrap();
+                    self.prepare_bin_oper(op, sql, collector);
+                    write!(sql, " ARRAY[").unwrap();
+                    right.json_text_keys().iter().fold(true, |first, key| {
+                        if !first {
+                            write!(sql, ", ").unwrap();
+                        }
+                        self.prepare_value(key, sql, collector);
+                        false
+                    });
+                    write!(sql, "]").unwrap();
                 } else {
                     self.binary_expr(left, op, right, sql, collector);
                 }
             },
-            SimpleExpr::SubQuery(sel) => {
+            SimpleExpr::SubQuery(oper, sel) => {
+                if let Some(oper) = oper {
+                    self.prepare_sub_query_oper(oper, sql, collector);
+                }
                 write!(sql, "(").unwrap();
                 self.prepare_select_statement(sel, sql, collector);
                 write!(sql, ")").unwrap();
@@ -242,6 +280,17 @@ impl QueryBuilder for PostgresQueryBuilder {
             SimpleExpr::Value(val) => {
                 self.prepare_value(val, sql, collector);
             },
+            SimpleExpr::Tuple(exprs) => {
+                write!(sql, "(").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            },
             SimpleExpr::Values(list) => {
                 write!(sql, "(").unwrap();
                 list.iter().fold(true, |first, val| {
@@ -273,9 +322,22 @@ impl QueryBuilder for PostgresQueryBuilder {
                     }
                 }
             },
+            SimpleExpr::CustomWithExprs(expr, exprs) => {
+                self.prepare_custom_with_exprs(expr, exprs, sql, collector);
+            },
             SimpleExpr::Keyword(keyword) => {
                 self.prepare_keyword(keyword, sql, collector);
             },
+            SimpleExpr::Case(case) => {
+                self.prepare_case_statement(case, sql, collector);
+            },
+            SimpleExpr::Cast(expr, column_type) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_simple_expr(expr, sql, collector);
+                write!(sql, " AS ").unwrap();
+                self.prepare_cast_type(column_type, sql);
+                write!(sql, ")").unwrap();
+            },
         }
     }
 
@@ -287,6 +349,11 @@ impl QueryBuilder for PostgresQueryBuilder {
         }).unwrap();
     }
 
+    fn prepare_select_distinct_on(&self, exprs: &[SimpleExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        write!(sql, "DISTINCT ON ").unwrap();
+        self.prepare_expr_list(exprs, sql, collector);
+    }
+
     fn prepare_select_expr(&self, select_expr: &SelectExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         self.prepare_simple_expr(&select_expr.expr, sql, collector);
         match &select_expr.alias {
@@ -299,11 +366,16 @@ impl QueryBuilder for PostgresQueryBuilder {
     }
 
     fn prepare_join_expr(&self, join_expr: &JoinExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if join_expr.natural {
+            write!(sql, "NATURAL ").unwrap();
+        }
         self.prepare_join_type(&join_expr.join, sql, collector);
         write!(sql, " ").unwrap();
+        if join_expr.lateral {
+            write!(sql, "LATERAL ").unwrap();
+        }
         self.prepare_table_ref(&join_expr.table, sql, collector);
         if let Some(on) = &join_expr.on {
-            write!(sql, " ").unwrap();
             self.prepare_join_on(on, sql, collector);
         }
     }
@@ -343,6 +415,16 @@ impl QueryBuilder for PostgresQueryBuilder {
     fn prepare_un_oper(&self, un_oper: &UnOper, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         write!(sql, "{}", match un_oper {
             UnOper::Not => "NOT",
+            UnOper::Neg => "-",
+        }).unwrap();
+    }
+
+    fn prepare_sub_query_oper(&self, oper: &SubQueryOper, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        write!(sql, "{}", match oper {
+            SubQueryOper::Exists => "EXISTS",
+            SubQueryOper::Any => "ANY",
+            SubQueryOper::Some => "SOME",
+            SubQueryOper::All => "ALL",
         }).unwrap();
     }
 
@@ -368,6 +450,31 @@ impl QueryBuilder for PostgresQueryBuilder {
             BinOper::Sub => "-",
             BinOper::Mul => "*",
             BinOper::Div => "/",
+            BinOper::Mod => "%",
+            BinOper::Concat => "||",
+            BinOper::BitAnd => "&",
+            BinOper::BitOr => "|",
+            BinOper::LShift => "<<",
+            BinOper::RShift => ">>",
+            BinOper::ILike => "ILIKE",
+            BinOper::NotILike => "NOT ILIKE",
+            BinOper::Escape => "ESCAPE",
+            BinOper::SimilarTo => "SIMILAR TO",
+            BinOper::NotSimilarTo => "NOT SIMILAR TO",
+            BinOper::Regexp => "~",
+            BinOper::NotRegexp => "!~",
+            BinOper::IsDistinctFrom => "IS DISTINCT FROM",
+            BinOper::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
+            BinOper::JsonGet => "->",
+            BinOper::JsonGetText => "->>",
+            BinOper::JsonGetPath => "#>",
+            BinOper::JsonGetPathText => "#>>",
+            BinOper::JsonContains => "@>",
+            BinOper::JsonContainedBy => "<@",
+            BinOper::JsonHasKey => "?",
+            BinOper::JsonHasAnyKey => "?|",
+            BinOper::JsonHasAllKeys => "?&",
+            BinOper::Matches | BinOper::MatchesBoolean => "@@",
         }).unwrap();
     }
 
@@ -393,6 +500,55 @@ impl QueryBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn prepare_condition(&self, condition: &ConditionHolder, keyword: &str, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if condition.is_empty() {
+            return;
+        }
+        write!(sql, " {} ", keyword).unwrap();
+        match &condition.contents {
+            ConditionHolderContents::Empty => {},
+            ConditionHolderContents::Condition(c) => {
+                self.prepare_condition_where(c, sql, collector);
+            },
+        }
+    }
+
+    fn prepare_condition_where(&self, condition: &Condition, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if condition.negate {
+            write!(sql, "NOT (").unwrap();
+        }
+        if condition.is_empty() {
+            let always = match condition.condition_type {
+                ConditionType::All => 1,
+                ConditionType::Any => 2,
+            };
+            self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(always.into()), sql, collector);
+        }
+        condition.conditions.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, " {} ", match condition.condition_type {
+                    ConditionType::All => "AND",
+                    ConditionType::Any => "OR",
+                }).unwrap();
+            }
+            let need_parentheses = condition.len() > 1 && expr.need_parentheses(condition.condition_type);
+            if need_parentheses {
+                write!(sql, "(").unwrap();
+            }
+            match expr {
+                ConditionExpression::Condition(c) => self.prepare_condition_where(c, sql, collector),
+                ConditionExpression::SimpleExpr(e) => self.prepare_simple_expr(e, sql, collector),
+            }
+            if need_parentheses {
+                write!(sql, ")").unwrap();
+            }
+            false
+        });
+        if condition.negate {
+            write!(sql, ")").unwrap();
+        }
+    }
+
     fn prepare_function(&self, function: &Function, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         if let Function::Custom(iden) = function {
             iden.unquoted(sql);
@@ -405,6 +561,29 @@ impl QueryBuilder for PostgresQueryBuilder {
                 Function::Count => "COUNT",
                 Function::IfNull => "COALESCE",
                 Function::CharLength => "CHAR_LENGTH",
+                Function::Grouping => "GROUPING",
+                Function::JsonBuildObject => "jsonb_build_object",
+                Function::JsonAgg => "json_agg",
+                Function::JsonbSet => "jsonb_set",
+                Function::StringAgg => "STRING_AGG",
+                Function::ArrayAgg => "ARRAY_AGG",
+                Function::MatchRank => "ts_rank",
+                Function::Coalesce => "COALESCE",
+                Function::Lower => "LOWER",
+                Function::Upper => "UPPER",
+                Function::Trim => "TRIM",
+                Function::Substring => "SUBSTRING",
+                Function::Concat => "CONCAT",
+                Function::Replace => "REPLACE",
+                Function::Abs => "ABS",
+                Function::Round => "ROUND",
+                Function::Greatest => "GREATEST",
+                Function::Least => "LEAST",
+                Function::Now => "NOW",
+                Function::CurrentDate => "CURRENT_DATE",
+                Function::DateTrunc(_) => "DATE_TRUNC",
+                Function::Extract(_) => "EXTRACT",
+                Function::Random => "RANDOM",
                 Function::Custom(_) => "",
             }).unwrap();
         }
@@ -416,25 +595,123 @@ impl QueryBuilder for PostgresQueryBuilder {
             JoinType::InnerJoin => "INNER JOIN",
             JoinType::LeftJoin => "LEFT JOIN",
             JoinType::RightJoin => "RIGHT JOIN",
+            JoinType::FullOuterJoin => "FULL OUTER JOIN",
+            JoinType::CrossJoin => "CROSS JOIN",
         }).unwrap()
     }
 
     fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        self.prepare_simple_expr(&order_expr.expr, sql, collector);
+        if let Some(values) = &order_expr.field {
+            write!(sql, "CASE").unwrap();
+            for (i, value) in values.iter().enumerate() {
+                write!(sql, " WHEN ").unwrap();
+                self.prepare_collated_expr(order_expr, sql, collector);
+                write!(sql, " = ").unwrap();
+                self.prepare_value(value, sql, collector);
+                write!(sql, " THEN {}", i + 1).unwrap();
+            }
+            write!(sql, " ELSE 0 END").unwrap();
+        } else {
+            self.prepare_collated_expr(order_expr, sql, collector);
+        }
         write!(sql, " ").unwrap();
         self.prepare_order(&order_expr.order, sql, collector);
+        if let Some(nulls) = order_expr.nulls {
+            write!(sql, " {}", match nulls {
+                NullOrdering::First => "NULLS FIRST",
+                NullOrdering::Last => "NULLS LAST",
+            }).unwrap();
+        }
+    }
+
+    fn prepare_grouping_set(&self, grouping_set: &GroupingSet, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match grouping_set {
+            GroupingSet::Rollup(exprs) => {
+                write!(sql, "ROLLUP ").unwrap();
+                self.prepare_expr_list(exprs, sql, collector);
+            },
+            GroupingSet::Cube(exprs) => {
+                write!(sql, "CUBE ").unwrap();
+                self.prepare_expr_list(exprs, sql, collector);
+            },
+            GroupingSet::Sets(sets) => {
+                write!(sql, "GROUPING SETS (").unwrap();
+                sets.iter().fold(true, |first, exprs| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_expr_list(exprs, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            },
+        }
     }
 
     fn prepare_join_on(&self, join_on: &JoinOn, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         match join_on {
             JoinOn::Condition(c) => {
-                write!(sql, "ON ").unwrap();
-                self.prepare_simple_expr(c, sql, collector);
+                if c.is_empty() {
+                    // The syntax requires a join condition even if nothing is filtered
+                    write!(sql, " ON ").unwrap();
+                    self.prepare_condition_where(&Condition::all(), sql, collector);
+                } else {
+                    self.prepare_condition(c, "ON", sql, collector);
+                }
+            },
+            JoinOn::Columns(columns) => {
+                write!(sql, " USING (").unwrap();
+                columns.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
             },
-            JoinOn::Columns(_c) => unimplemented!(),
         }
     }
 
+    fn prepare_select_lock(&self, lock: &LockClause, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        write!(sql, "FOR {}", match lock.r#type {
+            LockType::Update => "UPDATE",
+            LockType::NoKeyUpdate => "NO KEY UPDATE",
+            LockType::Share => "SHARE",
+            LockType::KeyShare => "KEY SHARE",
+        }).unwrap();
+        if !lock.tables.is_empty() {
+            write!(sql, " OF ").unwrap();
+            lock.tables.iter().fold(true, |first, table| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                table.prepare(sql, '"');
+                false
+            });
+        }
+        if let Some(behavior) = lock.behavior {
+            write!(sql, " {}", match behavior {
+                LockBehavior::Nowait => "NOWAIT",
+                LockBehavior::SkipLocked => "SKIP LOCKED",
+            }).unwrap();
+        }
+    }
+
+    fn prepare_returning(&self, returning: &[SelectExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if returning.is_empty() {
+            return;
+        }
+        write!(sql, " RETURNING ").unwrap();
+        returning.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_select_expr(expr, sql, collector);
+            false
+        });
+    }
+
     fn prepare_order(&self, order: &Order, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         match order {
             Order::Asc => {
@@ -447,7 +724,8 @@ impl QueryBuilder for PostgresQueryBuilder {
     }
 
     fn prepare_value(&self, value: &Value, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        sql.push_param("$", true);
+        let (sign, numbered) = self.placeholder();
+        sql.push_param(sign, numbered);
         collector(value.clone());
     }
 
@@ -462,12 +740,193 @@ impl QueryBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn prepare_case_statement(&self, case: &CaseStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if case.when.is_empty() {
+            panic!("No case condition found")
+        }
+        write!(sql, "CASE").unwrap();
+        for when in case.when.iter() {
+            write!(sql, " WHEN ").unwrap();
+            self.prepare_simple_expr(&when.condition, sql, collector);
+            write!(sql, " THEN ").unwrap();
+            self.prepare_simple_expr(&when.result, sql, collector);
+        }
+        if let Some(r#else) = &case.r#else {
+            write!(sql, " ELSE ").unwrap();
+            self.prepare_simple_expr(r#else, sql, collector);
+        }
+        write!(sql, " END").unwrap();
+    }
+
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
+        write!(sql, "{}", match column_type {
+            ColumnType::Char(length) => match length {
+                Some(length) => format!("char({})", length),
+                None => "char".into(),
+            },
+            ColumnType::String(length) => match length {
+                Some(length) => format!("varchar({})", length),
+                None => "varchar".into(),
+            },
+            ColumnType::Text => "text".into(),
+            ColumnType::TinyInteger(_) | ColumnType::SmallInteger(_) => "smallint".into(),
+            ColumnType::Integer(_) => "integer".into(),
+            ColumnType::BigInteger(_) => "bigint".into(),
+            ColumnType::Float(_) => "real".into(),
+            ColumnType::Double(_) => "double precision".into(),
+            ColumnType::Decimal(precision) => match precision {
+                Some((precision, scale)) => format!("decimal({}, {})", precision, scale),
+                None => "decimal".into(),
+            },
+            ColumnType::DateTime(precision) | ColumnType::Timestamp(precision) => match precision {
+                Some(precision) => format!("timestamp({})", precision),
+                None => "timestamp".into(),
+            },
+            ColumnType::Time(precision) => match precision {
+                Some(precision) => format!("time({})", precision),
+                None => "time".into(),
+            },
+            ColumnType::Date => "date".into(),
+            ColumnType::Binary(_) => "bytea".into(),
+            ColumnType::Boolean => "bool".into(),
+            ColumnType::Money(_) => "money".into(),
+            ColumnType::Json => "json".into(),
+            ColumnType::JsonBinary => "jsonb".into(),
+            ColumnType::Custom(iden) => iden.to_string(),
+        }).unwrap()
+    }
+
+    fn placeholder(&self) -> (&str, bool) {
+        ("$", true)
+    }
+
     fn value_to_string(&self, v: &Value) -> String {
         pg_value_to_string(v)
     }
 }
 
 impl PostgresQueryBuilder {
+    /// The expression of an [`OrderExpr`], followed by its collation
+    fn prepare_collated_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        self.prepare_simple_expr(&order_expr.expr, sql, collector);
+        if let Some(collation) = &order_expr.collation {
+            write!(sql, " COLLATE ").unwrap();
+            collation.prepare(sql, '"');
+        }
+    }
+
+    fn prepare_function_call(&self, func: &Function, exprs: &[SimpleExpr], modifiers: &AggregateModifiers,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match func {
+            Function::CurrentDate => self.prepare_function(func, sql, collector),
+            Function::DateTrunc(field) => {
+                self.prepare_function(func, sql, collector);
+                write!(sql, "('{}', ", pg_date_time_field(field).to_lowercase()).unwrap();
+                self.prepare_simple_expr(&exprs[0], sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            Function::Extract(field) => {
+                self.prepare_function(func, sql, collector);
+                write!(sql, "({} FROM ", pg_date_time_field(field)).unwrap();
+                self.prepare_simple_expr(&exprs[0], sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            _ => {
+                self.prepare_function(func, sql, collector);
+                write!(sql, "(").unwrap();
+                if modifiers.distinct {
+                    write!(sql, "DISTINCT ").unwrap();
+                }
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                if !modifiers.order_by.is_empty() {
+                    write!(sql, " ORDER BY ").unwrap();
+                    modifiers.order_by.iter().fold(true, |first, expr| {
+                        if !first {
+                            write!(sql, ", ").unwrap();
+                        }
+                        self.prepare_order_expr(expr, sql, collector);
+                        false
+                    });
+                }
+                write!(sql, ")").unwrap();
+                if let Some(filter) = &modifiers.filter {
+                    write!(sql, " FILTER (WHERE ").unwrap();
+                    self.prepare_simple_expr(filter, sql, collector);
+                    write!(sql, ")").unwrap();
+                }
+            },
+        }
+    }
+
+    /// Comma separated expressions in parentheses
+    fn prepare_expr_list(&self, exprs: &[SimpleExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        write!(sql, "(").unwrap();
+        exprs.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    /// The document of a full-text search, with multiple columns joined by spaces
+    fn prepare_ts_vector(&self, columns: &SimpleExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        write!(sql, "to_tsvector(").unwrap();
+        match columns {
+            SimpleExpr::Tuple(exprs) => {
+                write!(sql, "concat_ws(' ', ").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            },
+            _ => self.prepare_simple_expr(columns, sql, collector),
+        }
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_ts_query(&self, op: &BinOper, query: &SimpleExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        write!(sql, "{}(", if *op == BinOper::MatchesBoolean { "websearch_to_tsquery" } else { "plainto_tsquery" }).unwrap();
+        self.prepare_simple_expr(query, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    /// List the joined tables after `keyword`, moving their join conditions into the `WHERE` clause
+    fn prepare_join_tables(&self, joins: &[JoinExpr], wherei: &ConditionHolder, keyword: &str,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let mut condition = ConditionHolder::new();
+        write!(sql, " {} ", keyword).unwrap();
+        joins.iter().fold(true, |first, join_expr| {
+            if !matches!(join_expr.join, JoinType::Join | JoinType::InnerJoin) {
+                panic!("Postgres only supports inner joins in update and delete statements");
+            }
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_table_ref(&join_expr.table, sql, collector);
+            match &join_expr.on {
+                Some(JoinOn::Condition(c)) => condition.add_condition(c.to_condition()),
+                Some(JoinOn::Columns(_)) => panic!("Postgres does not support joining on USING columns in update and delete statements"),
+                None => {},
+            }
+            false
+        });
+        condition.add_condition(wherei.to_condition());
+        self.prepare_condition(&condition, "WHERE", sql, collector);
+    }
+
     fn binary_expr(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
         sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         let no_paren = matches!(op, BinOper::Equal | BinOper::NotEqual);
@@ -485,10 +944,11 @@ impl PostgresQueryBuilder {
         write!(sql, " ").unwrap();
         self.prepare_bin_oper(op, sql, collector);
         write!(sql, " ").unwrap();
-        let no_right_paren = matches!(op, BinOper::Between | BinOper::NotBetween);
+        let no_right_paren = matches!(op, BinOper::Between | BinOper::NotBetween) ||
+            right.get_bin_oper() == Some(BinOper::Escape);
         let right_paren =
             (right.need_parentheses() ||
-                right.is_binary() && *op != left.get_bin_oper().unwrap()) &&
+                right.is_binary() && left.get_bin_oper() != Some(*op)) &&
             !no_right_paren &&
             !no_paren;
         if right_paren {
@@ -501,6 +961,17 @@ impl PostgresQueryBuilder {
     }
 }
 
+fn pg_date_time_field(field: &DateTimeField) -> &'static str {
+    match field {
+        DateTimeField::Year => "YEAR",
+        DateTimeField::Month => "MONTH",
+        DateTimeField::Day => "DAY",
+        DateTimeField::Hour => "HOUR",
+        DateTimeField::Minute => "MINUTE",
+        DateTimeField::Second => "SECOND",
+    }
+}
+
 pub fn pg_value_to_string(v: &Value) -> String {
     let mut s = String::new();
     match v {
@@ -517,7 +988,7 @@ pub fn pg_value_to_string(v: &Value) -> String {
         Value::Float(v) => write!(s, "{}", v).unwrap(),
         Value::Double(v) => write!(s, "{}", v).unwrap(),
         Value::String(v) => write!(s, "{}", pg_escape_string_quoted(v)).unwrap(),
-        Value::Bytes(v) => write!(s, "x\'{}\'", v.iter().map(|b| format!("{:02X}", b)).collect::<String>()).unwrap(),
+        Value::Bytes(v) => write!(s, "{}", pg_escape_string_quoted(&format!("\\x{}", v.iter().map(|b| format!("{:02X}", b)).collect::<String>()))).unwrap(),
         #[cfg(feature="with-json")]
         Value::Json(v) => write!(s, "{}", pg_escape_string_quoted(&v.to_string())).unwrap(),
         #[cfg(feature="with-chrono")]
@@ -528,11 +999,14 @@ pub fn pg_value_to_string(v: &Value) -> String {
     s
 }
 
+/// Quote a string literal, doubling single quotes.
+/// Strings containing a backslash use the `E'...'` syntax with the backslash escaped,
+/// so that they are read the same regardless of `standard_conforming_strings`.
 pub fn pg_escape_string_quoted(string: &str) -> String {
-    let escaped = escape_string(string);
-    if escaped.find('\\').is_some() {
-        "E'".to_owned() + &escaped + "'"
+    let escaped = string.replace('\'', "''");
+    if escaped.contains('\\') {
+        "E'".to_owned() + &escaped.replace('\\', "\\\\") + "'"
     } else {
         "'".to_owned() + &escaped + "'"
     }
-}
\ No newline at end of file
+}
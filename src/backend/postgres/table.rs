@@ -3,6 +3,10 @@ use super::query::pg_value_to_string;
 
 impl TableBuilder for PostgresQueryBuilder {
     fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        if create.fts5.is_some() {
+            panic!("Postgres does not support FTS5 virtual tables");
+        }
+
         write!(sql, "CREATE TABLE ").unwrap();
 
         if create.if_not_exists {
@@ -189,48 +193,112 @@ impl TableBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn split_table_alter_statement(&self, alter: &TableAlterStatement) -> Vec<TableAlterStatement> {
+        // A non-unique index can only be added by a separate `CREATE INDEX`
+        let (indexes, options): (Vec<_>, Vec<_>) = alter.options.iter().cloned().partition(|alter_option| {
+            matches!(alter_option, TableAlterOption::AddIndex(index) if !index.primary && !index.unique)
+        });
+        let mut statements = Vec::new();
+        if !options.is_empty() {
+            statements.push(TableAlterStatement {
+                table: alter.table.clone(),
+                options,
+            });
+        }
+        statements.extend(indexes.into_iter().map(|index| TableAlterStatement {
+            table: alter.table.clone(),
+            options: vec![index],
+        }));
+        statements
+    }
+
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
-        let alter_option = match &alter.alter_option {
-            Some(alter_option) => alter_option,
-            None => panic!("No alter option found"),
+        if alter.options.is_empty() {
+            panic!("No alter option found")
         };
+        let statements = self.split_table_alter_statement(alter);
+        if statements.len() > 1 {
+            statements.iter().fold(true, |first, alter| {
+                if !first {
+                    write!(sql, "; ").unwrap();
+                }
+                self.prepare_table_alter_statement(alter, sql);
+                false
+            });
+            return;
+        }
+        if let [TableAlterOption::AddIndex(index)] = alter.options.as_slice() {
+            if !index.primary && !index.unique {
+                let mut index = index.clone();
+                index.table = alter.table.clone();
+                self.prepare_index_create_statement(&index, sql);
+                return;
+            }
+        }
         write!(sql, "ALTER TABLE ").unwrap();
         if let Some(table) = &alter.table {
             table.prepare(sql, '"');
             write!(sql, " ").unwrap();
         }
-        match alter_option {
-            TableAlterOption::AddColumn(column_def) => {
-                write!(sql, "ADD COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            },
-            TableAlterOption::ModifyColumn(column_def) => {
-                write!(sql, "ALTER COLUMN ").unwrap();
-                column_def.name.prepare(sql, '"');
-                write!(sql, " TYPE").unwrap();
-                self.prepare_column_type_check_auto_increment(column_def, sql);
-                for column_spec in column_def.spec.iter() {
-                    if let ColumnSpec::AutoIncrement = column_spec {
-                        continue;
-                    }
-                    write!(sql, ", ").unwrap();
+        alter.options.iter().fold(true, |first, alter_option| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            match alter_option {
+                TableAlterOption::AddColumn(column_def) => {
+                    write!(sql, "ADD COLUMN ").unwrap();
+                    self.prepare_column_def(column_def, sql);
+                },
+                TableAlterOption::ModifyColumn(column_def) => {
                     write!(sql, "ALTER COLUMN ").unwrap();
                     column_def.name.prepare(sql, '"');
-                    write!(sql, " SET ").unwrap();
-                    self.prepare_column_spec(column_spec, sql);
-                }
-            },
-            TableAlterOption::RenameColumn(from_name, to_name) => {
-                write!(sql, "RENAME COLUMN ").unwrap();
-                from_name.prepare(sql, '"');
-                write!(sql, " TO ").unwrap();
-                to_name.prepare(sql, '"');
-            },
-            TableAlterOption::DropColumn(column_name) => {
-                write!(sql, "DROP COLUMN ").unwrap();
-                column_name.prepare(sql, '"');
-            },
-        }
+                    write!(sql, " TYPE").unwrap();
+                    self.prepare_column_type_check_auto_increment(column_def, sql);
+                    for column_spec in column_def.spec.iter() {
+                        if let ColumnSpec::AutoIncrement = column_spec {
+                            continue;
+                        }
+                        write!(sql, ", ").unwrap();
+                        write!(sql, "ALTER COLUMN ").unwrap();
+                        column_def.name.prepare(sql, '"');
+                        write!(sql, " SET ").unwrap();
+                        self.prepare_column_spec(column_spec, sql);
+                    }
+                },
+                TableAlterOption::RenameColumn(from_name, to_name) => {
+                    write!(sql, "RENAME COLUMN ").unwrap();
+                    from_name.prepare(sql, '"');
+                    write!(sql, " TO ").unwrap();
+                    to_name.prepare(sql, '"');
+                },
+                TableAlterOption::DropColumn(column_name) => {
+                    write!(sql, "DROP COLUMN ").unwrap();
+                    column_name.prepare(sql, '"');
+                },
+                TableAlterOption::AddForeignKey(foreign_key) => {
+                    write!(sql, "ADD ").unwrap();
+                    self.prepare_foreign_key_create_statement_internal(foreign_key, sql, true);
+                },
+                TableAlterOption::DropForeignKey(name) => {
+                    write!(sql, "DROP CONSTRAINT \"{}\"", name).unwrap();
+                },
+                TableAlterOption::AddIndex(index) => {
+                    if index.primary {
+                        write!(sql, "ADD PRIMARY KEY").unwrap();
+                    } else if index.unique {
+                        write!(sql, "ADD ").unwrap();
+                        if let Some(name) = &index.index.name {
+                            write!(sql, "CONSTRAINT \"{}\" ", name).unwrap();
+                        }
+                        write!(sql, "UNIQUE").unwrap();
+                    } else {
+                        unreachable!()
+                    }
+                    self.prepare_index_columns(&index.index.columns, sql);
+                },
+            }
+            false
+        });
     }
 
     fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
@@ -0,0 +1,221 @@
+use std::fmt;
+use super::*;
+use crate::discovery::*;
+
+/// Identifiers of `sqlite_master` and the `PRAGMA` table-valued functions
+#[derive(Debug, Clone, Copy)]
+enum SqliteMaster {
+    Master,
+    Table,
+    Type,
+    Name,
+    Sql,
+    Cid,
+    Notnull,
+    DfltValue,
+    Pk,
+    Seq,
+    Unique,
+    Origin,
+    Seqno,
+    Id,
+    From,
+    To,
+    OnUpdate,
+    OnDelete,
+}
+
+impl Iden for SqliteMaster {
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Master => "sqlite_master",
+            Self::Table => "table",
+            Self::Type => "type",
+            Self::Name => "name",
+            Self::Sql => "sql",
+            Self::Cid => "cid",
+            Self::Notnull => "notnull",
+            Self::DfltValue => "dflt_value",
+            Self::Pk => "pk",
+            Self::Seq => "seq",
+            Self::Unique => "unique",
+            Self::Origin => "origin",
+            Self::Seqno => "seqno",
+            Self::Id => "id",
+            Self::From => "from",
+            Self::To => "to",
+            Self::OnUpdate => "on_update",
+            Self::OnDelete => "on_delete",
+        }).unwrap();
+    }
+}
+
+/// Table-valued pragma function call, e.g. `pragma_table_info('font')`, rendered without quotes
+#[derive(Debug)]
+struct Pragma(&'static str, String);
+
+impl Iden for Pragma {
+    fn prepare(&self, s: &mut dyn fmt::Write, _q: char) {
+        self.unquoted(s);
+    }
+
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "pragma_{}({})", self.0, self.1).unwrap();
+    }
+}
+
+fn quote_table(table: &str) -> String {
+    format!("'{}'", table.replace('\'', "''"))
+}
+
+impl DiscoveryBuilder for SqliteQueryBuilder {
+    fn query_tables(&self, _schema: &str) -> SelectStatement {
+        Query::select()
+            .column(SqliteMaster::Name)
+            .from(SqliteMaster::Master)
+            .and_where(Expr::col(SqliteMaster::Type).eq("table"))
+            .and_where(Expr::expr(Expr::col(SqliteMaster::Name).like("sqlite_%")).not())
+            .order_by(SqliteMaster::Name, Order::Asc)
+            .take()
+    }
+
+    fn query_table_exists(&self, table: &str) -> SelectStatement {
+        Query::select()
+            .column(SqliteMaster::Name)
+            .from(SqliteMaster::Master)
+            .and_where(Expr::col(SqliteMaster::Type).eq("table"))
+            .and_where(Expr::col(SqliteMaster::Name).eq(table))
+            .take()
+    }
+
+    fn query_columns(&self, _schema: &str, table: &str) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                SqliteMaster::Name,
+                SqliteMaster::Type,
+                SqliteMaster::Notnull,
+                SqliteMaster::DfltValue,
+                SqliteMaster::Pk,
+            ])
+            .expr(SimpleExpr::SubQuery(None, Box::new(
+                Query::select()
+                    .column(SqliteMaster::Sql)
+                    .from(SqliteMaster::Master)
+                    .and_where(Expr::col(SqliteMaster::Type).eq("table"))
+                    .and_where(Expr::col(SqliteMaster::Name).eq(table))
+                    .take()
+            )))
+            .from(Pragma("table_info", quote_table(table)))
+            .order_by(SqliteMaster::Cid, Order::Asc)
+            .take()
+    }
+
+    fn query_indexes(&self, _schema: &str, table: &str) -> SelectStatement {
+        let il = Alias::new("il");
+        let ii = Alias::new("ii");
+        let mut select = Query::select()
+            .column((il.clone(), SqliteMaster::Name))
+            .column((il.clone(), SqliteMaster::Unique))
+            .column((il.clone(), SqliteMaster::Origin))
+            .column((ii.clone(), SqliteMaster::Name))
+            .from_as(Pragma("index_list", quote_table(table)), il.clone())
+            .order_by((il, SqliteMaster::Seq), Order::Asc)
+            .order_by((ii.clone(), SqliteMaster::Seqno), Order::Asc)
+            .take();
+        // The table function refers to the preceding one, so no join condition is needed
+        select.join.push(JoinExpr {
+            join: JoinType::Join,
+            table: Box::new(Pragma("index_info", "`il`.`name`".to_owned()).into_table_ref().alias(ii.into_iden())),
+            on: None,
+            natural: false,
+            lateral: false,
+        });
+        select
+    }
+
+    fn query_foreign_keys(&self, _schema: &str, table: &str) -> SelectStatement {
+        Query::select()
+            .columns(vec![
+                SqliteMaster::Id,
+                SqliteMaster::From,
+                SqliteMaster::Table,
+                SqliteMaster::To,
+                SqliteMaster::OnUpdate,
+                SqliteMaster::OnDelete,
+            ])
+            .from(Pragma("foreign_key_list", quote_table(table)))
+            .order_by(SqliteMaster::Id, Order::Asc)
+            .order_by(SqliteMaster::Seq, Order::Asc)
+            .take()
+    }
+
+    fn parse_table(&self, table: &str, columns: &[Vec<Value>], indexes: &[Vec<Value>], foreign_keys: &[Vec<Value>]) -> TableDef {
+        let mut table_def = TableDef::new(Alias::new(table));
+        let mut primary_key = Vec::new();
+        let autoincrement = columns.first()
+            .and_then(|row| row_string(row, 5))
+            .map(|sql| sql.to_uppercase().contains("AUTOINCREMENT"))
+            .unwrap_or(false);
+
+        for row in columns.iter() {
+            let name = row_string(row, 0).unwrap_or_default();
+            let mut column = ColumnDef::new(Alias::new(&name));
+            column.types = row_string(row, 1).filter(|t| !t.is_empty()).map(|t| parse_column_type(&t));
+            if row_bool(row, 2) {
+                column.spec.push(ColumnSpec::NotNull);
+            }
+            if let Some(default) = row_string(row, 3) {
+                column.spec.push(parse_default(&default));
+            }
+            if let Some(position) = row_u32(row, 4).filter(|pk| *pk > 0) {
+                primary_key.push((position, name));
+            }
+            table_def.columns.push(column);
+        }
+
+        if !primary_key.is_empty() {
+            primary_key.sort();
+            if autoincrement && primary_key.len() == 1 {
+                let name = &primary_key[0].1;
+                if let Some(column) = table_def.columns.iter_mut().find(|c| &c.name.to_string() == name) {
+                    column.spec.push(ColumnSpec::AutoIncrement);
+                }
+            }
+            let mut index = index_create(table, None, primary_key.iter().map(|(_, name)| index_column(name, None)).collect());
+            index.primary = true;
+            table_def.primary_key = Some(index);
+        }
+
+        for (name, rows) in group_rows(indexes, 0) {
+            let columns: Vec<IndexColumn> = rows.iter()
+                .filter_map(|row| row_string(row, 3).map(|column| index_column(&column, None)))
+                .collect();
+            match row_string(rows[0], 2).as_deref() {
+                // Covered by the primary key above
+                Some("pk") => continue,
+                // Implicit index of a UNIQUE constraint, which cannot be created by name
+                Some("u") if columns.len() == 1 => {
+                    let name = columns[0].name.to_string();
+                    if let Some(column) = table_def.columns.iter_mut().find(|c| c.name.to_string() == name) {
+                        column.spec.push(ColumnSpec::UniqueKey);
+                    }
+                    continue;
+                },
+                Some("u") => {
+                    let name = format!("{}_{}_key", table, columns.iter().map(|c| c.name.to_string()).collect::<Vec<_>>().join("_"));
+                    let mut index = index_create(table, Some(name), columns);
+                    index.unique = true;
+                    table_def.indexes.push(index);
+                },
+                _ => {
+                    let mut index = index_create(table, name, columns);
+                    index.unique = row_bool(rows[0], 1);
+                    table_def.indexes.push(index);
+                },
+            }
+        }
+
+        table_def.foreign_keys = parse_foreign_keys(table, foreign_keys);
+        table_def
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) mod query;
 pub(crate) mod table;
 pub(crate) mod index;
+pub(crate) mod discovery;
 pub(crate) mod foreign_key;
 
 use super::*;
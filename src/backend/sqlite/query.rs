@@ -36,17 +36,23 @@ impl QueryBuilder for SqliteQueryBuilder {
             write!(sql, ")").unwrap();
             false
         });
+
+        self.prepare_returning(&insert.returning, sql, collector);
     }
 
     fn prepare_select_statement(&self, select: &SelectStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         write!(sql, "SELECT ").unwrap();
 
         if let Some(distinct) = &select.distinct {
-            write!(sql, " ").unwrap();
             self.prepare_select_distinct(distinct, sql, collector);
             write!(sql, " ").unwrap();
         }
 
+        if !select.distinct_on.is_empty() {
+            self.prepare_select_distinct_on(&select.distinct_on, sql, collector);
+            write!(sql, " ").unwrap();
+        }
+
         select.selects.iter().fold(true, |first, expr| {
             if !first {
                 write!(sql, ", ").unwrap()
@@ -67,30 +73,27 @@ impl QueryBuilder for SqliteQueryBuilder {
             }
         }
 
-        if !select.wherei.is_empty() {
-            write!(sql, " WHERE ").unwrap();
-        }
-        for (i, log_chain_oper) in select.wherei.iter().enumerate() {
-            self.prepare_logical_chain_oper(log_chain_oper, i, select.wherei.len(), sql, collector);
-        }
+        self.prepare_condition(&select.wherei, "WHERE", sql, collector);
 
-        if !select.groups.is_empty() {
+        if !select.groups.is_empty() || !select.grouping_sets.is_empty() {
             write!(sql, " GROUP BY ").unwrap();
-            select.groups.iter().fold(true, |first, expr| {
+            let first = select.groups.iter().fold(true, |first, expr| {
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
                 self.prepare_simple_expr(expr, sql, collector);
                 false
             });
+            select.grouping_sets.iter().fold(first, |first, grouping_set| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_grouping_set(grouping_set, sql, collector);
+                false
+            });
         }
 
-        if !select.having.is_empty() {
-            write!(sql, " HAVING ").unwrap();
-        }
-        for (i, log_chain_oper) in select.having.iter().enumerate() {
-            self.prepare_logical_chain_oper(log_chain_oper, i, select.having.len(), sql, collector);
-        }
+        self.prepare_condition(&select.having, "HAVING", sql, collector);
 
         if !select.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -112,6 +115,10 @@ impl QueryBuilder for SqliteQueryBuilder {
             write!(sql, " OFFSET ").unwrap();
             self.prepare_value(offset, sql, collector);
         }
+
+        if let Some(lock) = &select.lock {
+            self.prepare_select_lock(lock, sql, collector);
+        }
     }
 
     fn prepare_update_statement(&self, update: &UpdateStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
@@ -133,11 +140,14 @@ impl QueryBuilder for SqliteQueryBuilder {
             false
         });
 
-        if let Some(wherei) = &update.wherei {
-            write!(sql, " WHERE ").unwrap();
-            self.prepare_simple_expr(wherei, sql, collector);
+        if update.join.is_empty() {
+            self.prepare_condition(&update.wherei, "WHERE", sql, collector);
+        } else {
+            self.prepare_join_tables(&update.join, &update.wherei, "FROM", sql, collector);
         }
 
+        self.prepare_returning(&update.returning, sql, collector);
+
         if !update.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
             update.orders.iter().fold(true, |first, expr| {
@@ -156,17 +166,20 @@ impl QueryBuilder for SqliteQueryBuilder {
     }
 
     fn prepare_delete_statement(&self, delete: &DeleteStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if !delete.join.is_empty() {
+            panic!("Sqlite does not support joins in delete statements");
+        }
+
         write!(sql, "DELETE ").unwrap();
-        
+
         if let Some(table) = &delete.table {
             write!(sql, "FROM ").unwrap();
             self.prepare_table_ref(table, sql, collector);
         }
 
-        if let Some(wherei) = &delete.wherei {
-            write!(sql, " WHERE ").unwrap();
-            self.prepare_simple_expr(wherei, sql, collector);
-        }
+        self.prepare_condition(&delete.wherei, "WHERE", sql, collector);
+
+        self.prepare_returning(&delete.returning, sql, collector);
 
         if !delete.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -200,30 +213,62 @@ impl QueryBuilder for SqliteQueryBuilder {
             SimpleExpr::Unary(op, expr) => {
                 self.prepare_un_oper(op, sql, collector);
                 write!(sql, " ").unwrap();
+                let paren = expr.need_parentheses_under(op);
+                if paren {
+                    write!(sql, "(").unwrap();
+                }
                 self.prepare_simple_expr(expr, sql, collector);
+                if paren {
+                    write!(sql, ")").unwrap();
+                }
             },
-            SimpleExpr::FunctionCall(func, exprs) => {
-                self.prepare_function(func, sql, collector);
-                write!(sql, "(").unwrap();
-                exprs.iter().fold(true, |first, expr| {
-                    if !first {
-                        write!(sql, ", ").unwrap();
-                    }
-                    self.prepare_simple_expr(expr, sql, collector);
-                    false
-                });
-                write!(sql, ")").unwrap();
+            SimpleExpr::FunctionCall(func, exprs, modifiers) => {
+                self.prepare_function_call(func, exprs, modifiers, sql, collector);
             },
             SimpleExpr::Binary(left, op, right) => {
-                if *op == BinOper::In && right.is_values() && right.get_values().is_empty() {
+                if *op == BinOper::In && right.is_empty_list() {
                     self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(2.into()), sql, collector);
-                } else if *op == BinOper::NotIn && right.is_values() && right.get_values().is_empty() {
+                } else if *op == BinOper::NotIn && right.is_empty_list() {
                     self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(1.into()), sql, collector);
+                } else if matches!(op, BinOper::Matches | BinOper::MatchesBoolean) && left.full_text_columns().len() > 1 {
+                    panic!("Sqlite does not support full-text search on multiple columns, search the FTS5 table instead");
+                } else if SimpleExpr::is_json_oper(op) {
+                    self.prepare_json_expr(left, op, right, sql, collector);
+                } else if matches!(op, BinOper::ILike | BinOper::NotILike) {
+                    self.prepare_ilike(left, op, right, sql, collector);
+                } else if matches!(op, BinOper::In | BinOper::NotIn) && right.is_tuple_list() {
+                    self.prepare_simple_expr(left, sql, collector);
+                    write!(sql, " ").unwrap();
+                    self.prepare_bin_oper(op, sql, collector);
+                    write!(sql, " (VALUES ").unwrap();
+                    if let SimpleExpr::Tuple(rows) = right.as_ref() {
+                        rows.iter().fold(true, |first, row| {
+                            if !first {
+                                write!(sql, ", ").unwrap();
+                            }
+                            self.prepare_simple_expr(row, sql, collector);
+                            false
+                        });
+                    }
+                    write!(sql, ")").unwrap();
+                } else if let SimpleExpr::SubQuery(Some(oper @ (SubQueryOper::Any | SubQueryOper::Some | SubQueryOper::All)), sel) = right.as_ref() {
+                    let op = match (op, oper) {
+                        (BinOper::Equal, SubQueryOper::Any) | (BinOper::Equal, SubQueryOper::Some) => BinOper::In,
+                        (BinOper::NotEqual, SubQueryOper::All) => BinOper::NotIn,
+                        _ => panic!("Sqlite only supports `= ANY` and `<> ALL` sub-query comparisons"),
+                    };
+                    self.binary_expr(left, &op, &SimpleExpr::SubQuery(None, sel.clone()), sql, collector);
                 } else {
                     self.binary_expr(left, op, right, sql, collector);
                 }
             },
-            SimpleExpr::SubQuery(sel) => {
+            SimpleExpr::SubQuery(oper, sel) => {
+                if let Some(oper) = oper {
+                    if *oper != SubQueryOper::Exists {
+                        panic!("Sqlite only supports `= ANY` and `<> ALL` sub-query comparisons");
+                    }
+                    self.prepare_sub_query_oper(oper, sql, collector);
+                }
                 write!(sql, "(").unwrap();
                 self.prepare_select_statement(sel, sql, collector);
                 write!(sql, ")").unwrap();
@@ -231,6 +276,17 @@ impl QueryBuilder for SqliteQueryBuilder {
             SimpleExpr::Value(val) => {
                 self.prepare_value(val, sql, collector);
             },
+            SimpleExpr::Tuple(exprs) => {
+                write!(sql, "(").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            },
             SimpleExpr::Values(list) => {
                 write!(sql, "(").unwrap();
                 list.iter().fold(true, |first, val| {
@@ -262,9 +318,22 @@ impl QueryBuilder for SqliteQueryBuilder {
                     }
                 }
             },
+            SimpleExpr::CustomWithExprs(expr, exprs) => {
+                self.prepare_custom_with_exprs(expr, exprs, sql, collector);
+            },
             SimpleExpr::Keyword(keyword) => {
                 self.prepare_keyword(keyword, sql, collector);
             },
+            SimpleExpr::Case(case) => {
+                self.prepare_case_statement(case, sql, collector);
+            },
+            SimpleExpr::Cast(expr, column_type) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_simple_expr(expr, sql, collector);
+                write!(sql, " AS ").unwrap();
+                self.prepare_cast_type(column_type, sql);
+                write!(sql, ")").unwrap();
+            },
         }
     }
 
@@ -276,6 +345,10 @@ impl QueryBuilder for SqliteQueryBuilder {
         }).unwrap();
     }
 
+    fn prepare_select_distinct_on(&self, _exprs: &[SimpleExpr], _sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        panic!("Sqlite does not support DISTINCT ON");
+    }
+
     fn prepare_select_expr(&self, select_expr: &SelectExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         self.prepare_simple_expr(&select_expr.expr, sql, collector);
         match &select_expr.alias {
@@ -288,11 +361,16 @@ impl QueryBuilder for SqliteQueryBuilder {
     }
 
     fn prepare_join_expr(&self, join_expr: &JoinExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if join_expr.natural {
+            write!(sql, "NATURAL ").unwrap();
+        }
         self.prepare_join_type(&join_expr.join, sql, collector);
         write!(sql, " ").unwrap();
+        if join_expr.lateral {
+            panic!("Sqlite does not support LATERAL joins");
+        }
         self.prepare_table_ref(&join_expr.table, sql, collector);
         if let Some(on) = &join_expr.on {
-            write!(sql, " ").unwrap();
             self.prepare_join_on(on, sql, collector);
         }
     }
@@ -332,6 +410,16 @@ impl QueryBuilder for SqliteQueryBuilder {
     fn prepare_un_oper(&self, un_oper: &UnOper, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         write!(sql, "{}", match un_oper {
             UnOper::Not => "NOT",
+            UnOper::Neg => "-",
+        }).unwrap();
+    }
+
+    fn prepare_sub_query_oper(&self, oper: &SubQueryOper, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        write!(sql, "{}", match oper {
+            SubQueryOper::Exists => "EXISTS",
+            SubQueryOper::Any => "ANY",
+            SubQueryOper::Some => "SOME",
+            SubQueryOper::All => "ALL",
         }).unwrap();
     }
 
@@ -357,6 +445,26 @@ impl QueryBuilder for SqliteQueryBuilder {
             BinOper::Sub => "-",
             BinOper::Mul => "*",
             BinOper::Div => "/",
+            BinOper::Mod => "%",
+            BinOper::Concat => "||",
+            BinOper::BitAnd => "&",
+            BinOper::BitOr => "|",
+            BinOper::LShift => "<<",
+            BinOper::RShift => ">>",
+            BinOper::ILike => "LIKE",
+            BinOper::NotILike => "NOT LIKE",
+            BinOper::Escape => "ESCAPE",
+            BinOper::SimilarTo | BinOper::NotSimilarTo => panic!("Sqlite does not support SIMILAR TO"),
+            BinOper::Regexp => "REGEXP",
+            BinOper::NotRegexp => "NOT REGEXP",
+            BinOper::IsDistinctFrom => "IS NOT",
+            BinOper::IsNotDistinctFrom => "IS",
+            BinOper::JsonGet | BinOper::JsonGetText | BinOper::JsonGetPath | BinOper::JsonGetPathText |
+            BinOper::JsonContains | BinOper::JsonContainedBy |
+            BinOper::JsonHasKey | BinOper::JsonHasAnyKey | BinOper::JsonHasAllKeys => {
+                unreachable!("JSON operators are expressed by functions in Sqlite")
+            },
+            BinOper::Matches | BinOper::MatchesBoolean => "MATCH",
         }).unwrap();
     }
 
@@ -382,6 +490,55 @@ impl QueryBuilder for SqliteQueryBuilder {
         }
     }
 
+    fn prepare_condition(&self, condition: &ConditionHolder, keyword: &str, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if condition.is_empty() {
+            return;
+        }
+        write!(sql, " {} ", keyword).unwrap();
+        match &condition.contents {
+            ConditionHolderContents::Empty => {},
+            ConditionHolderContents::Condition(c) => {
+                self.prepare_condition_where(c, sql, collector);
+            },
+        }
+    }
+
+    fn prepare_condition_where(&self, condition: &Condition, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if condition.negate {
+            write!(sql, "NOT (").unwrap();
+        }
+        if condition.is_empty() {
+            let always = match condition.condition_type {
+                ConditionType::All => 1,
+                ConditionType::Any => 2,
+            };
+            self.binary_expr(&SimpleExpr::Value(1.into()), &BinOper::Equal, &SimpleExpr::Value(always.into()), sql, collector);
+        }
+        condition.conditions.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, " {} ", match condition.condition_type {
+                    ConditionType::All => "AND",
+                    ConditionType::Any => "OR",
+                }).unwrap();
+            }
+            let need_parentheses = condition.len() > 1 && expr.need_parentheses(condition.condition_type);
+            if need_parentheses {
+                write!(sql, "(").unwrap();
+            }
+            match expr {
+                ConditionExpression::Condition(c) => self.prepare_condition_where(c, sql, collector),
+                ConditionExpression::SimpleExpr(e) => self.prepare_simple_expr(e, sql, collector),
+            }
+            if need_parentheses {
+                write!(sql, ")").unwrap();
+            }
+            false
+        });
+        if condition.negate {
+            write!(sql, ")").unwrap();
+        }
+    }
+
     fn prepare_function(&self, function: &Function, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         if let Function::Custom(iden) = function {
             iden.unquoted(sql);
@@ -394,6 +551,28 @@ impl QueryBuilder for SqliteQueryBuilder {
                 Function::Count => "COUNT",
                 Function::IfNull => "IFNULL",
                 Function::CharLength => "LENGTH",
+                Function::Grouping => panic!("Sqlite does not support GROUPING function"),
+                Function::JsonBuildObject => "json_object",
+                Function::JsonAgg => "json_group_array",
+                Function::JsonbSet => panic!("Sqlite does not support jsonb_set function"),
+                Function::StringAgg => "GROUP_CONCAT",
+                Function::ArrayAgg => panic!("Sqlite does not support arrays, use json_agg instead"),
+                Function::MatchRank => panic!("Sqlite does not support full-text rank, order by the `rank` column of FTS5 tables instead"),
+                Function::Coalesce => "COALESCE",
+                Function::Lower => "LOWER",
+                Function::Upper => "UPPER",
+                Function::Trim => "TRIM",
+                Function::Substring => "SUBSTR",
+                Function::Concat => unreachable!("Concatenation is expressed by || in Sqlite"),
+                Function::Replace => "REPLACE",
+                Function::Abs => "ABS",
+                Function::Round => "ROUND",
+                Function::Greatest => "MAX",
+                Function::Least => "MIN",
+                Function::Now => "CURRENT_TIMESTAMP",
+                Function::CurrentDate => "CURRENT_DATE",
+                Function::DateTrunc(_) | Function::Extract(_) => "strftime",
+                Function::Random => "RANDOM",
                 Function::Custom(_) => "",
             }).unwrap();
         }
@@ -404,26 +583,86 @@ impl QueryBuilder for SqliteQueryBuilder {
             JoinType::Join => "JOIN",
             JoinType::InnerJoin => "INNER JOIN",
             JoinType::LeftJoin => "LEFT JOIN",
-            JoinType::RightJoin => "RIGHT JOIN",
+            JoinType::RightJoin => panic!("Sqlite does not support RIGHT JOIN before 3.39"),
+            JoinType::FullOuterJoin => panic!("Sqlite does not support FULL OUTER JOIN before 3.39"),
+            JoinType::CrossJoin => "CROSS JOIN",
         }).unwrap()
     }
 
     fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        self.prepare_simple_expr(&order_expr.expr, sql, collector);
+        if let Some(values) = &order_expr.field {
+            write!(sql, "CASE").unwrap();
+            for (i, value) in values.iter().enumerate() {
+                write!(sql, " WHEN ").unwrap();
+                self.prepare_collated_expr(order_expr, sql, collector);
+                write!(sql, " = ").unwrap();
+                self.prepare_value(value, sql, collector);
+                write!(sql, " THEN {}", i + 1).unwrap();
+            }
+            write!(sql, " ELSE 0 END").unwrap();
+        } else {
+            self.prepare_collated_expr(order_expr, sql, collector);
+        }
         write!(sql, " ").unwrap();
         self.prepare_order(&order_expr.order, sql, collector);
+        if let Some(nulls) = order_expr.nulls {
+            write!(sql, " {}", match nulls {
+                NullOrdering::First => "NULLS FIRST",
+                NullOrdering::Last => "NULLS LAST",
+            }).unwrap();
+        }
+    }
+
+    fn prepare_grouping_set(&self, _grouping_set: &GroupingSet, _sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        panic!("Sqlite does not support ROLLUP, CUBE and GROUPING SETS");
     }
 
     fn prepare_join_on(&self, join_on: &JoinOn, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         match join_on {
             JoinOn::Condition(c) => {
-                write!(sql, "ON ").unwrap();
-                self.prepare_simple_expr(c, sql, collector);
+                if c.is_empty() {
+                    // The syntax requires a join condition even if nothing is filtered
+                    write!(sql, " ON ").unwrap();
+                    self.prepare_condition_where(&Condition::all(), sql, collector);
+                } else {
+                    self.prepare_condition(c, "ON", sql, collector);
+                }
+            },
+            JoinOn::Columns(columns) => {
+                write!(sql, " USING (").unwrap();
+                columns.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
             },
-            JoinOn::Columns(_c) => unimplemented!(),
         }
     }
 
+    fn prepare_select_lock(&self, lock: &LockClause, _sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        // Sqlite locks the whole database within a transaction, there is no row locking to express
+        if !lock.optional {
+            panic!("Sqlite does not support row locking, use `lock_optional` to omit the lock clause");
+        }
+    }
+
+    fn prepare_returning(&self, returning: &[SelectExpr], sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if returning.is_empty() {
+            return;
+        }
+        write!(sql, " RETURNING ").unwrap();
+        returning.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_select_expr(expr, sql, collector);
+            false
+        });
+    }
+
     fn prepare_order(&self, order: &Order, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
         match order {
             Order::Asc => {
@@ -436,7 +675,8 @@ impl QueryBuilder for SqliteQueryBuilder {
     }
 
     fn prepare_value(&self, value: &Value, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        sql.push_param("?", false);
+        let (sign, numbered) = self.placeholder();
+        sql.push_param(sign, numbered);
         collector(value.clone());
     }
 
@@ -451,12 +691,202 @@ impl QueryBuilder for SqliteQueryBuilder {
         }
     }
 
+    fn prepare_case_statement(&self, case: &CaseStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        if case.when.is_empty() {
+            panic!("No case condition found")
+        }
+        write!(sql, "CASE").unwrap();
+        for when in case.when.iter() {
+            write!(sql, " WHEN ").unwrap();
+            self.prepare_simple_expr(&when.condition, sql, collector);
+            write!(sql, " THEN ").unwrap();
+            self.prepare_simple_expr(&when.result, sql, collector);
+        }
+        if let Some(r#else) = &case.r#else {
+            write!(sql, " ELSE ").unwrap();
+            self.prepare_simple_expr(r#else, sql, collector);
+        }
+        write!(sql, " END").unwrap();
+    }
+
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
+        write!(sql, "{}", match column_type {
+            ColumnType::Char(_)
+            | ColumnType::String(_)
+            | ColumnType::Text
+            | ColumnType::DateTime(_)
+            | ColumnType::Timestamp(_)
+            | ColumnType::Time(_)
+            | ColumnType::Date
+            | ColumnType::Json
+            | ColumnType::JsonBinary => "TEXT".into(),
+            ColumnType::TinyInteger(_)
+            | ColumnType::SmallInteger(_)
+            | ColumnType::Integer(_)
+            | ColumnType::BigInteger(_)
+            | ColumnType::Boolean => "INTEGER".into(),
+            ColumnType::Float(_) | ColumnType::Double(_) => "REAL".into(),
+            ColumnType::Decimal(_) | ColumnType::Money(_) => "NUMERIC".into(),
+            ColumnType::Binary(_) => "BLOB".into(),
+            ColumnType::Custom(iden) => iden.to_string(),
+        }).unwrap()
+    }
+
+    fn placeholder(&self) -> (&str, bool) {
+        ("?", false)
+    }
+
     fn value_to_string(&self, v: &Value) -> String {
         sqlite_value_to_string(v)
     }
 }
 
 impl SqliteQueryBuilder {
+    /// The expression of an [`OrderExpr`], followed by its collation
+    fn prepare_collated_expr(&self, order_expr: &OrderExpr, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        self.prepare_simple_expr(&order_expr.expr, sql, collector);
+        if let Some(collation) = &order_expr.collation {
+            write!(sql, " COLLATE ").unwrap();
+            collation.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_function_call(&self, func: &Function, exprs: &[SimpleExpr], modifiers: &AggregateModifiers,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match func {
+            Function::Now | Function::CurrentDate => self.prepare_function(func, sql, collector),
+            Function::Concat => {
+                write!(sql, "(").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, " || ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            },
+            Function::DateTrunc(field) => {
+                self.prepare_function(func, sql, collector);
+                write!(sql, "('{}', ", match field {
+                    DateTimeField::Year => "%Y-01-01 00:00:00",
+                    DateTimeField::Month => "%Y-%m-01 00:00:00",
+                    DateTimeField::Day => "%Y-%m-%d 00:00:00",
+                    DateTimeField::Hour => "%Y-%m-%d %H:00:00",
+                    DateTimeField::Minute => "%Y-%m-%d %H:%M:00",
+                    DateTimeField::Second => "%Y-%m-%d %H:%M:%S",
+                }).unwrap();
+                self.prepare_simple_expr(&exprs[0], sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            Function::Extract(field) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_function(func, sql, collector);
+                write!(sql, "('{}', ", match field {
+                    DateTimeField::Year => "%Y",
+                    DateTimeField::Month => "%m",
+                    DateTimeField::Day => "%d",
+                    DateTimeField::Hour => "%H",
+                    DateTimeField::Minute => "%M",
+                    DateTimeField::Second => "%S",
+                }).unwrap();
+                self.prepare_simple_expr(&exprs[0], sql, collector);
+                write!(sql, ") AS INTEGER)").unwrap();
+            },
+            Function::Random => {
+                write!(sql, "ABS(").unwrap();
+                self.prepare_function(func, sql, collector);
+                write!(sql, "() / 9223372036854775808.0)").unwrap();
+            },
+            _ => {
+                if modifiers.distinct && exprs.len() > 1 {
+                    panic!("Sqlite only supports DISTINCT in aggregate functions with a single argument");
+                }
+                if !modifiers.order_by.is_empty() {
+                    panic!("Sqlite does not support ORDER BY in aggregate functions before 3.44");
+                }
+                self.prepare_function(func, sql, collector);
+                write!(sql, "(").unwrap();
+                if modifiers.distinct {
+                    write!(sql, "DISTINCT ").unwrap();
+                }
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+                if let Some(filter) = &modifiers.filter {
+                    write!(sql, " FILTER (WHERE ").unwrap();
+                    self.prepare_simple_expr(filter, sql, collector);
+                    write!(sql, ")").unwrap();
+                }
+            },
+        }
+    }
+
+    /// `ILIKE` by comparing both sides in lower case
+    fn prepare_ilike(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let (pattern, escape) = match right {
+            SimpleExpr::Binary(pattern, BinOper::Escape, escape) => (pattern.as_ref(), Some(escape)),
+            _ => (right, None),
+        };
+        write!(sql, "LOWER(").unwrap();
+        self.prepare_simple_expr(left, sql, collector);
+        write!(sql, ") ").unwrap();
+        self.prepare_bin_oper(op, sql, collector);
+        write!(sql, " LOWER(").unwrap();
+        self.prepare_simple_expr(pattern, sql, collector);
+        write!(sql, ")").unwrap();
+        if let Some(escape) = escape {
+            write!(sql, " ").unwrap();
+            self.prepare_bin_oper(&BinOper::Escape, sql, collector);
+            write!(sql, " ").unwrap();
+            self.prepare_simple_expr(escape, sql, collector);
+        }
+    }
+
+    fn prepare_json_expr(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        match op {
+            BinOper::JsonGet | BinOper::JsonGetText | BinOper::JsonGetPath | BinOper::JsonGetPathText => {
+                write!(sql, "json_extract(").unwrap();
+                self.prepare_simple_expr(left, sql, collector);
+                write!(sql, ", ").unwrap();
+                self.prepare_value(&right.json_paths(op).remove(0).into(), sql, collector);
+                write!(sql, ")").unwrap();
+            },
+            _ => panic!("Sqlite does not support JSON containment and key existence operators"),
+        }
+    }
+
+    /// List the joined tables after `keyword`, moving their join conditions into the `WHERE` clause
+    fn prepare_join_tables(&self, joins: &[JoinExpr], wherei: &ConditionHolder, keyword: &str,
+        sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let mut condition = ConditionHolder::new();
+        write!(sql, " {} ", keyword).unwrap();
+        joins.iter().fold(true, |first, join_expr| {
+            if !matches!(join_expr.join, JoinType::Join | JoinType::InnerJoin) {
+                panic!("Sqlite only supports inner joins in update and delete statements");
+            }
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_table_ref(&join_expr.table, sql, collector);
+            match &join_expr.on {
+                Some(JoinOn::Condition(c)) => condition.add_condition(c.to_condition()),
+                Some(JoinOn::Columns(_)) => panic!("Sqlite does not support joining on USING columns in update and delete statements"),
+                None => {},
+            }
+            false
+        });
+        condition.add_condition(wherei.to_condition());
+        self.prepare_condition(&condition, "WHERE", sql, collector);
+    }
+
     fn binary_expr(&self, left: &SimpleExpr, op: &BinOper, right: &SimpleExpr,
         sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
         let no_paren = matches!(op, BinOper::Equal | BinOper::NotEqual);
@@ -474,10 +904,11 @@ impl SqliteQueryBuilder {
         write!(sql, " ").unwrap();
         self.prepare_bin_oper(op, sql, collector);
         write!(sql, " ").unwrap();
-        let no_right_paren = matches!(op, BinOper::Between | BinOper::NotBetween);
+        let no_right_paren = matches!(op, BinOper::Between | BinOper::NotBetween) ||
+            right.get_bin_oper() == Some(BinOper::Escape);
         let right_paren =
             (right.need_parentheses() ||
-                right.is_binary() && *op != left.get_bin_oper().unwrap()) &&
+                right.is_binary() && left.get_bin_oper() != Some(*op)) &&
             !no_right_paren &&
             !no_paren;
         if right_paren {
@@ -505,14 +936,19 @@ pub fn sqlite_value_to_string(v: &Value) -> String {
         Value::BigUnsigned(v) => write!(s, "{}", v).unwrap(),
         Value::Float(v) => write!(s, "{}", v).unwrap(),
         Value::Double(v) => write!(s, "{}", v).unwrap(),
-        Value::String(v) => write!(s, "\'{}\'", escape_string(v)).unwrap(),
-        Value::Bytes(v) => write!(s, "x\'{}\'", v.iter().map(|b| format!("{:02X}", b)).collect::<String>()).unwrap(),
+        Value::String(v) => write!(s, "{}", sqlite_escape_string_quoted(v)).unwrap(),
+        Value::Bytes(v) => write!(s, "X\'{}\'", v.iter().map(|b| format!("{:02X}", b)).collect::<String>()).unwrap(),
         #[cfg(feature="with-json")]
-        Value::Json(v) => write!(s, "\'{}\'", escape_string(&v.to_string())).unwrap(),
+        Value::Json(v) => write!(s, "{}", sqlite_escape_string_quoted(&v.to_string())).unwrap(),
         #[cfg(feature="with-chrono")]
         Value::DateTime(v) => write!(s, "\'{}\'", v.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap(),
         #[cfg(feature="with-uuid")]
         Value::Uuid(v) => write!(s, "\'{}\'", v.to_string()).unwrap(),
     };
     s
-}
\ No newline at end of file
+}
+
+/// Quote a string literal, doubling single quotes. Backslash has no special meaning in Sqlite.
+pub fn sqlite_escape_string_quoted(string: &str) -> String {
+    "'".to_owned() + &string.replace('\'', "''") + "'"
+}
@@ -1,8 +1,20 @@
+use std::rc::Rc;
 use super::*;
 use super::query::sqlite_value_to_string;
 
+/// Options accepted by the FTS5 module, written unquoted
+const FTS5_OPTIONS: [&str; 10] = [
+    "tokenize", "prefix", "content", "content_rowid", "columnsize", "detail",
+    "contentless_delete", "contentless_unindexed", "tokendata", "locale",
+];
+
 impl TableBuilder for SqliteQueryBuilder {
     fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        if let Some(options) = &create.fts5 {
+            self.prepare_fts5_table_create_statement(create, options, sql);
+            return;
+        }
+
         write!(sql, "CREATE TABLE ").unwrap();
 
         if create.if_not_exists {
@@ -209,11 +221,34 @@ impl TableBuilder for SqliteQueryBuilder {
         }
     }
 
+    fn split_table_alter_statement(&self, alter: &TableAlterStatement) -> Vec<TableAlterStatement> {
+        alter.split()
+    }
+
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
-        let alter_option = match &alter.alter_option {
-            Some(alter_option) => alter_option,
-            None => panic!("No alter option found"),
+        if alter.options.is_empty() {
+            panic!("No alter option found")
         };
+        if alter.options.len() > 1 {
+            alter.split().iter().fold(true, |first, alter| {
+                if !first {
+                    write!(sql, "; ").unwrap();
+                }
+                self.prepare_table_alter_statement(alter, sql);
+                false
+            });
+            return;
+        }
+        let alter_option = &alter.options[0];
+        if let TableAlterOption::AddIndex(index) = alter_option {
+            if index.primary {
+                panic!("Sqlite not support adding primary key to existing table")
+            }
+            let mut index = index.clone();
+            index.table = alter.table.clone();
+            self.prepare_index_create_statement(&index, sql);
+            return;
+        }
         write!(sql, "ALTER TABLE ").unwrap();
         if let Some(table) = &alter.table {
             table.prepare(sql, '`');
@@ -236,6 +271,10 @@ impl TableBuilder for SqliteQueryBuilder {
             TableAlterOption::DropColumn(_) => {
                 panic!("Sqlite not support dropping table column")
             },
+            TableAlterOption::AddForeignKey(_) | TableAlterOption::DropForeignKey(_) => {
+                panic!("Sqlite does not support modification of foreign key constraints to existing tables")
+            },
+            TableAlterOption::AddIndex(_) => unreachable!(),
         }
     }
 
@@ -249,4 +288,273 @@ impl TableBuilder for SqliteQueryBuilder {
             to_name.prepare(sql, '`');
         }
     }
-}
\ No newline at end of file
+}
+
+impl SqliteQueryBuilder {
+    /// Statements rebuilding table `current` into table `desired`, following the
+    /// [procedure recommended by SQLite](https://www.sqlite.org/lang_altertable.html#otheralter)
+    /// for schema changes `ALTER TABLE` cannot express.
+    ///
+    /// The new table is created as `sea_query_new_<table>`, rows of columns present in both tables are copied over,
+    /// then the old table is dropped and the new one renamed into place. Creating the new table fails,
+    /// and nothing is changed, if a table of that name already exists.
+    /// `indexes` are the indexes of the current table, those whose columns still exist are recreated.
+    /// `triggers` are the `CREATE TRIGGER` statements of the triggers on the current table, as found
+    /// in the `sql` column of `sqlite_master`, which are dropped along with the table and recreated.
+    /// Views are left in place, the table is renamed with `legacy_alter_table` on so that they do not
+    /// fail the rename while the table is missing.
+    ///
+    /// The statements are meant to run inside a transaction, e.g. a [`Migration`](crate::migration::Migration).
+    /// The last statements check the foreign keys of the whole database and fail on any violation,
+    /// so that the transaction can be rolled back instead of committing dangling references.
+    ///
+    /// Foreign key enforcement should be off while the statements run, otherwise dropping the table
+    /// fires the `ON DELETE` actions of the tables referencing it. `PRAGMA foreign_keys` has no effect
+    /// inside a transaction, it has to be turned off before the transaction begins, see
+    /// [`Migration::foreign_keys_off`](crate::migration::Migration::foreign_keys_off).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let current = Table::create()
+    ///     .table(Font::Table)
+    ///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+    ///     .col(ColumnDef::new(Font::Name).string().not_null())
+    ///     .col(ColumnDef::new(Font::Variant).text())
+    ///     .to_owned();
+    ///
+    /// let desired = Table::create()
+    ///     .table(Font::Table)
+    ///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+    ///     .col(ColumnDef::new(Font::Name).text())
+    ///     .to_owned();
+    ///
+    /// let indexes = vec![
+    ///     Index::create().name("idx-font-name").table(Font::Table).col(Font::Name).to_owned(),
+    ///     Index::create().name("idx-font-variant").table(Font::Table).col(Font::Variant).to_owned(),
+    /// ];
+    ///
+    /// let triggers = vec![
+    ///     "CREATE TRIGGER `font_touch` AFTER UPDATE ON `font` BEGIN SELECT 1; END".to_owned(),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     SqliteQueryBuilder.rebuild_table(&desired, &current, &indexes, &triggers),
+    ///     vec![
+    ///         "PRAGMA legacy_alter_table = ON",
+    ///         "CREATE TABLE `sea_query_new_font` ( `id` integer NOT NULL PRIMARY KEY, `name` text )",
+    ///         "INSERT INTO `sea_query_new_font` (`id`, `name`) SELECT `id`, `name` FROM `font`",
+    ///         "DROP TABLE `font`",
+    ///         "ALTER TABLE `sea_query_new_font` RENAME TO `font`",
+    ///         "PRAGMA legacy_alter_table = OFF",
+    ///         "CREATE INDEX `idx-font-name` ON `font` (`name`)",
+    ///         "CREATE TRIGGER `font_touch` AFTER UPDATE ON `font` BEGIN SELECT 1; END",
+    ///         "CREATE TEMP TABLE `sea_query_foreign_key_check` ( `violations` integer CHECK (`violations` = 0) )",
+    ///         "INSERT INTO `sea_query_foreign_key_check` SELECT COUNT(*) FROM `pragma_foreign_key_check`",
+    ///         "DROP TABLE `sea_query_foreign_key_check`",
+    ///     ]
+    /// );
+    /// ```
+    pub fn rebuild_table(&self, desired: &TableCreateStatement, current: &TableCreateStatement, indexes: &[IndexCreateStatement], triggers: &[String]) -> Vec<String> {
+        let table = desired.table.clone().or_else(|| current.table.clone())
+            .expect("No table name found");
+        let new_table: Rc<dyn Iden> = Rc::new(Alias::new(&format!("sea_query_new_{}", table.to_string())));
+        let has_column = |name: &Rc<dyn Iden>| desired.columns.iter().any(|c| c.name.to_string() == name.to_string());
+
+        let mut statements = vec!["PRAGMA legacy_alter_table = ON".to_owned()];
+
+        let mut create = desired.clone();
+        create.table = Some(new_table.clone());
+        create.if_not_exists = false;
+        statements.push(create.to_string(SqliteQueryBuilder));
+
+        let columns: Vec<&Rc<dyn Iden>> = desired.columns.iter()
+            .map(|c| &c.name)
+            .filter(|name| current.columns.iter().any(|c| c.name.to_string() == name.to_string()))
+            .collect();
+        if !columns.is_empty() {
+            let mut sql = SqlWriter::new();
+            write!(sql, "INSERT INTO ").unwrap();
+            new_table.prepare(&mut sql, '`');
+            write!(sql, " (").unwrap();
+            self.prepare_rebuild_columns(&columns, &mut sql);
+            write!(sql, ") SELECT ").unwrap();
+            self.prepare_rebuild_columns(&columns, &mut sql);
+            write!(sql, " FROM ").unwrap();
+            table.prepare(&mut sql, '`');
+            statements.push(sql.result());
+        }
+
+        let mut drop = TableDropStatement::new();
+        drop.tables.push(table.clone());
+        statements.push(drop.to_string(SqliteQueryBuilder));
+
+        let mut rename = TableRenameStatement::new();
+        rename.from_name = Some(new_table);
+        rename.to_name = Some(table.clone());
+        statements.push(rename.to_string(SqliteQueryBuilder));
+        statements.push("PRAGMA legacy_alter_table = OFF".to_owned());
+
+        for index in indexes.iter() {
+            if index.primary || !index.index.columns.iter().all(|c| has_column(&c.name)) {
+                continue;
+            }
+            let mut index = index.clone();
+            index.table = Some(table.clone());
+            statements.push(index.to_string(SqliteQueryBuilder));
+        }
+
+        statements.extend(triggers.iter().cloned());
+
+        // Fail on any violation, `PRAGMA foreign_key_check` only reports them
+        statements.push("CREATE TEMP TABLE `sea_query_foreign_key_check` ( `violations` integer CHECK (`violations` = 0) )".to_owned());
+        statements.push("INSERT INTO `sea_query_foreign_key_check` SELECT COUNT(*) FROM `pragma_foreign_key_check`".to_owned());
+        statements.push("DROP TABLE `sea_query_foreign_key_check`".to_owned());
+        statements
+    }
+
+    /// Statements applying `alter` to table `current`.
+    /// Alterations `ALTER TABLE` cannot express, i.e. modifying or dropping a column, adding a primary key
+    /// or adding and dropping a foreign key, are applied together by [`rebuild_table`](Self::rebuild_table),
+    /// with column renames executed first.
+    /// Otherwise each alteration is rendered as its own statement.
+    pub fn rebuild_table_alter(&self, alter: &TableAlterStatement, current: &TableCreateStatement, indexes: &[IndexCreateStatement], triggers: &[String]) -> Vec<String> {
+        let single = |alter_option: &TableAlterOption| {
+            let mut single = TableAlterStatement::new();
+            single.table = alter.table.clone().or_else(|| current.table.clone());
+            single.options.push(alter_option.clone());
+            single.to_string(SqliteQueryBuilder)
+        };
+        let rebuild = alter.options.iter().any(|alter_option| match alter_option {
+            TableAlterOption::ModifyColumn(_)
+            | TableAlterOption::DropColumn(_)
+            | TableAlterOption::AddForeignKey(_)
+            | TableAlterOption::DropForeignKey(_) => true,
+            TableAlterOption::AddIndex(index) => index.primary,
+            _ => false,
+        });
+        if !rebuild {
+            return alter.options.iter().map(single).collect();
+        }
+
+        let mut current = current.clone();
+        if alter.table.is_some() {
+            current.table = alter.table.clone();
+        }
+        let mut indexes = indexes.to_vec();
+        let mut renames = Vec::new();
+        for alter_option in alter.options.iter() {
+            if let TableAlterOption::RenameColumn(from_name, to_name) = alter_option {
+                let from_name = from_name.to_string();
+                let rename = |name: &mut Rc<dyn Iden>| if name.to_string() == from_name {
+                    *name = to_name.clone();
+                };
+                current.columns.iter_mut().for_each(|c| rename(&mut c.name));
+                current.indexes.iter_mut().chain(indexes.iter_mut())
+                    .flat_map(|i| i.index.columns.iter_mut())
+                    .for_each(|c| rename(&mut c.name));
+                current.foreign_keys.iter_mut()
+                    .flat_map(|f| f.foreign_key.columns.iter_mut())
+                    .for_each(rename);
+                renames.push(single(alter_option));
+            }
+        }
+
+        let mut desired = current.clone();
+        for alter_option in alter.options.iter() {
+            match alter_option {
+                TableAlterOption::AddColumn(column_def) => desired.columns.push(column_def.clone()),
+                TableAlterOption::ModifyColumn(column_def) => {
+                    for column in desired.columns.iter_mut() {
+                        if column.name.to_string() == column_def.name.to_string() {
+                            *column = column_def.clone();
+                        }
+                    }
+                },
+                TableAlterOption::RenameColumn(_, _) => {},
+                TableAlterOption::DropColumn(name) => {
+                    let name = name.to_string();
+                    desired.columns.retain(|c| c.name.to_string() != name);
+                    desired.indexes.retain(|i| !i.index.columns.iter().any(|c| c.name.to_string() == name));
+                    desired.foreign_keys.retain(|f| !f.foreign_key.columns.iter().any(|c| c.to_string() == name));
+                },
+                TableAlterOption::AddForeignKey(foreign_key) => desired.foreign_keys.push(foreign_key.clone()),
+                TableAlterOption::DropForeignKey(name) => {
+                    desired.foreign_keys.retain(|f| f.foreign_key.name.as_ref() != Some(name));
+                },
+                TableAlterOption::AddIndex(index) if index.primary => desired.indexes.push(index.clone()),
+                TableAlterOption::AddIndex(index) => indexes.push(index.clone()),
+            }
+        }
+
+        renames.append(&mut self.rebuild_table(&desired, &current, &indexes, triggers));
+        renames
+    }
+
+    /// Statements adding foreign key `create` to table `current` by [`rebuild_table`](Self::rebuild_table)
+    pub fn rebuild_foreign_key_create(&self, create: &ForeignKeyCreateStatement, current: &TableCreateStatement, indexes: &[IndexCreateStatement], triggers: &[String]) -> Vec<String> {
+        let mut desired = current.clone();
+        if create.foreign_key.table.is_some() {
+            desired.table = create.foreign_key.table.clone();
+        }
+        desired.foreign_keys.push(create.clone());
+        self.rebuild_table(&desired, current, indexes, triggers)
+    }
+
+    fn prepare_fts5_table_create_statement(&self, create: &TableCreateStatement, options: &[(String, String)], sql: &mut SqlWriter) {
+        if !create.indexes.is_empty() || !create.foreign_keys.is_empty() {
+            panic!("Sqlite does not support indexes and foreign keys in FTS5 virtual tables");
+        }
+
+        write!(sql, "CREATE VIRTUAL TABLE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(table) = &create.table {
+            table.prepare(sql, '`');
+        }
+
+        write!(sql, " USING fts5(").unwrap();
+        let mut count = 0;
+
+        for column_def in create.columns.iter() {
+            if count > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            column_def.name.prepare(sql, '`');
+            for column_spec in column_def.spec.iter() {
+                if let ColumnSpec::Extra(string) = column_spec {
+                    write!(sql, " {}", string).unwrap();
+                }
+            }
+            count += 1;
+        }
+
+        for (key, value) in options.iter() {
+            if !FTS5_OPTIONS.contains(&key.as_str()) {
+                panic!("Sqlite does not support FTS5 option `{}`", key);
+            }
+            if count > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            write!(sql, "{} = {}", key, sqlite_value_to_string(&value.as_str().into())).unwrap();
+            count += 1;
+        }
+
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_rebuild_columns(&self, columns: &[&Rc<dyn Iden>], sql: &mut SqlWriter) {
+        columns.iter().fold(true, |first, column| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            column.prepare(sql, '`');
+            false
+        });
+    }
+}
@@ -0,0 +1,418 @@
+//! Schema discovery of existing databases.
+//!
+//! sea-query does not own a database connection, so discovery is split in two halves:
+//! a [`DiscoveryBuilder`] constructs the [`SelectStatement`]s that read the catalog
+//! (`information_schema`, `pg_catalog` or `sqlite_master` and the `PRAGMA` table functions),
+//! and parses the resulting rows back into a [`TableDef`].
+//! Rows are handed over as `Vec<Value>`, one [`Value`] per selected column, in select order.
+//!
+//! # Examples
+//!
+//! ```
+//! use sea_query::{*, discovery::*};
+//!
+//! // Pretend the database answered with these rows
+//! let table = SqliteQueryBuilder.discover_table("main", "font", |select| {
+//!     let (sql, _) = select.build(SqliteQueryBuilder);
+//!     if sql.contains("pragma_table_info") {
+//!         vec![
+//!             vec!["id".into(), "integer".into(), 1.into(), Value::Null, 1.into(), Value::Null],
+//!             vec!["name".into(), "text".into(), 1.into(), Value::Null, 0.into(), Value::Null],
+//!         ]
+//!     } else {
+//!         vec![]
+//!     }
+//! });
+//!
+//! assert_eq!(
+//!     table.to_create_statement().to_string(SqliteQueryBuilder),
+//!     "CREATE TABLE `font` ( `id` integer NOT NULL PRIMARY KEY, `name` text NOT NULL )"
+//! );
+//! ```
+
+use std::rc::Rc;
+use std::fmt;
+use crate::{query::*, table::*, index::*, foreign_key::*, types::*, value::*};
+
+/// Structure of an existing table, as discovered from the database
+#[derive(Debug, Clone)]
+pub struct TableDef {
+    pub(crate) table: Rc<dyn Iden>,
+    pub(crate) columns: Vec<ColumnDef>,
+    pub(crate) primary_key: Option<IndexCreateStatement>,
+    pub(crate) indexes: Vec<IndexCreateStatement>,
+    pub(crate) foreign_keys: Vec<ForeignKeyCreateStatement>,
+}
+
+pub trait DiscoveryBuilder {
+    /// Query the names of all base tables in a schema. Each row holds one table name.
+    fn query_tables(&self, schema: &str) -> SelectStatement;
+
+    /// Query a table by name in the current schema of the connection, returning one row if it exists.
+    fn query_table_exists(&self, table: &str) -> SelectStatement;
+
+    /// Query the columns of a table, in definition order.
+    fn query_columns(&self, schema: &str, table: &str) -> SelectStatement;
+
+    /// Query the indexes of a table, one row per indexed column.
+    fn query_indexes(&self, schema: &str, table: &str) -> SelectStatement;
+
+    /// Query the foreign keys of a table, one row per key column.
+    fn query_foreign_keys(&self, schema: &str, table: &str) -> SelectStatement;
+
+    /// Assemble a [`TableDef`] from the rows returned by
+    /// [`DiscoveryBuilder::query_columns`], [`DiscoveryBuilder::query_indexes`] and [`DiscoveryBuilder::query_foreign_keys`].
+    fn parse_table(&self, table: &str, columns: &[Vec<Value>], indexes: &[Vec<Value>], foreign_keys: &[Vec<Value>]) -> TableDef;
+
+    /// Extract table names from the rows returned by [`DiscoveryBuilder::query_tables`].
+    fn parse_tables(&self, rows: &[Vec<Value>]) -> Vec<String> {
+        rows.iter().filter_map(|row| row_string(row, 0)).collect()
+    }
+
+    /// Discover a single table, running each catalog query through `query`.
+    fn discover_table<F>(&self, schema: &str, table: &str, mut query: F) -> TableDef
+        where Self: Sized, F: FnMut(&SelectStatement) -> Vec<Vec<Value>> {
+        let columns = query(&self.query_columns(schema, table));
+        let indexes = query(&self.query_indexes(schema, table));
+        let foreign_keys = query(&self.query_foreign_keys(schema, table));
+        self.parse_table(table, &columns, &indexes, &foreign_keys)
+    }
+
+    /// Discover every table in a schema, running each catalog query through `query`.
+    fn discover<F>(&self, schema: &str, mut query: F) -> Vec<TableDef>
+        where Self: Sized, F: FnMut(&SelectStatement) -> Vec<Vec<Value>> {
+        let tables = self.parse_tables(&query(&self.query_tables(schema)));
+        tables.iter().map(|table| self.discover_table(schema, table, &mut query)).collect()
+    }
+}
+
+impl TableDef {
+    /// Construct an empty table definition
+    pub fn new<T>(table: T) -> Self
+        where T: Iden + 'static {
+        Self {
+            table: Rc::new(table),
+            columns: Vec::new(),
+            primary_key: None,
+            indexes: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    /// Name of the table
+    pub fn get_table_name(&self) -> String {
+        self.table.to_string()
+    }
+
+    /// Columns in definition order
+    pub fn get_columns(&self) -> &[ColumnDef] {
+        &self.columns
+    }
+
+    /// Primary key, if any
+    pub fn get_primary_key(&self) -> Option<&IndexCreateStatement> {
+        self.primary_key.as_ref()
+    }
+
+    /// Secondary indexes, excluding the primary key
+    pub fn get_indexes(&self) -> &[IndexCreateStatement] {
+        &self.indexes
+    }
+
+    /// Foreign keys referencing other tables
+    pub fn get_foreign_keys(&self) -> &[ForeignKeyCreateStatement] {
+        &self.foreign_keys
+    }
+
+    /// Convert into a [`TableCreateStatement`] with columns, primary key and foreign keys.
+    /// Secondary indexes are created separately, see [`TableDef::to_index_create_statements`].
+    pub fn to_create_statement(&self) -> TableCreateStatement {
+        let mut create = TableCreateStatement::new();
+        create.table = Some(self.table.clone());
+        let single_primary_key = match &self.primary_key {
+            Some(primary_key) if primary_key.index.columns.len() == 1 => Some(primary_key.index.columns[0].name.to_string()),
+            _ => None,
+        };
+        for column in self.columns.iter() {
+            let mut column = column.clone();
+            if single_primary_key.as_ref() == Some(&column.name.to_string()) {
+                column.spec.push(ColumnSpec::PrimaryKey);
+            }
+            create.col(column);
+        }
+        if let (Some(primary_key), None) = (&self.primary_key, &single_primary_key) {
+            let mut primary_key = primary_key.clone();
+            primary_key.index.name = None;
+            create.primary_key(primary_key);
+        }
+        for foreign_key in self.foreign_keys.iter() {
+            create.foreign_key(foreign_key.clone());
+        }
+        create
+    }
+
+    /// Convert secondary indexes into [`IndexCreateStatement`]s
+    pub fn to_index_create_statements(&self) -> Vec<IndexCreateStatement> {
+        self.indexes.clone()
+    }
+}
+
+/// Identifiers of the ANSI `information_schema`, shared by MySQL and Postgres
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum InformationSchema {
+    Schema,
+    Tables,
+    Columns,
+    Statistics,
+    KeyColumnUsage,
+    ReferentialConstraints,
+    TableSchema,
+    TableName,
+    TableType,
+    ColumnName,
+    ColumnType,
+    DataType,
+    UdtName,
+    CharacterMaximumLength,
+    NumericPrecision,
+    NumericScale,
+    IsNullable,
+    ColumnDefault,
+    Extra,
+    OrdinalPosition,
+    IndexName,
+    NonUnique,
+    SeqInIndex,
+    SubPart,
+    IndexType,
+    ConstraintSchema,
+    ConstraintName,
+    UniqueConstraintSchema,
+    UniqueConstraintName,
+    PositionInUniqueConstraint,
+    ReferencedTableName,
+    ReferencedColumnName,
+    UpdateRule,
+    DeleteRule,
+}
+
+impl Iden for InformationSchema {
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Schema => "information_schema",
+            Self::Tables => "tables",
+            Self::Columns => "columns",
+            Self::Statistics => "statistics",
+            Self::KeyColumnUsage => "key_column_usage",
+            Self::ReferentialConstraints => "referential_constraints",
+            Self::TableSchema => "table_schema",
+            Self::TableName => "table_name",
+            Self::TableType => "table_type",
+            Self::ColumnName => "column_name",
+            Self::ColumnType => "column_type",
+            Self::DataType => "data_type",
+            Self::UdtName => "udt_name",
+            Self::CharacterMaximumLength => "character_maximum_length",
+            Self::NumericPrecision => "numeric_precision",
+            Self::NumericScale => "numeric_scale",
+            Self::IsNullable => "is_nullable",
+            Self::ColumnDefault => "column_default",
+            Self::Extra => "extra",
+            Self::OrdinalPosition => "ordinal_position",
+            Self::IndexName => "index_name",
+            Self::NonUnique => "non_unique",
+            Self::SeqInIndex => "seq_in_index",
+            Self::SubPart => "sub_part",
+            Self::IndexType => "index_type",
+            Self::ConstraintSchema => "constraint_schema",
+            Self::ConstraintName => "constraint_name",
+            Self::UniqueConstraintSchema => "unique_constraint_schema",
+            Self::UniqueConstraintName => "unique_constraint_name",
+            Self::PositionInUniqueConstraint => "position_in_unique_constraint",
+            Self::ReferencedTableName => "referenced_table_name",
+            Self::ReferencedColumnName => "referenced_column_name",
+            Self::UpdateRule => "update_rule",
+            Self::DeleteRule => "delete_rule",
+        }).unwrap();
+    }
+}
+
+/// Read a column of a row as string, `None` for SQL NULL
+pub(crate) fn row_string(row: &[Value], i: usize) -> Option<String> {
+    match row.get(i)? {
+        Value::Null => None,
+        Value::String(v) => Some(v.as_ref().clone()),
+        Value::Bytes(v) => Some(String::from_utf8_lossy(v).into_owned()),
+        Value::Bool(v) => Some(v.to_string()),
+        Value::TinyInt(v) => Some(v.to_string()),
+        Value::SmallInt(v) => Some(v.to_string()),
+        Value::Int(v) => Some(v.to_string()),
+        Value::BigInt(v) => Some(v.to_string()),
+        Value::TinyUnsigned(v) => Some(v.to_string()),
+        Value::SmallUnsigned(v) => Some(v.to_string()),
+        Value::Unsigned(v) => Some(v.to_string()),
+        Value::BigUnsigned(v) => Some(v.to_string()),
+        Value::Float(v) => Some(v.to_string()),
+        Value::Double(v) => Some(v.to_string()),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+/// Read a column of a row as unsigned integer
+pub(crate) fn row_u32(row: &[Value], i: usize) -> Option<u32> {
+    row_string(row, i).and_then(|v| v.parse().ok())
+}
+
+/// Read a column of a row as boolean, accepting the catalog spellings `1`, `t`, `true` and `YES`
+pub(crate) fn row_bool(row: &[Value], i: usize) -> bool {
+    match row_string(row, i) {
+        Some(v) => matches!(v.to_lowercase().as_str(), "1" | "t" | "true" | "yes"),
+        None => false,
+    }
+}
+
+/// Group consecutive rows sharing the same key column, preserving order
+pub(crate) fn group_rows(rows: &[Vec<Value>], key: usize) -> Vec<(Option<String>, Vec<&Vec<Value>>)> {
+    let mut groups: Vec<(Option<String>, Vec<&Vec<Value>>)> = Vec::new();
+    for row in rows.iter() {
+        let name = row_string(row, key);
+        match groups.last_mut() {
+            Some((last, members)) if *last == name => members.push(row),
+            _ => groups.push((name, vec![row])),
+        }
+    }
+    groups
+}
+
+/// Parse a type declaration such as `decimal(10,2)` or `int(11) unsigned`
+/// into lowercase base name, arguments and trailing modifiers
+pub(crate) fn split_type(type_name: &str) -> (String, Vec<u32>, String) {
+    let type_name = type_name.trim().to_lowercase();
+    match (type_name.find('('), type_name.find(')')) {
+        (Some(open), Some(close)) if open < close => {
+            let args = type_name[open + 1..close]
+                .split(',')
+                .filter_map(|arg| arg.trim().parse().ok())
+                .collect();
+            (type_name[..open].trim().to_owned(), args, type_name[close + 1..].trim().to_owned())
+        },
+        _ => (type_name, Vec::new(), String::new()),
+    }
+}
+
+/// Map a MySQL-like type declaration to [`ColumnType`], falling back to [`ColumnType::Custom`]
+pub(crate) fn parse_column_type(type_name: &str) -> ColumnType {
+    let (base, args, modifiers) = split_type(type_name);
+    if !modifiers.is_empty() {
+        return ColumnType::Custom(Rc::new(Alias::new(type_name)));
+    }
+    let arg = args.first().copied();
+    let pair = match args.as_slice() {
+        [precision, scale] => Some((*precision, *scale)),
+        _ => None,
+    };
+    match base.as_str() {
+        "char" | "character" => ColumnType::Char(arg),
+        "varchar" | "character varying" => ColumnType::String(arg),
+        "text" if arg.is_some() => ColumnType::String(arg),
+        "text" => ColumnType::Text,
+        "tinyint" => ColumnType::TinyInteger(arg),
+        "smallint" => ColumnType::SmallInteger(arg),
+        "int" | "integer" => ColumnType::Integer(arg),
+        "bigint" => ColumnType::BigInteger(arg),
+        "float" => ColumnType::Float(arg),
+        "double" | "real" => ColumnType::Double(arg),
+        "decimal" | "numeric" => ColumnType::Decimal(pair),
+        "datetime" => ColumnType::DateTime(arg),
+        "timestamp" => ColumnType::Timestamp(arg),
+        "time" => ColumnType::Time(arg),
+        "date" => ColumnType::Date,
+        "binary" => ColumnType::Binary(arg),
+        "bool" | "boolean" => ColumnType::Boolean,
+        "json" => ColumnType::Json,
+        _ => ColumnType::Custom(Rc::new(Alias::new(type_name))),
+    }
+}
+
+/// Map a SQL default expression to [`ColumnSpec::Default`] when it is a plain literal,
+/// otherwise keep it verbatim as [`ColumnSpec::Extra`]
+pub(crate) fn parse_default(default: &str) -> ColumnSpec {
+    let default = default.trim();
+    if default.eq_ignore_ascii_case("null") {
+        return ColumnSpec::Default(Value::Null);
+    }
+    if let Ok(v) = default.parse::<i64>() {
+        return ColumnSpec::Default(Value::BigInt(v));
+    }
+    if let Ok(v) = default.parse::<f64>() {
+        return ColumnSpec::Default(Value::Double(v));
+    }
+    if default.len() >= 2 && default.starts_with('\'') && default.ends_with('\'') {
+        let inner = &default[1..default.len() - 1];
+        if !inner.replace("''", "").contains('\'') {
+            return ColumnSpec::Default(Value::String(Box::new(inner.replace("''", "'"))));
+        }
+    }
+    ColumnSpec::Extra(format!("DEFAULT {}", default))
+}
+
+/// Parse a foreign key action as reported by the catalog
+pub(crate) fn parse_foreign_key_action(action: &str) -> Option<ForeignKeyAction> {
+    match action.to_uppercase().as_str() {
+        "RESTRICT" => Some(ForeignKeyAction::Restrict),
+        "CASCADE" => Some(ForeignKeyAction::Cascade),
+        "SET NULL" => Some(ForeignKeyAction::SetNull),
+        "NO ACTION" => Some(ForeignKeyAction::NoAction),
+        "SET DEFAULT" => Some(ForeignKeyAction::SetDefault),
+        _ => None,
+    }
+}
+
+/// Parse foreign key rows laid out as
+/// `(name, column, referenced table, referenced column, update rule, delete rule)`
+pub(crate) fn parse_foreign_keys(table: &str, rows: &[Vec<Value>]) -> Vec<ForeignKeyCreateStatement> {
+    group_rows(rows, 0).into_iter().map(|(_, rows)| {
+        let mut foreign_key = TableForeignKey::new();
+        foreign_key.from_tbl(Alias::new(table));
+        // SQLite identifies foreign keys by number only, leave them unnamed
+        if let Some(Value::String(name)) = rows[0].first() {
+            foreign_key.name(name);
+        }
+        if let Some(ref_table) = row_string(rows[0], 2) {
+            foreign_key.to_tbl(Alias::new(&ref_table));
+        }
+        for row in rows.iter() {
+            if let Some(column) = row_string(row, 1) {
+                foreign_key.from_col(Alias::new(&column));
+            }
+            if let Some(ref_column) = row_string(row, 3) {
+                foreign_key.to_col(Alias::new(&ref_column));
+            }
+        }
+        if let Some(action) = row_string(rows[0], 4).as_deref().and_then(parse_foreign_key_action) {
+            foreign_key.on_update(action);
+        }
+        if let Some(action) = row_string(rows[0], 5).as_deref().and_then(parse_foreign_key_action) {
+            foreign_key.on_delete(action);
+        }
+        ForeignKeyCreateStatement { foreign_key }
+    }).collect()
+}
+
+/// Build an [`IndexCreateStatement`] on `table` over the given columns
+pub(crate) fn index_create(table: &str, name: Option<String>, columns: Vec<IndexColumn>) -> IndexCreateStatement {
+    let mut index = IndexCreateStatement::new().table(Alias::new(table));
+    index.index.name = name;
+    index.index.columns = columns;
+    index
+}
+
+/// Build an [`IndexColumn`] from a column name
+pub(crate) fn index_column(name: &str, prefix: Option<u32>) -> IndexColumn {
+    IndexColumn {
+        name: Rc::new(Alias::new(name)),
+        prefix,
+        order: None,
+    }
+}
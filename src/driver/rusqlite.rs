@@ -2,8 +2,8 @@
 macro_rules! sea_query_driver_rusqlite {
     () => {
         mod sea_query_driver_rusqlite {
-            use rusqlite::{Result, ToSql, types::ToSqlOutput};
-            use sea_query::{Values, Value};
+            use rusqlite::{Connection, Result, ToSql, types::{ToSqlOutput, ValueRef}};
+            use sea_query::{Values, Value, migration::MigrationConnection};
 
             pub struct RusqliteValue(pub Value);
 
@@ -55,6 +55,40 @@ macro_rules! sea_query_driver_rusqlite {
                     }
                 }
             }
+
+            pub struct RusqliteConnection<'a>(pub &'a Connection);
+
+            impl<'a> MigrationConnection for RusqliteConnection<'a> {
+                type Error = rusqlite::Error;
+
+                fn execute(&mut self, sql: &str, values: Values) -> Result<()> {
+                    let values = RusqliteValues::from(values);
+                    self.0.execute(sql, values.as_params().as_slice())?;
+                    Ok(())
+                }
+
+                fn query(&mut self, sql: &str, values: Values) -> Result<Vec<Vec<Value>>> {
+                    let values = RusqliteValues::from(values);
+                    let mut stmt = self.0.prepare(sql)?;
+                    let column_count = stmt.column_count();
+                    let mut rows = stmt.query(values.as_params().as_slice())?;
+                    let mut result = Vec::new();
+                    while let Some(row) = rows.next()? {
+                        let mut values = Vec::with_capacity(column_count);
+                        for i in 0..column_count {
+                            values.push(match row.get_ref(i)? {
+                                ValueRef::Null => Value::Null,
+                                ValueRef::Integer(v) => Value::BigInt(v),
+                                ValueRef::Real(v) => Value::Double(v),
+                                ValueRef::Text(v) => String::from_utf8_lossy(v).into_owned().into(),
+                                ValueRef::Blob(v) => v.to_vec().into(),
+                            });
+                        }
+                        result.push(values);
+                    }
+                    Ok(result)
+                }
+            }
         }
     }
-}
\ No newline at end of file
+}
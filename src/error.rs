@@ -10,5 +10,19 @@ pub enum Error {
     ColValNumMismatch {
         col_len: usize,
         val_len: usize,
-    }
+    },
+
+    /// Index without a name to be dropped
+    #[error("Cannot drop the unnamed index on ({columns}) of table `{table}`")]
+    UnnamedIndexDrop {
+        table: String,
+        columns: String,
+    },
+
+    /// Foreign key without a name to be dropped
+    #[error("Cannot drop the unnamed foreign key on ({columns}) of table `{table}`")]
+    UnnamedForeignKeyDrop {
+        table: String,
+        columns: String,
+    },
 }
\ No newline at end of file
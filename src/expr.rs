@@ -4,9 +4,40 @@
 //!
 //! [`SimpleExpr`] is the expression common among select fields, where clauses and many other places.
 
-use crate::{query::*, func::*, types::*, value::*};
+use crate::{query::*, func::*, table::ColumnType, types::*, value::*};
 
 /// Helper to build a [`SimpleExpr`].
+/// 
+/// Arithmetic operators, `-`, `!`, as well as `&` and `|` for logical `AND` and `OR`, are overloaded
+/// for [`Expr`] and [`SimpleExpr`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+/// 
+/// let query = Query::select()
+///     .column(Char::Id)
+///     .from(Char::Table)
+///     .and_where(
+///         (Expr::col(Char::SizeW) * Expr::col(Char::SizeH)).gt(100)
+///             & !(Expr::col(Char::FontSize) % 2).eq(0)
+///     )
+///     .to_owned();
+/// 
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"SELECT `id` FROM `character` WHERE ((`size_w` * `size_h`) > 100) AND NOT `font_size` % 2 = 0"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"SELECT "id" FROM "character" WHERE (("size_w" * "size_h") > 100) AND NOT "font_size" % 2 = 0"#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"SELECT `id` FROM `character` WHERE ((`size_w` * `size_h`) > 100) AND NOT `font_size` % 2 = 0"#
+/// );
+/// ```
 #[derive(Debug, Clone, Default)]
 pub struct Expr {
     pub(crate) left: Option<SimpleExpr>,
@@ -25,14 +56,64 @@ pub struct Expr {
 pub enum SimpleExpr {
     Column(ColumnRef),
     Unary(UnOper, Box<SimpleExpr>),
-    FunctionCall(Function, Vec<SimpleExpr>),
+    FunctionCall(Function, Vec<SimpleExpr>, AggregateModifiers),
     Binary(Box<SimpleExpr>, BinOper, Box<SimpleExpr>),
-    SubQuery(Box<SelectStatement>),
+    SubQuery(Option<SubQueryOper>, Box<SelectStatement>),
     Value(Value),
     Values(Vec<Value>),
     Custom(String),
     CustomWithValues(String, Vec<Value>),
+    CustomWithExprs(String, Vec<SimpleExpr>),
     Keyword(Keyword),
+    Case(Box<CaseStatement>),
+    Cast(Box<SimpleExpr>, ColumnType),
+    Tuple(Vec<SimpleExpr>),
+}
+
+/// A `LIKE` pattern, with an optional `ESCAPE` character
+#[derive(Debug, Clone)]
+pub struct LikeExpr {
+    pub(crate) pattern: String,
+    pub(crate) escape: Option<char>,
+}
+
+pub trait IntoLikeExpr {
+    fn into_like_expr(self) -> LikeExpr;
+}
+
+impl LikeExpr {
+    pub fn str(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_owned(),
+            escape: None,
+        }
+    }
+
+    /// Set the character escaping `%` and `_` in the pattern
+    pub fn escape(self, c: char) -> Self {
+        Self {
+            pattern: self.pattern,
+            escape: Some(c),
+        }
+    }
+}
+
+impl IntoLikeExpr for LikeExpr {
+    fn into_like_expr(self) -> LikeExpr {
+        self
+    }
+}
+
+impl IntoLikeExpr for &str {
+    fn into_like_expr(self) -> LikeExpr {
+        LikeExpr::str(self)
+    }
+}
+
+impl IntoLikeExpr for String {
+    fn into_like_expr(self) -> LikeExpr {
+        LikeExpr::str(&self)
+    }
 }
 
 impl Expr {
@@ -199,6 +280,40 @@ impl Expr {
         Self::new_with_left(expr)
     }
 
+    /// Express a row value (tuple) of expressions, to be compared with another tuple.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(
+    ///         Expr::tuple(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH)])
+    ///             .gt(Expr::tuple(vec![Expr::val(3), Expr::val(4)]))
+    ///     )
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w`, `size_h`) > (3, 4)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE ("size_w", "size_h") > (3, 4)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w`, `size_h`) > (3, 4)"#
+    /// );
+    /// ```
+    pub fn tuple<T, I>(exprs: I) -> Self
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = T> {
+        Self::new_with_left(SimpleExpr::Tuple(exprs.into_iter().map(|e| e.into()).collect()))
+    }
+
     /// Express a [`Value`], returning a [`SimpleExpr`].
     /// 
     /// # Examples
@@ -297,6 +412,188 @@ impl Expr {
         SimpleExpr::CustomWithValues(s.to_owned(), v.into_iter().map(|v| v.into()).collect())
     }
 
+    /// Express any custom expression with numbered placeholders `$1`, `$2`, ... substituted by [`SimpleExpr`]s,
+    /// which can be columns, values, sub-queries or any other expressions.
+    /// Binary and unary expressions are enclosed in parentheses when substituted.
+    /// 
+    /// Use the [`cust_with_exprs!`] macro to check the number of expressions against the placeholders at compile time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::expr(Expr::cust_with_exprs("ABS($1 - $2)", vec![Expr::col(Char::SizeW), Expr::val(10)])).lt(2))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE ABS(`size_w` - 10) < 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE ABS("size_w" - 10) < 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE ABS(`size_w` - 10) < 2"#
+    /// );
+    /// ```
+    pub fn cust_with_exprs<T, I>(s: &str, exprs: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        SimpleExpr::CustomWithExprs(s.to_owned(), exprs.into_iter().map(|e| e.into()).collect())
+    }
+
+    /// Start a [`CaseStatement`] with its first `WHEN condition THEN result` branch.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .order_by_expr(
+    ///         Expr::case(Expr::col(Char::FontId).is_null(), 1).finally(0).into(),
+    ///         Order::Asc
+    ///     )
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` ORDER BY CASE WHEN `font_id` IS NULL THEN 1 ELSE 0 END ASC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" ORDER BY CASE WHEN "font_id" IS NULL THEN 1 ELSE 0 END ASC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` ORDER BY CASE WHEN `font_id` IS NULL THEN 1 ELSE 0 END ASC"#
+    /// );
+    /// ```
+    pub fn case<C, T>(condition: C, then: T) -> CaseStatement
+        where C: Into<SimpleExpr>, T: Into<SimpleExpr> {
+        CaseStatement::new().case(condition, then)
+    }
+
+    /// Express an `EXISTS` sub-query.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::exists(
+    ///         Query::select()
+    ///             .column(Font::Id)
+    ///             .from(Font::Table)
+    ///             .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+    ///             .take()
+    ///     ))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE EXISTS(SELECT `id` FROM `font` WHERE `font`.`id` = `character`.`font_id`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE EXISTS(SELECT "id" FROM "font" WHERE "font"."id" = "character"."font_id")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE EXISTS(SELECT `id` FROM `font` WHERE `font`.`id` = `character`.`font_id`)"#
+    /// );
+    /// ```
+    pub fn exists(sel: SelectStatement) -> SimpleExpr {
+        SimpleExpr::SubQuery(Some(SubQueryOper::Exists), Box::new(sel))
+    }
+
+    /// Express a `NOT EXISTS` sub-query, see [`Expr::exists`].
+    pub fn not_exists(sel: SelectStatement) -> SimpleExpr {
+        SimpleExpr::Unary(UnOper::Not, Box::new(Self::exists(sel)))
+    }
+
+    /// Express an `ANY` sub-query, to be compared against.
+    /// 
+    /// Sqlite has no `ANY`, so `= ANY` is rendered as `IN` there, other comparisons panic.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq(Expr::any(
+    ///         Query::select().column(Font::Id).from(Font::Table).take()
+    ///     )))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = ANY(SELECT `id` FROM `font`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = ANY(SELECT "id" FROM "font")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` IN (SELECT `id` FROM `font`)"#
+    /// );
+    /// ```
+    pub fn any(sel: SelectStatement) -> SimpleExpr {
+        SimpleExpr::SubQuery(Some(SubQueryOper::Any), Box::new(sel))
+    }
+
+    /// Express a `SOME` sub-query, a synonym of [`Expr::any`].
+    pub fn some(sel: SelectStatement) -> SimpleExpr {
+        SimpleExpr::SubQuery(Some(SubQueryOper::Some), Box::new(sel))
+    }
+
+    /// Express an `ALL` sub-query, to be compared against.
+    /// 
+    /// Sqlite has no `ALL`, so `<> ALL` is rendered as `NOT IN` there, other comparisons panic.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).gt(Expr::all(
+    ///         Query::select().column(Char::SizeH).from(Char::Table).take()
+    ///     )))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `size_w` > ALL(SELECT `size_h` FROM `character`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "size_w" > ALL(SELECT "size_h" FROM "character")"#
+    /// );
+    /// ```
+    pub fn all(sel: SelectStatement) -> SimpleExpr {
+        SimpleExpr::SubQuery(Some(SubQueryOper::All), Box::new(sel))
+    }
+
     /// Express an equal (`=`) expression.
     /// 
     /// # Examples
@@ -325,8 +622,8 @@ impl Expr {
     /// );
     /// ```
     pub fn eq<V>(self, v: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(BinOper::Equal, SimpleExpr::Value(v.into()))
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::Equal, v.into())
     }
 
     /// Express a not equal (`<>`) expression.
@@ -357,8 +654,8 @@ impl Expr {
     /// );
     /// ```
     pub fn ne<V>(self, v: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(BinOper::NotEqual, SimpleExpr::Value(v.into()))
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::NotEqual, v.into())
     }
 
     /// Express a equal expression between two table columns,
@@ -420,8 +717,8 @@ impl Expr {
     /// );
     /// ```
     pub fn gt<V>(self, v: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(BinOper::GreaterThan, SimpleExpr::Value(v.into()))
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::GreaterThan, v.into())
     }
 
     /// Express a greater than or equal (`>=`) expression.
@@ -451,8 +748,8 @@ impl Expr {
     /// );
     /// ```
     pub fn gte<V>(self, v: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(BinOper::GreaterThanOrEqual, SimpleExpr::Value(v.into()))
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::GreaterThanOrEqual, v.into())
     }
 
     /// Express a less than (`<`) expression.
@@ -482,8 +779,8 @@ impl Expr {
     /// );
     /// ```
     pub fn lt<V>(self, v: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(BinOper::SmallerThan, SimpleExpr::Value(v.into()))
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::SmallerThan, v.into())
     }
 
     /// Express a less than or equal (`<=`) expression.
@@ -513,8 +810,8 @@ impl Expr {
     /// );
     /// ```
     pub fn lte<V>(self, v: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(BinOper::SmallerThanOrEqual, SimpleExpr::Value(v.into()))
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::SmallerThanOrEqual, v.into())
     }
 
     /// Express an arithmetic addition operation.
@@ -645,7 +942,7 @@ impl Expr {
         self.bin_oper(BinOper::Div, SimpleExpr::Value(v.into()))
     }
 
-    /// Express a `BETWEEN` expression.
+    /// Express an arithmetic modulo operation.
     /// 
     /// # Examples
     /// 
@@ -653,30 +950,30 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .column(Char::Id)
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).between(1, 10))
+    ///     .and_where(Expr::expr(Expr::col(Char::SizeW).modulo(2)).eq(0))
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
+    ///     r#"SELECT `id` FROM `character` WHERE `size_w` % 2 = 0"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" BETWEEN 1 AND 10"#
+    ///     r#"SELECT "id" FROM "character" WHERE "size_w" % 2 = 0"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
+    ///     r#"SELECT `id` FROM `character` WHERE `size_w` % 2 = 0"#
     /// );
     /// ```
-    pub fn between<V>(self, a: V, b: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.between_or_not_between(BinOper::Between, a, b)
+    pub fn modulo<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::Mod, v.into())
     }
 
-    /// Express a `NOT BETWEEN` expression.
+    /// Express a bitwise `&` operation.
     /// 
     /// # Examples
     /// 
@@ -684,39 +981,50 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .expr(Expr::col(Char::FontSize).bit_and(0xff))
+    ///     .expr(Expr::col(Char::FontSize).bit_or(1))
+    ///     .expr(Expr::col(Char::FontSize).left_shift(2))
+    ///     .expr(Expr::col(Char::FontSize).right_shift(2))
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).not_between(1, 10))
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
+    ///     r#"SELECT `font_size` & 255, `font_size` | 1, `font_size` << 2, `font_size` >> 2 FROM `character`"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" NOT BETWEEN 1 AND 10"#
+    ///     r#"SELECT "font_size" & 255, "font_size" | 1, "font_size" << 2, "font_size" >> 2 FROM "character""#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
+    ///     r#"SELECT `font_size` & 255, `font_size` | 1, `font_size` << 2, `font_size` >> 2 FROM `character`"#
     /// );
     /// ```
-    pub fn not_between<V>(self, a: V, b: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.between_or_not_between(BinOper::NotBetween, a, b)
+    pub fn bit_and<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::BitAnd, v.into())
     }
 
-    fn between_or_not_between<V>(self, op: BinOper, a: V, b: V) -> SimpleExpr
-        where V: Into<Value> {
-        self.bin_oper(op, SimpleExpr::Binary(
-            Box::new(SimpleExpr::Value(a.into())),
-            BinOper::And,
-            Box::new(SimpleExpr::Value(b.into())),
-        ))
+    /// Express a bitwise `|` operation.
+    pub fn bit_or<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::BitOr, v.into())
     }
 
-    /// Express a `LIKE` expression.
+    /// Express a bitwise `<<` operation.
+    pub fn left_shift<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::LShift, v.into())
+    }
+
+    /// Express a bitwise `>>` operation.
+    pub fn right_shift<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::RShift, v.into())
+    }
+
+    /// Express a string concatenation, `||` on Postgres and Sqlite and `CONCAT` on MySQL.
     /// 
     /// # Examples
     /// 
@@ -724,29 +1032,29 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::Character).like("Ours'%"))
+    ///     .expr(Expr::expr(Expr::col(Font::Name).concat(" ")).concat(Expr::col(Font::Variant)))
+    ///     .from(Font::Table)
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`character` LIKE 'Ours\'%'"#
+    ///     r#"SELECT CONCAT(`name`, ' ', `variant`) FROM `font`"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" LIKE E'Ours\'%'"#
+    ///     r#"SELECT "name" || ' ' || "variant" FROM "font""#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`character` LIKE 'Ours\'%'"#
+    ///     r#"SELECT `name` || ' ' || `variant` FROM `font`"#
     /// );
     /// ```
-    pub fn like(self, v: &str) -> SimpleExpr  {
-        self.bin_oper(BinOper::Like, SimpleExpr::Value(Value::String(Box::new(v.to_owned()))))
+    pub fn concat<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::Concat, v.into())
     }
 
-    /// Express a `IS NULL` expression.
+    /// Express a `BETWEEN` expression.
     /// 
     /// # Examples
     /// 
@@ -756,28 +1064,28 @@ impl Expr {
     /// let query = Query::select()
     ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_null())
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).between(1, 10))
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NULL"#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" BETWEEN 1 AND 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_null(self) -> SimpleExpr {
-        self.bin_oper(BinOper::Is, SimpleExpr::Keyword(Keyword::Null))
+    pub fn between<V>(self, a: V, b: V) -> SimpleExpr
+        where V: Into<Value> {
+        self.between_or_not_between(BinOper::Between, a, b)
     }
 
-    /// Express a `IS NOT NULL` expression.
+    /// Express a `NOT BETWEEN` expression.
     /// 
     /// # Examples
     /// 
@@ -787,34 +1095,322 @@ impl Expr {
     /// let query = Query::select()
     ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_null())
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).not_between(1, 10))
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NOT NULL"#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" NOT BETWEEN 1 AND 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_not_null(self) -> SimpleExpr {
-        self.bin_oper(BinOper::IsNot, SimpleExpr::Keyword(Keyword::Null))
+    pub fn not_between<V>(self, a: V, b: V) -> SimpleExpr
+        where V: Into<Value> {
+        self.between_or_not_between(BinOper::NotBetween, a, b)
     }
 
-    /// Negates an expression with `NOT`.
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use sea_query::{*, tests_cfg::*};
-    /// 
+    fn between_or_not_between<V>(self, op: BinOper, a: V, b: V) -> SimpleExpr
+        where V: Into<Value> {
+        self.bin_oper(op, SimpleExpr::Binary(
+            Box::new(SimpleExpr::Value(a.into())),
+            BinOper::And,
+            Box::new(SimpleExpr::Value(b.into())),
+        ))
+    }
+
+    /// Express a `LIKE` expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::Character).like("Ours'%"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`character` LIKE 'Ours\'%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" LIKE 'Ours''%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`character` LIKE 'Ours''%'"#
+    /// );
+    /// ```
+    /// 
+    /// With an escape character
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).like(LikeExpr::str(r"100\%%").escape('\\')))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character` LIKE '100\\%%' ESCAPE '\\'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character" LIKE E'100\\%%' ESCAPE E'\\'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character` LIKE '100\%%' ESCAPE '\'"#
+    /// );
+    /// ```
+    pub fn like<L>(self, like: L) -> SimpleExpr
+        where L: IntoLikeExpr {
+        self.like_like(BinOper::Like, like.into_like_expr())
+    }
+
+    /// Express a `NOT LIKE` expression.
+    pub fn not_like<L>(self, like: L) -> SimpleExpr
+        where L: IntoLikeExpr {
+        self.like_like(BinOper::NotLike, like.into_like_expr())
+    }
+
+    /// Express a case-insensitive `ILIKE` expression.
+    /// Rendered as `LOWER(..) LIKE LOWER(..)` on MySQL and Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).ilike("A%"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE LOWER(`character`) LIKE LOWER('A%')"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "character" ILIKE 'A%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE LOWER(`character`) LIKE LOWER('A%')"#
+    /// );
+    /// ```
+    pub fn ilike<L>(self, like: L) -> SimpleExpr
+        where L: IntoLikeExpr {
+        self.like_like(BinOper::ILike, like.into_like_expr())
+    }
+
+    /// Express a case-insensitive `NOT ILIKE` expression.
+    /// Rendered as `LOWER(..) NOT LIKE LOWER(..)` on MySQL and Sqlite.
+    pub fn not_ilike<L>(self, like: L) -> SimpleExpr
+        where L: IntoLikeExpr {
+        self.like_like(BinOper::NotILike, like.into_like_expr())
+    }
+
+    /// Express a `SIMILAR TO` expression. Postgres only.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).similar_to("(a|b)%"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "character" SIMILAR TO '(a|b)%'"#
+    /// );
+    /// ```
+    pub fn similar_to<L>(self, like: L) -> SimpleExpr
+        where L: IntoLikeExpr {
+        self.like_like(BinOper::SimilarTo, like.into_like_expr())
+    }
+
+    /// Express a `NOT SIMILAR TO` expression. Postgres only.
+    pub fn not_similar_to<L>(self, like: L) -> SimpleExpr
+        where L: IntoLikeExpr {
+        self.like_like(BinOper::NotSimilarTo, like.into_like_expr())
+    }
+
+    /// Express a regular expression match, `REGEXP` on MySQL and Sqlite and `~` on Postgres.
+    /// Sqlite requires the application to provide a `regexp()` function.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).regexp("^[a-c]"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character` REGEXP '^[a-c]'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "character" ~ '^[a-c]'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character` REGEXP '^[a-c]'"#
+    /// );
+    /// ```
+    pub fn regexp(self, pattern: &str) -> SimpleExpr {
+        self.bin_oper(BinOper::Regexp, SimpleExpr::Value(pattern.into()))
+    }
+
+    /// Express a negated regular expression match, `NOT REGEXP` on MySQL and Sqlite and `!~` on Postgres.
+    pub fn not_regexp(self, pattern: &str) -> SimpleExpr {
+        self.bin_oper(BinOper::NotRegexp, SimpleExpr::Value(pattern.into()))
+    }
+
+    fn like_like(self, op: BinOper, like: LikeExpr) -> SimpleExpr {
+        let pattern = SimpleExpr::Value(like.pattern.into());
+        self.bin_oper(op, match like.escape {
+            Some(escape) => SimpleExpr::Binary(
+                Box::new(pattern),
+                BinOper::Escape,
+                Box::new(SimpleExpr::Value(escape.to_string().into())),
+            ),
+            None => pattern,
+        })
+    }
+
+    /// Express a `IS NULL` expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_null())
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_null(self) -> SimpleExpr {
+        self.bin_oper(BinOper::Is, SimpleExpr::Keyword(Keyword::Null))
+    }
+
+    /// Express a `IS NOT NULL` expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_null())
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_not_null(self) -> SimpleExpr {
+        self.bin_oper(BinOper::IsNot, SimpleExpr::Keyword(Keyword::Null))
+    }
+
+    /// Express a null-safe inequality, `IS DISTINCT FROM` on Postgres, `NOT (.. <=> ..)` on MySQL and `IS NOT` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).is_distinct_from(Expr::col(Char::FontSize)))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE NOT (`font_id` <=> `font_size`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "font_id" IS DISTINCT FROM "font_size""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `font_id` IS NOT `font_size`"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_distinct_from<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::IsDistinctFrom, v.into())
+    }
+
+    /// Express a null-safe equality, `IS NOT DISTINCT FROM` on Postgres, `<=>` on MySQL and `IS` on Sqlite.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_not_distinct_from<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::IsNotDistinctFrom, v.into())
+    }
+
+    /// Negates an expression with `NOT`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
     /// let query = Query::select()
     ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
     ///     .from(Char::Table)
@@ -1009,110 +1605,681 @@ impl Expr {
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" IN (1, 2, 3)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` IN (1, 2, 3)"#
+    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` IN (1, 2, 3)"#
+    /// );
+    /// ```
+    /// Empty value list
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_in(Vec::<u8>::new()))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE 1 = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_in<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::In);
+        self.right = Some(SimpleExpr::Values(
+            v.into_iter().map(|v| v.into()).collect(),
+        ));
+        self.into()
+    }
+
+    /// Express a `NOT IN` expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(vec![1, 2, 3]))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" NOT IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
+    /// );
+    /// ```
+    /// Empty value list
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(Vec::<u8>::new()))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE 1 = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_not_in<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: Into<Value>,
+        I: IntoIterator<Item = V>
+    {
+        self.bopr = Some(BinOper::NotIn);
+        self.right = Some(SimpleExpr::Values(v.into_iter().map(|v| v.into()).collect()));
+        self.into()
+    }
+
+    /// Express a `IN` expression against a list of row values, usually on the left of [`Expr::tuple`].
+    /// 
+    /// Sqlite only accepts a sub-query on the right of a row value `IN`, so the list is rendered as `VALUES` there.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(
+    ///         Expr::tuple(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH)])
+    ///             .in_tuples(vec![(1, 2), (3, 4)])
+    ///     )
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w`, `size_h`) IN ((1, 2), (3, 4))"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE ("size_w", "size_h") IN ((1, 2), (3, 4))"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w`, `size_h`) IN (VALUES (1, 2), (3, 4))"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn in_tuples<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: IntoValueTuple,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::In);
+        self.right = Some(SimpleExpr::tuple_list(v));
+        self.into()
+    }
+
+    /// Express a `NOT IN` expression against a list of row values, see [`Expr::in_tuples`].
+    #[allow(clippy::wrong_self_convention)]
+    pub fn not_in_tuples<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: IntoValueTuple,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::NotIn);
+        self.right = Some(SimpleExpr::tuple_list(v));
+        self.into()
+    }
+
+    /// Express a `IN` sub-query expression.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).in_subquery(
+    ///         Query::select()
+    ///             .expr(Expr::cust("3 + 2 * 2"))
+    ///             .take()
+    ///     ))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn in_subquery(mut self, sel: SelectStatement) -> SimpleExpr {
+        self.bopr = Some(BinOper::In);
+        self.right = Some(SimpleExpr::SubQuery(None, Box::new(sel)));
+        self.into()
+    }
+
+    /// Express an equal `ANY` sub-query expression, a shorthand of `.eq(Expr::any(sel))`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq_any(
+    ///         Query::select().column(Font::Id).from(Font::Table).take()
+    ///     ))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = ANY(SELECT `id` FROM `font`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = ANY(SELECT "id" FROM "font")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` IN (SELECT `id` FROM `font`)"#
+    /// );
+    /// ```
+    pub fn eq_any(self, sel: SelectStatement) -> SimpleExpr {
+        self.eq(Expr::any(sel))
+    }
+
+    /// Express an equal `ALL` sub-query expression, a shorthand of `.eq(Expr::all(sel))`.
+    /// Other comparisons against `ALL` are expressed with [`Expr::all`], e.g. `.gt(Expr::all(sel))`.
+    /// 
+    /// Sqlite has no `ALL`, building it panics there.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq_all(
+    ///         Query::select().column(Font::Id).from(Font::Table).take()
+    ///     ))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = ALL(SELECT `id` FROM `font`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = ALL(SELECT "id" FROM "font")"#
+    /// );
+    /// ```
+    pub fn eq_all(self, sel: SelectStatement) -> SimpleExpr {
+        self.eq(Expr::all(sel))
+    }
+
+    pub(crate) fn func(func: Function) -> Self {
+        let mut expr = Expr::new();
+        expr.func = Some(func);
+        expr
+    }
+
+    pub fn arg<T>(mut self, arg: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        self.args = vec![arg.into()];
+        self.into()
+    }
+
+    pub fn args<T, I>(mut self, args: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        self.args = args.into_iter().map(|v| v.into()).collect();
+        self.into()
+    }
+
+    fn func_with_args(func: Function, args: Vec<SimpleExpr>) -> SimpleExpr {
+        let mut expr = Expr::new();
+        expr.func = Some(func);
+        expr.args = args;
+        expr.into()
+    }
+
+    fn un_oper(mut self, o: UnOper) -> SimpleExpr {
+        self.uopr = Some(o);
+        self.into()
+    }
+
+    /// Express a `CAST` expression, converting to a [`ColumnType`] or a custom type.
+    /// Each backend maps the column type to a name its `CAST` accepts, e.g. `SIGNED` for integers in MySQL,
+    /// or the type affinity in Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Char::SizeW).cast_as(ColumnType::String(None)))
+    ///     .expr(Expr::val("1.5").cast_as(ColumnType::Decimal(Some((4, 2)))))
+    ///     .expr(Expr::col(Char::Character).cast_as(Alias::new("citext")))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CAST(`size_w` AS CHAR), CAST('1.5' AS DECIMAL(4, 2)), CAST(`character` AS citext) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CAST("size_w" AS varchar), CAST('1.5' AS decimal(4, 2)), CAST("character" AS citext) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST(`size_w` AS TEXT), CAST('1.5' AS NUMERIC), CAST(`character` AS citext) FROM `character`"#
+    /// );
+    /// ```
+    pub fn cast_as<T>(self, column_type: T) -> SimpleExpr
+        where T: Into<ColumnType> {
+        SimpleExpr::Cast(Box::new(self.into()), column_type.into())
+    }
+
+    /// Get a field of a JSON object by key, or an element of a JSON array by index, as JSON.
+    /// Rendered as `->` on Postgres, and as `JSON_EXTRACT` / `json_extract` on MySQL and Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Alias::new("metadata")).get_json_field("size"))
+    ///     .expr(Expr::expr(Expr::col(Alias::new("metadata")).get_json_field("tags")).get_json_field(0))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT JSON_EXTRACT(`metadata`, '$.size'), JSON_EXTRACT(JSON_EXTRACT(`metadata`, '$.tags'), '$[0]') FROM `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "metadata" -> 'size', "metadata" -> 'tags' -> 0 FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT json_extract(`metadata`, '$.size'), json_extract(json_extract(`metadata`, '$.tags'), '$[0]') FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn get_json_field<K>(self, key: K) -> SimpleExpr
+        where K: Into<Value> {
+        self.bin_oper(BinOper::JsonGet, SimpleExpr::Value(key.into()))
+    }
+
+    /// Get a field of a JSON object by key, or an element of a JSON array by index, as text.
+    /// Rendered as `->>` on Postgres, `JSON_UNQUOTE(JSON_EXTRACT(...))` on MySQL and `json_extract` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::expr(Expr::col(Alias::new("metadata")).cast_json_field("font")).eq("Arial"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE JSON_UNQUOTE(JSON_EXTRACT(`metadata`, '$.font')) = 'Arial'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "metadata" ->> 'font' = 'Arial'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE json_extract(`metadata`, '$.font') = 'Arial'"#
+    /// );
+    /// ```
+    pub fn cast_json_field<K>(self, key: K) -> SimpleExpr
+        where K: Into<Value> {
+        self.bin_oper(BinOper::JsonGetText, SimpleExpr::Value(key.into()))
+    }
+
+    /// Get a nested JSON value by a path of keys and indexes, as JSON.
+    /// Rendered as `#>` on Postgres, and as `JSON_EXTRACT` / `json_extract` on MySQL and Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Alias::new("metadata")).get_json_path(vec![Value::from("tags"), Value::from(1)]))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT JSON_EXTRACT(`metadata`, '$.tags[1]') FROM `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "metadata" #> ARRAY['tags', '1'] FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT json_extract(`metadata`, '$.tags[1]') FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn get_json_path<K, I>(self, path: I) -> SimpleExpr
+        where K: Into<Value>, I: IntoIterator<Item = K> {
+        self.bin_oper(BinOper::JsonGetPath, json_keys(path))
+    }
+
+    /// Get a nested JSON value by a path of keys and indexes, as text.
+    /// Rendered as `#>>` on Postgres, `JSON_UNQUOTE(JSON_EXTRACT(...))` on MySQL and `json_extract` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Alias::new("metadata")).cast_json_path(vec!["author", "first name"]))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT JSON_UNQUOTE(JSON_EXTRACT(`metadata`, '$.author.\"first name\"')) FROM `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "metadata" #>> ARRAY['author', 'first name'] FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT json_extract(`metadata`, '$.author."first name"') FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn cast_json_path<K, I>(self, path: I) -> SimpleExpr
+        where K: Into<Value>, I: IntoIterator<Item = K> {
+        self.bin_oper(BinOper::JsonGetPathText, json_keys(path))
+    }
+
+    /// Express a JSON containment test, `@>` on Postgres and `JSON_CONTAINS` on MySQL. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Alias::new("metadata")).json_contains(r#"{"font":"Arial"}"#))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE JSON_CONTAINS(`metadata`, '{\"font\":\"Arial\"}')"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "metadata" @> '{"font":"Arial"}'"#
+    /// );
+    /// ```
+    pub fn json_contains<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::JsonContains, v.into())
+    }
+
+    /// Express a JSON containment test the other way round, `<@` on Postgres and `JSON_CONTAINS` on MySQL. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Alias::new("tags")).json_contained_by(Expr::col(Alias::new("allowed_tags"))))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE JSON_CONTAINS(`allowed_tags`, `tags`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "tags" <@ "allowed_tags""#
+    /// );
+    /// ```
+    pub fn json_contained_by<V>(self, v: V) -> SimpleExpr
+        where V: Into<SimpleExpr> {
+        self.bin_oper(BinOper::JsonContainedBy, v.into())
+    }
+
+    /// Express whether a JSON object has the key, `?` on Postgres and `JSON_CONTAINS_PATH` on MySQL. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Alias::new("metadata")).json_has_key("font"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE JSON_CONTAINS_PATH(`metadata`, 'one', '$.font')"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "metadata" ? 'font'"#
+    /// );
+    /// ```
+    pub fn json_has_key(self, key: &str) -> SimpleExpr {
+        self.bin_oper(BinOper::JsonHasKey, SimpleExpr::Value(key.into()))
+    }
+
+    /// Express whether a JSON object has any of the keys, `?|` on Postgres and `JSON_CONTAINS_PATH` on MySQL. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Alias::new("metadata")).json_has_any_keys(vec!["font", "size"]))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE JSON_CONTAINS_PATH(`metadata`, 'one', '$.font', '$.size')"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "metadata" ?| ARRAY['font', 'size']"#
+    /// );
+    /// ```
+    pub fn json_has_any_keys<'a, I>(self, keys: I) -> SimpleExpr
+        where I: IntoIterator<Item = &'a str> {
+        self.bin_oper(BinOper::JsonHasAnyKey, json_keys(keys))
+    }
+
+    /// Express whether a JSON object has all of the keys, `?&` on Postgres and `JSON_CONTAINS_PATH` on MySQL. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Alias::new("metadata")).json_has_all_keys(vec!["font", "size"]))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` WHERE JSON_CONTAINS_PATH(`metadata`, 'all', '$.font', '$.size')"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "metadata" ?& ARRAY['font', 'size']"#
     /// );
     /// ```
-    /// Empty value list
+    pub fn json_has_all_keys<'a, I>(self, keys: I) -> SimpleExpr
+        where I: IntoIterator<Item = &'a str> {
+        self.bin_oper(BinOper::JsonHasAllKeys, json_keys(keys))
+    }
+
+    /// Express a full-text search on a column, or on a [`Expr::tuple`] of columns, in natural language mode.
+    /// Rendered as `MATCH (..) AGAINST (..)` on MySQL, `to_tsvector(..) @@ plainto_tsquery(..)` on Postgres,
+    /// and as the FTS5 `MATCH` operator on Sqlite, where the column may also be the FTS5 table itself.
+    /// 
+    /// # Examples
+    /// 
     /// ```
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
+    ///     .column(Char::Id)
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_in(Vec::<u8>::new()))
+    ///     .and_where(Expr::col(Char::Character).matches("sea query"))
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    ///     r#"SELECT `id` FROM `character` WHERE MATCH (`character`) AGAINST ('sea query')"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE 1 = 2"#
+    ///     r#"SELECT "id" FROM "character" WHERE to_tsvector("character") @@ plainto_tsquery('sea query')"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    ///     r#"SELECT `id` FROM `character` WHERE `character` MATCH 'sea query'"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_in<V, I>(mut self, v: I) -> SimpleExpr
-    where
-        V: Into<Value>,
-        I: IntoIterator<Item = V>,
-    {
-        self.bopr = Some(BinOper::In);
-        self.right = Some(SimpleExpr::Values(
-            v.into_iter().map(|v| v.into()).collect(),
-        ));
-        self.into()
-    }
-
-    /// Express a `NOT IN` expression.
     /// 
-    /// # Examples
+    /// Multiple columns
     /// 
     /// ```
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
+    ///     .column(Char::Id)
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(vec![1, 2, 3]))
+    ///     .and_where(
+    ///         Expr::tuple(vec![Expr::col(Char::Character), Expr::col(Char::FontId)])
+    ///             .matches("sea query")
+    ///     )
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
+    ///     r#"SELECT `id` FROM `character` WHERE MATCH (`character`, `font_id`) AGAINST ('sea query')"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" NOT IN (1, 2, 3)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
+    ///     r#"SELECT "id" FROM "character" WHERE to_tsvector(concat_ws(' ', "character", "font_id")) @@ plainto_tsquery('sea query')"#
     /// );
     /// ```
-    /// Empty value list
+    pub fn matches(self, query: &str) -> SimpleExpr {
+        self.bin_oper(BinOper::Matches, SimpleExpr::Value(query.into()))
+    }
+
+    /// Express a full-text search in boolean mode, where words can be required with `+`, excluded with `-`
+    /// or grouped in a phrase with double quotes.
+    /// Rendered as `MATCH (..) AGAINST (.. IN BOOLEAN MODE)` on MySQL, with `websearch_to_tsquery` on Postgres (11 or later),
+    /// and as the FTS5 `MATCH` operator on Sqlite, where the query uses the FTS5 syntax instead.
+    /// 
+    /// Postgres treats the words without a prefix as required, while MySQL ranks them as optional,
+    /// and understands `OR` between words but none of the MySQL operators other than `+`, `-` and double quotes.
+    /// 
+    /// # Examples
+    /// 
     /// ```
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
+    ///     .column(Char::Id)
     ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(Vec::<u8>::new()))
+    ///     .and_where(Expr::col(Char::Character).matches_boolean("+sea -orm"))
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    ///     r#"SELECT `id` FROM `character` WHERE MATCH (`character`) AGAINST ('+sea -orm' IN BOOLEAN MODE)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE 1 = 1"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    ///     r#"SELECT "id" FROM "character" WHERE to_tsvector("character") @@ websearch_to_tsquery('+sea -orm')"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_not_in<V, I>(mut self, v: I) -> SimpleExpr
-    where
-        V: Into<Value>,
-        I: IntoIterator<Item = V>
-    {
-        self.bopr = Some(BinOper::NotIn);
-        self.right = Some(SimpleExpr::Values(v.into_iter().map(|v| v.into()).collect()));
-        self.into()
+    pub fn matches_boolean(self, query: &str) -> SimpleExpr {
+        self.bin_oper(BinOper::MatchesBoolean, SimpleExpr::Value(query.into()))
     }
 
-    /// Express a `IN` sub-query expression.
+    /// Express the relevance of a full-text search in natural language mode, for ordering the matches.
+    /// Rendered as `MATCH (..) AGAINST (..)` on MySQL and `ts_rank` on Postgres.
+    /// Not supported by Sqlite, where FTS5 tables have a `rank` column instead.
     /// 
     /// # Examples
     /// 
@@ -1120,66 +2287,26 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .column(Char::Id)
     ///     .from(Char::Table)
-    ///     .and_where(Expr::col(Char::SizeW).in_subquery(
-    ///         Query::select()
-    ///             .expr(Expr::cust("3 + 2 * 2"))
-    ///             .take()
-    ///     ))
+    ///     .and_where(Expr::col(Char::Character).matches("sea query"))
+    ///     .order_by_expr(Expr::col(Char::Character).match_rank("sea query"), Order::Desc)
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    ///     r#"SELECT `id` FROM `character` WHERE MATCH (`character`) AGAINST ('sea query') ORDER BY MATCH (`character`) AGAINST ('sea query') DESC"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" IN (SELECT 3 + 2 * 2)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    ///     vec![
+    ///         r#"SELECT "id" FROM "character" WHERE to_tsvector("character") @@ plainto_tsquery('sea query')"#,
+    ///         r#"ORDER BY ts_rank(to_tsvector("character"), plainto_tsquery('sea query')) DESC"#,
+    ///     ].join(" ")
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn in_subquery(mut self, sel: SelectStatement) -> SimpleExpr {
-        self.bopr = Some(BinOper::In);
-        self.right = Some(SimpleExpr::SubQuery(Box::new(sel)));
-        self.into()
-    }
-
-    pub(crate) fn func(func: Function) -> Self {
-        let mut expr = Expr::new();
-        expr.func = Some(func);
-        expr
-    }
-
-    pub fn arg<T>(mut self, arg: T) -> SimpleExpr
-        where T: Into<SimpleExpr> {
-        self.args = vec![arg.into()];
-        self.into()
-    }
-
-    pub fn args<T, I>(mut self, args: I) -> SimpleExpr
-    where
-        T: Into<SimpleExpr>,
-        I: IntoIterator<Item = T>,
-    {
-        self.args = args.into_iter().map(|v| v.into()).collect();
-        self.into()
-    }
-
-    fn func_with_args(func: Function, args: Vec<SimpleExpr>) -> SimpleExpr {
-        let mut expr = Expr::new();
-        expr.func = Some(func);
-        expr.args = args;
-        expr.into()
-    }
-
-    fn un_oper(mut self, o: UnOper) -> SimpleExpr {
-        self.uopr = Some(o);
-        self.into()
+    pub fn match_rank(self, query: &str) -> SimpleExpr {
+        Self::func_with_args(Function::MatchRank, vec![self.into(), SimpleExpr::Value(query.into())])
     }
 
     fn bin_oper(mut self, o: BinOper, e: SimpleExpr) -> SimpleExpr {
@@ -1210,7 +2337,8 @@ impl Into<SimpleExpr> for Expr {
         } else if let Some(func) = self.func {
             SimpleExpr::FunctionCall(
                 func,
-                self.args
+                self.args,
+                AggregateModifiers::default(),
             )
         } else if let Some(left) = self.left {
             left
@@ -1220,6 +2348,13 @@ impl Into<SimpleExpr> for Expr {
     }
 }
 
+impl<T> From<T> for SimpleExpr
+    where T: Into<Value> {
+    fn from(v: T) -> SimpleExpr {
+        SimpleExpr::Value(v.into())
+    }
+}
+
 impl Into<SelectExpr> for Expr {
     fn into(self) -> SelectExpr {
         self.into_simple_expr().into()
@@ -1243,15 +2378,15 @@ impl SimpleExpr {
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE ((`size_w` = 1) AND (`size_h` = 2)) OR ((`size_w` = 3) AND (`size_h` = 4))"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w` = 1) AND (`size_h` = 2) OR (`size_w` = 3) AND (`size_h` = 4)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE (("size_w" = 1) AND ("size_h" = 2)) OR (("size_w" = 3) AND ("size_h" = 4))"#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE ("size_w" = 1) AND ("size_h" = 2) OR ("size_w" = 3) AND ("size_h" = 4)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE ((`size_w` = 1) AND (`size_h` = 2)) OR ((`size_w` = 3) AND (`size_h` = 4))"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w` = 1) AND (`size_h` = 2) OR (`size_w` = 3) AND (`size_h` = 4)"#
     /// );
     /// ```
     pub fn and(self, right: SimpleExpr) -> Self {
@@ -1413,6 +2548,106 @@ impl SimpleExpr {
         self.binary(BinOper::Sub, right.into())
     }
 
+    /// Apply `DISTINCT` to the arguments of an aggregate function call.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::count(Expr::col(Char::FontId)).distinct())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT COUNT(DISTINCT `font_id`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COUNT(DISTINCT "font_id") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT COUNT(DISTINCT `font_id`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn distinct(mut self) -> Self {
+        self.aggregate_modifiers().distinct = true;
+        self
+    }
+
+    /// Only aggregate the rows matching `condition`, i.e. `FILTER (WHERE ...)`.
+    /// Emulated by `CASE WHEN ... THEN ... END` on MySQL.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::sum(Expr::col(Char::SizeW)).filter(Expr::col(Char::FontSize).gt(10)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT SUM(CASE WHEN `font_size` > 10 THEN `size_w` END) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SUM("size_w") FILTER (WHERE "font_size" > 10) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT SUM(`size_w`) FILTER (WHERE `font_size` > 10) FROM `character`"#
+    /// );
+    /// ```
+    pub fn filter(mut self, condition: SimpleExpr) -> Self {
+        self.aggregate_modifiers().filter = Some(Box::new(condition));
+        self
+    }
+
+    /// Order the values fed to an aggregate function call by column.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::group_concat(Expr::col(Char::Character)).order_by(Char::Id, Order::Desc))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT GROUP_CONCAT(`character` ORDER BY `id` DESC SEPARATOR ',') FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT STRING_AGG("character", ',' ORDER BY "id" DESC) FROM "character""#
+    /// );
+    /// ```
+    pub fn order_by<T>(self, col: T, order: Order) -> Self
+        where T: IntoColumnRef {
+        self.order_by_expr(SimpleExpr::Column(col.into_column_ref()), order)
+    }
+
+    /// Order the values fed to an aggregate function call by expression.
+    pub fn order_by_expr(mut self, expr: SimpleExpr, order: Order) -> Self {
+        self.aggregate_modifiers().order_by.push(OrderExpr::expr(expr, order));
+        self
+    }
+
+    fn aggregate_modifiers(&mut self) -> &mut AggregateModifiers {
+        match self {
+            Self::FunctionCall(_, _, modifiers) => modifiers,
+            _ => panic!("Aggregate modifiers can only be applied to function calls"),
+        }
+    }
+
     pub(crate) fn binary(self, op: BinOper, right: SimpleExpr) -> Self {
         SimpleExpr::Binary(Box::new(self), op, Box::new(right))
     }
@@ -1441,21 +2676,277 @@ impl SimpleExpr {
         matches!(self, Self::Binary(_, _, _))
     }
 
-    pub(crate) fn is_values(&self) -> bool {
-        matches!(self, Self::Values(_))
+    /// Whether it is an empty list on the right of `IN` / `NOT IN`
+    pub(crate) fn is_empty_list(&self) -> bool {
+        match self {
+            Self::Values(list) => list.is_empty(),
+            Self::Tuple(list) => list.is_empty(),
+            _ => false,
+        }
     }
 
-    pub(crate) fn get_values(&self) -> &Vec<Value> {
+    /// Whether it is a list of row values, as built by [`Expr::in_tuples`]
+    pub(crate) fn is_tuple_list(&self) -> bool {
         match self {
-            Self::Values(vec) => vec,
-            _ => panic!("not Values"),
+            Self::Tuple(list) => list.iter().all(|e| matches!(e, Self::Tuple(_))),
+            _ => false,
         }
     }
 
+    fn tuple_list<V, I>(rows: I) -> Self
+    where
+        V: IntoValueTuple,
+        I: IntoIterator<Item = V>,
+    {
+        Self::Tuple(
+            rows.into_iter()
+                .map(|row| Self::Tuple(row.into_value_tuple().into_iter().map(Self::Value).collect()))
+                .collect()
+        )
+    }
+
     pub(crate) fn get_bin_oper(&self) -> Option<BinOper> {
         match self {
             Self::Binary(_, oper, _) => Some(*oper),
             _ => None,
         }
     }
+
+    /// The columns searched by a full-text search, a single column or a tuple of columns
+    pub(crate) fn full_text_columns(&self) -> Vec<&SimpleExpr> {
+        match self {
+            Self::Tuple(exprs) => exprs.iter().collect(),
+            _ => vec![self],
+        }
+    }
+
+    /// Whether the operand of unary operator `op` has to be enclosed in parentheses
+    pub(crate) fn need_parentheses_under(&self, op: &UnOper) -> bool {
+        match op {
+            UnOper::Not => matches!(self.get_bin_oper(), Some(BinOper::And) | Some(BinOper::Or)),
+            UnOper::Neg => self.is_binary(),
+        }
+    }
+
+    /// Whether it is a JSON operator, which MySQL and Sqlite express by functions
+    pub(crate) fn is_json_oper(oper: &BinOper) -> bool {
+        matches!(oper,
+            BinOper::JsonGet | BinOper::JsonGetText | BinOper::JsonGetPath | BinOper::JsonGetPathText |
+            BinOper::JsonContains | BinOper::JsonContainedBy |
+            BinOper::JsonHasKey | BinOper::JsonHasAnyKey | BinOper::JsonHasAllKeys
+        )
+    }
+
+    /// Whether it is a JSON operator taking a list of keys on the right
+    pub(crate) fn is_json_keys_oper(oper: &BinOper) -> bool {
+        matches!(oper, BinOper::JsonGetPath | BinOper::JsonGetPathText | BinOper::JsonHasAnyKey | BinOper::JsonHasAllKeys)
+    }
+
+    /// The keys on the right of a JSON operator as text, the way Postgres takes a `text[]` of keys and indexes
+    pub(crate) fn json_text_keys(&self) -> Vec<Value> {
+        match self {
+            Self::Values(keys) => keys
+                .iter()
+                .map(|key| match json_index(key) {
+                    Some(index) => index.to_string().into(),
+                    None => key.clone(),
+                })
+                .collect(),
+            _ => panic!("Only values can be used as JSON keys"),
+        }
+    }
+
+    /// The keys on the right of a JSON operator, as JSON paths in the `$.key[0]` form of MySQL and Sqlite.
+    /// A path operator yields a single path, key existence operators yield one path per key.
+    pub(crate) fn json_paths(&self, oper: &BinOper) -> Vec<String> {
+        let keys = match self {
+            Self::Value(key) => std::slice::from_ref(key),
+            Self::Values(keys) => keys.as_slice(),
+            _ => panic!("Only values can be used as JSON keys"),
+        };
+        if matches!(oper, BinOper::JsonGetPath | BinOper::JsonGetPathText) {
+            vec![keys.iter().fold("$".to_owned(), |path, key| path + &json_path_leg(key))]
+        } else {
+            keys.iter().map(|key| format!("${}", json_path_leg(key))).collect()
+        }
+    }
+}
+
+macro_rules! impl_bin_oper_ops {
+    ( $trait: ident, $method: ident, $oper: expr ) => {
+        impl<T> std::ops::$trait<T> for SimpleExpr
+            where T: Into<SimpleExpr> {
+            type Output = SimpleExpr;
+
+            fn $method(self, rhs: T) -> SimpleExpr {
+                self.binary($oper, rhs.into())
+            }
+        }
+
+        impl<T> std::ops::$trait<T> for Expr
+            where T: Into<SimpleExpr> {
+            type Output = Expr;
+
+            fn $method(self, rhs: T) -> Expr {
+                Expr::expr(self.into_simple_expr().binary($oper, rhs.into()))
+            }
+        }
+    };
+}
+
+impl_bin_oper_ops!(Add, add, BinOper::Add);
+impl_bin_oper_ops!(Sub, sub, BinOper::Sub);
+impl_bin_oper_ops!(Mul, mul, BinOper::Mul);
+impl_bin_oper_ops!(Div, div, BinOper::Div);
+impl_bin_oper_ops!(Rem, rem, BinOper::Mod);
+impl_bin_oper_ops!(BitAnd, bitand, BinOper::And);
+impl_bin_oper_ops!(BitOr, bitor, BinOper::Or);
+
+macro_rules! impl_un_oper_ops {
+    ( $trait: ident, $method: ident, $oper: expr ) => {
+        impl std::ops::$trait for SimpleExpr {
+            type Output = SimpleExpr;
+
+            fn $method(self) -> SimpleExpr {
+                SimpleExpr::Unary($oper, Box::new(self))
+            }
+        }
+
+        impl std::ops::$trait for Expr {
+            type Output = Expr;
+
+            fn $method(self) -> Expr {
+                Expr::expr(SimpleExpr::Unary($oper, Box::new(self.into_simple_expr())))
+            }
+        }
+    };
+}
+
+impl_un_oper_ops!(Neg, neg, UnOper::Neg);
+impl_un_oper_ops!(Not, not, UnOper::Not);
+
+/// Build a custom expression with [`Expr::cust_with_exprs`], checking at compile time that the
+/// highest numbered placeholder in the template matches the number of expressions.
+/// The expressions can be of different types, as long as they convert into [`SimpleExpr`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+/// 
+/// let query = Query::select()
+///     .expr(cust_with_exprs!(
+///         "COALESCE($1, $2, $1)",
+///         Query::select().expr(Func::max(Expr::col(Char::SizeW))).from(Char::Table).to_owned(),
+///         Expr::val(0),
+///     ))
+///     .to_owned();
+/// 
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"SELECT COALESCE((SELECT MAX(`size_w`) FROM `character`), 0, (SELECT MAX(`size_w`) FROM `character`))"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"SELECT COALESCE((SELECT MAX("size_w") FROM "character"), 0, (SELECT MAX("size_w") FROM "character"))"#
+/// );
+/// ```
+/// 
+/// A mismatch fails to compile:
+/// 
+/// ```compile_fail
+/// use sea_query::{*, tests_cfg::*};
+/// 
+/// let expr = cust_with_exprs!("ST_Distance($1, $2)", Expr::col(Char::Id));
+/// ```
+#[macro_export]
+macro_rules! cust_with_exprs {
+    ($template:literal $(, $expr:expr)* $(,)?) => {{
+        const _: () = assert!(
+            $crate::expr::custom_placeholder_count($template) == <[&str]>::len(&[$(stringify!($expr)),*]),
+            "The number of expressions does not match the placeholders of the custom expression"
+        );
+        let exprs: ::std::vec::Vec<$crate::SimpleExpr> = vec![$(<_ as ::std::convert::Into<$crate::SimpleExpr>>::into($expr)),*];
+        $crate::Expr::cust_with_exprs($template, exprs)
+    }};
+}
+
+/// The highest numbered placeholder `$n` of a custom expression template, skipping quoted strings and identifiers.
+/// Used by [`cust_with_exprs!`].
+#[doc(hidden)]
+pub const fn custom_placeholder_count(template: &str) -> usize {
+    let bytes = template.as_bytes();
+    let mut max = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'\'' || c == b'"' || c == b'`' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != c {
+                if bytes[i] == b'\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+        } else if c == b'$' && (i == 0 || !is_identifier_byte(bytes[i - 1])) {
+            let mut n = 0;
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j].is_ascii_digit() {
+                n = n * 10 + (bytes[j] - b'0') as usize;
+                j += 1;
+            }
+            if j > i + 1 && (j == bytes.len() || !is_identifier_byte(bytes[j])) && n > max {
+                max = n;
+            }
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    max
+}
+
+/// Part of an unquoted identifier, non-ASCII characters included
+const fn is_identifier_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'$' || c >= 0x80
+}
+
+fn json_keys<K, I>(keys: I) -> SimpleExpr
+where
+    K: Into<Value>,
+    I: IntoIterator<Item = K>,
+{
+    SimpleExpr::Values(keys.into_iter().map(|key| key.into()).collect())
+}
+
+fn json_path_leg(key: &Value) -> String {
+    if let Some(index) = json_index(key) {
+        return format!("[{}]", index);
+    }
+    match key {
+        Value::String(key) => {
+            let plain = key.chars().enumerate().all(|(i, c)| c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()));
+            if plain && !key.is_empty() {
+                format!(".{}", key)
+            } else {
+                format!(".\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+        },
+        _ => panic!("JSON keys can only be strings or integers"),
+    }
+}
+
+fn json_index(key: &Value) -> Option<i128> {
+    match key {
+        Value::TinyInt(i) => Some(*i as i128),
+        Value::SmallInt(i) => Some(*i as i128),
+        Value::Int(i) => Some(*i as i128),
+        Value::BigInt(i) => Some(*i as i128),
+        Value::TinyUnsigned(i) => Some(*i as i128),
+        Value::SmallUnsigned(i) => Some(*i as i128),
+        Value::Unsigned(i) => Some(*i as i128),
+        Value::BigUnsigned(i) => Some(*i as i128),
+        _ => None,
+    }
 }
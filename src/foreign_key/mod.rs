@@ -5,6 +5,8 @@
 //! - Table Foreign Key Create, see [`ForeignKeyCreateStatement`]
 //! - Table Foreign Key Drop, see [`ForeignKeyDropStatement`]
 
+use crate::ForeignKeyBuilder;
+
 mod common;
 mod create;
 mod drop;
@@ -34,4 +36,30 @@ impl ForeignKey {
     pub fn drop() -> ForeignKeyDropStatement {
         ForeignKeyDropStatement::new()
     }
+}
+
+impl ForeignKeyStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: ForeignKeyBuilder>(&self, foreign_key_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.build(foreign_key_builder),
+            Self::Drop(stat) => stat.build(foreign_key_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, foreign_key_builder: &dyn ForeignKeyBuilder) -> String {
+        match self {
+            Self::Create(stat) => stat.build_any(foreign_key_builder),
+            Self::Drop(stat) => stat.build_any(foreign_key_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: ForeignKeyBuilder>(&self, foreign_key_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.to_string(foreign_key_builder),
+            Self::Drop(stat) => stat.to_string(foreign_key_builder),
+        }
+    }
 }
\ No newline at end of file
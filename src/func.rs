@@ -1,7 +1,7 @@
 //! For calling built-in SQL functions.
 
 use std::rc::Rc;
-use crate::{expr::*, types::*};
+use crate::{expr::*, types::*, value::*};
 
 /// Functions
 #[derive(Debug, Clone)]
@@ -13,9 +13,51 @@ pub enum Function {
     Count,
     IfNull,
     CharLength,
+    Grouping,
+    JsonBuildObject,
+    JsonAgg,
+    JsonbSet,
+    StringAgg,
+    ArrayAgg,
+    MatchRank,
+    Coalesce,
+    Lower,
+    Upper,
+    Trim,
+    Substring,
+    Concat,
+    Replace,
+    Abs,
+    Round,
+    Greatest,
+    Least,
+    Now,
+    CurrentDate,
+    DateTrunc(DateTimeField),
+    Extract(DateTimeField),
+    Random,
     Custom(Rc<dyn Iden>),
 }
 
+/// Fields of a date time, for [`Func::extract`] and [`Func::date_trunc`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// Modifiers of an aggregate function call, i.e. `DISTINCT`, `ORDER BY` and `FILTER (WHERE ...)`
+#[derive(Debug, Clone, Default)]
+pub struct AggregateModifiers {
+    pub(crate) distinct: bool,
+    pub(crate) order_by: Vec<OrderExpr>,
+    pub(crate) filter: Option<Box<SimpleExpr>>,
+}
+
 /// Function call helper.
 #[derive(Debug, Clone)]
 pub struct Func;
@@ -268,4 +310,737 @@ impl Func {
         where A: Into<SimpleExpr>, B: Into<SimpleExpr> {
         Expr::func(Function::IfNull).args(vec![a.into(), b.into()])
     }
-}
\ No newline at end of file
+
+    /// Call `COALESCE` function, the first of the expressions which is not null.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::coalesce(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH), Expr::val(0)]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT COALESCE(`size_w`, `size_h`, 0) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COALESCE("size_w", "size_h", 0) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT COALESCE(`size_w`, `size_h`, 0) FROM `character`"#
+    /// );
+    /// ```
+    pub fn coalesce<T, I>(exprs: I) -> SimpleExpr
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = T> {
+        Expr::func(Function::Coalesce).args(exprs)
+    }
+
+    /// Call `LOWER` function.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::lower(Expr::col(Char::Character)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT LOWER(`character`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT LOWER("character") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT LOWER(`character`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn lower<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::Lower).arg(expr)
+    }
+
+    /// Call `UPPER` function.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::upper(Expr::col(Char::Character)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT UPPER(`character`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT UPPER("character") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT UPPER(`character`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn upper<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::Upper).arg(expr)
+    }
+
+    /// Call `TRIM` function, removing spaces from both ends.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::trim(Expr::col(Char::Character)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT TRIM(`character`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT TRIM("character") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT TRIM(`character`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn trim<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::Trim).arg(expr)
+    }
+
+    /// Call `SUBSTRING` function, taking `length` characters or the rest of the string from the 1-based `start`.
+    /// Rendered as `SUBSTR` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::substring(Expr::col(Char::Character), 2, Some(3)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT SUBSTRING(`character`, 2, 3) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SUBSTRING("character", 2, 3) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT SUBSTR(`character`, 2, 3) FROM `character`"#
+    /// );
+    /// ```
+    pub fn substring<T>(expr: T, start: i64, length: Option<i64>) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        let mut args = vec![expr.into(), start.into()];
+        if let Some(length) = length {
+            args.push(length.into());
+        }
+        Expr::func(Function::Substring).args(args)
+    }
+
+    /// Call `CONCAT` function. Rendered as `||` on Sqlite.
+    /// 
+    /// The result is `NULL` if any of the expressions is `NULL` on Mysql and Sqlite,
+    /// while Postgres skips the `NULL` arguments. Wrap nullable expressions with [`Func::coalesce`]
+    /// for the same result everywhere.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::concat(vec![Expr::col(Char::Character), Expr::val("-"), Expr::col(Char::FontId)]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CONCAT(`character`, '-', `font_id`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CONCAT("character", '-', "font_id") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT (`character` || '-' || `font_id`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn concat<T, I>(exprs: I) -> SimpleExpr
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = T> {
+        Expr::func(Function::Concat).args(exprs)
+    }
+
+    /// Call `REPLACE` function, replacing all occurrences of `from` by `to`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::replace(Expr::col(Char::Character), "a", "b"))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT REPLACE(`character`, 'a', 'b') FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT REPLACE("character", 'a', 'b') FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT REPLACE(`character`, 'a', 'b') FROM `character`"#
+    /// );
+    /// ```
+    pub fn replace<T, F, R>(expr: T, from: F, to: R) -> SimpleExpr
+        where T: Into<SimpleExpr>, F: Into<SimpleExpr>, R: Into<SimpleExpr> {
+        Expr::func(Function::Replace).args(vec![expr.into(), from.into(), to.into()])
+    }
+
+    /// Call `ABS` function.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::abs(Expr::col(Char::SizeW)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT ABS(`size_w`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT ABS("size_w") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT ABS(`size_w`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn abs<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::Abs).arg(expr)
+    }
+
+    /// Call `ROUND` function, rounding to `digits` decimal places or to an integer.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::round(Expr::col(Char::SizeW), Some(2)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT ROUND(`size_w`, 2) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT ROUND("size_w", 2) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT ROUND(`size_w`, 2) FROM `character`"#
+    /// );
+    /// ```
+    pub fn round<T>(expr: T, digits: Option<i32>) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        let mut args = vec![expr.into()];
+        if let Some(digits) = digits {
+            args.push(digits.into());
+        }
+        Expr::func(Function::Round).args(args)
+    }
+
+    /// Call `GREATEST` function, the largest of the expressions. Rendered as `MAX` on Sqlite.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics with less than two expressions, `MAX` of a single expression is the aggregate function on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::greatest(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH)]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT GREATEST(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT GREATEST("size_w", "size_h") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MAX(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn greatest<T, I>(exprs: I) -> SimpleExpr
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = T> {
+        let exprs: Vec<SimpleExpr> = exprs.into_iter().map(Into::into).collect();
+        if exprs.len() < 2 {
+            panic!("GREATEST takes at least two expressions");
+        }
+        Expr::func(Function::Greatest).args(exprs)
+    }
+
+    /// Call `LEAST` function, the smallest of the expressions. Rendered as `MIN` on Sqlite.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics with less than two expressions, `MIN` of a single expression is the aggregate function on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::least(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH)]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT LEAST(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT LEAST("size_w", "size_h") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MIN(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn least<T, I>(exprs: I) -> SimpleExpr
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = T> {
+        let exprs: Vec<SimpleExpr> = exprs.into_iter().map(Into::into).collect();
+        if exprs.len() < 2 {
+            panic!("LEAST takes at least two expressions");
+        }
+        Expr::func(Function::Least).args(exprs)
+    }
+
+    /// Call `NOW` function, the current date and time. Rendered as `CURRENT_TIMESTAMP` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::now())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT NOW() FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT NOW() FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP FROM `character`"#
+    /// );
+    /// ```
+    pub fn now() -> SimpleExpr {
+        Expr::func(Function::Now).into_simple_expr()
+    }
+
+    /// Express `CURRENT_DATE`, the current date.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::current_date())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CURRENT_DATE FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CURRENT_DATE FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CURRENT_DATE FROM `character`"#
+    /// );
+    /// ```
+    pub fn current_date() -> SimpleExpr {
+        Expr::func(Function::CurrentDate).into_simple_expr()
+    }
+
+    /// Call `DATE_TRUNC` function, truncating a date time to the start of the `field`.
+    /// Emulated by formatting the date time with `DATE_FORMAT` on MySQL and `strftime` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::date_trunc(DateTimeField::Month, Expr::col(Alias::new("created_at"))))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CAST(DATE_FORMAT(`created_at`, '%Y-%m-01 00:00:00') AS DATETIME) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT DATE_TRUNC('month', "created_at") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT strftime('%Y-%m-01 00:00:00', `created_at`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn date_trunc<T>(field: DateTimeField, expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::DateTrunc(field)).arg(expr)
+    }
+
+    /// Call `EXTRACT` function, a field of a date time as a number.
+    /// Emulated by `strftime` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::extract(DateTimeField::Year, Expr::col(Alias::new("created_at"))))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT EXTRACT(YEAR FROM `created_at`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT EXTRACT(YEAR FROM "created_at") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST(strftime('%Y', `created_at`) AS INTEGER) FROM `character`"#
+    /// );
+    /// ```
+    pub fn extract<T>(field: DateTimeField, expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::Extract(field)).arg(expr)
+    }
+
+    /// Call `RANDOM` function, a random number from 0 to 1. Rendered as `RAND` on MySQL,
+    /// and scaled from the random integer of `RANDOM` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::random())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT RAND() FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT RANDOM() FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT ABS(RANDOM() / 9223372036854775808.0) FROM `character`"#
+    /// );
+    /// ```
+    pub fn random() -> SimpleExpr {
+        Expr::func(Function::Random).into_simple_expr()
+    }
+
+    /// Call `GROUPING` function, telling whether the columns are rolled up in a subtotal row. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr_as(Func::grouping(vec![Expr::col(Char::FontId)]), Alias::new("is_total"))
+    ///     .expr(Func::count(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_rollup(vec![Char::FontId])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUPING(`font_id`) AS `is_total`, COUNT(`id`) FROM `character` GROUP BY `font_id` WITH ROLLUP"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", GROUPING("font_id") AS "is_total", COUNT("id") FROM "character" GROUP BY ROLLUP ("font_id")"#
+    /// );
+    /// ```
+    pub fn grouping<T, I>(exprs: I) -> SimpleExpr
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = T> {
+        Expr::func(Function::Grouping).args(exprs)
+    }
+
+    /// Call `jsonb_build_object` function, building a JSON object from pairs of keys and values.
+    /// Rendered as `JSON_OBJECT` on MySQL and `json_object` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .expr(Func::json_build_object(vec![
+    ///         ("id", Expr::col(Char::Id).into_simple_expr()),
+    ///         ("size", Expr::col(Char::FontSize).into_simple_expr()),
+    ///     ]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT JSON_OBJECT('id', `id`, 'size', `font_size`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT jsonb_build_object('id', "id", 'size', "font_size") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT json_object('id', `id`, 'size', `font_size`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn json_build_object<K, V, I>(pairs: I) -> SimpleExpr
+        where K: Into<Value>, V: Into<SimpleExpr>, I: IntoIterator<Item = (K, V)> {
+        Expr::func(Function::JsonBuildObject).args(
+            pairs
+                .into_iter()
+                .flat_map(|(k, v)| vec![SimpleExpr::Value(k.into()), v.into()])
+                .collect::<Vec<_>>()
+        )
+    }
+
+    /// Call `json_agg` aggregate function, collecting the values into a JSON array.
+    /// Rendered as `JSON_ARRAYAGG` on MySQL and `json_group_array` on Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(Func::json_agg(Expr::col(Char::Character)))
+    ///     .from(Char::Table)
+    ///     .group_by_columns(vec![Char::FontId])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, JSON_ARRAYAGG(`character`) FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", json_agg("character") FROM "character" GROUP BY "font_id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `font_id`, json_group_array(`character`) FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// ```
+    pub fn json_agg<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::JsonAgg).arg(expr)
+    }
+
+    /// Call `STRING_AGG` aggregate function, concatenating the values with `separator`.
+    /// Rendered as `GROUP_CONCAT` on MySQL and Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(Func::string_agg(Expr::col(Char::Character), ", ").order_by(Char::Id, Order::Asc))
+    ///     .from(Char::Table)
+    ///     .group_by_columns(vec![Char::FontId])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character` ORDER BY `id` ASC SEPARATOR ', ') FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", STRING_AGG("character", ', ' ORDER BY "id" ASC) FROM "character" GROUP BY "font_id""#
+    /// );
+    /// ```
+    pub fn string_agg<T>(expr: T, separator: &str) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::StringAgg).args(vec![expr.into(), SimpleExpr::Value(separator.into())])
+    }
+
+    /// Call `GROUP_CONCAT` aggregate function, concatenating the values with the default separator `,`.
+    /// Same as [`Func::string_agg`] with separator `","`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(Func::group_concat(Expr::col(Char::Character)))
+    ///     .from(Char::Table)
+    ///     .group_by_columns(vec![Char::FontId])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character` SEPARATOR ',') FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", STRING_AGG("character", ',') FROM "character" GROUP BY "font_id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character`, ',') FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// ```
+    pub fn group_concat<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Self::string_agg(expr, ",")
+    }
+
+    /// Call `ARRAY_AGG` aggregate function, collecting the values into an array. Postgres only,
+    /// use [`Func::json_agg`] for other backends.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(Func::array_agg(Expr::col(Char::Id)).distinct().order_by(Char::Id, Order::Desc))
+    ///     .from(Char::Table)
+    ///     .group_by_columns(vec![Char::FontId])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", ARRAY_AGG(DISTINCT "id" ORDER BY "id" DESC) FROM "character" GROUP BY "font_id""#
+    /// );
+    /// ```
+    pub fn array_agg<T>(expr: T) -> SimpleExpr
+        where T: Into<SimpleExpr> {
+        Expr::func(Function::ArrayAgg).arg(expr)
+    }
+
+    /// Call `jsonb_set` function, replacing the value at a path of keys and array indexes. Postgres only.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value_expr(Alias::new("metadata"), Func::jsonb_set(
+    ///         Expr::col(Alias::new("metadata")),
+    ///         vec!["tags", "0"],
+    ///         Expr::val(r#""bold""#),
+    ///     ))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "metadata" = jsonb_set("metadata", '{"tags","0"}', '"bold"')"#
+    /// );
+    /// ```
+    pub fn jsonb_set<'a, T, I, V>(target: T, path: I, new_value: V) -> SimpleExpr
+        where T: Into<SimpleExpr>, I: IntoIterator<Item = &'a str>, V: Into<SimpleExpr> {
+        // A `text[]` literal, with every key quoted so that no character has to be special cased
+        let path = path
+            .into_iter()
+            .map(|key| format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\"")))
+            .collect::<Vec<_>>()
+            .join(",");
+        Expr::func(Function::JsonbSet).args(vec![
+            target.into(),
+            SimpleExpr::Value(format!("{{{}}}", path).into()),
+            new_value.into(),
+        ])
+    }
+}
@@ -2,7 +2,7 @@ use std::rc::Rc;
 use crate::types::*;
 
 /// Specification of a table index
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TableIndex {
     pub(crate) name: Option<String>,
     pub(crate) columns: Vec<IndexColumn>,
@@ -15,7 +15,7 @@ pub struct IndexColumn {
     pub(crate) order: Option<IndexOrder>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IndexOrder {
     Asc,
     Desc,
@@ -25,6 +25,14 @@ pub trait IntoIndexColumn {
     fn into_index_column(self) -> IndexColumn;
 }
 
+impl PartialEq for IndexColumn {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.as_ref() == other.name.as_ref()
+            && self.prefix == other.prefix
+            && self.order == other.order
+    }
+}
+
 impl Default for TableIndex {
     fn default() -> Self {
         Self::new()
@@ -97,7 +97,7 @@ use super::common::*;
 ///     r#"CREATE INDEX `idx-glyph-aspect` ON `glyph` (`aspect` ASC)"#
 /// );
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IndexCreateStatement {
     pub(crate) table: Option<Rc<dyn Iden>>,
     pub(crate) index: TableIndex,
@@ -107,7 +107,7 @@ pub struct IndexCreateStatement {
 }
 
 /// Specification of a table index
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IndexType {
     BTree,
     FullText,
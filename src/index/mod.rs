@@ -5,6 +5,8 @@
 //! - Table Index Create, see [`IndexCreateStatement`]
 //! - Table Index Drop, see [`IndexDropStatement`]
 
+use crate::IndexBuilder;
+
 mod common;
 mod create;
 mod drop;
@@ -34,4 +36,30 @@ impl Index {
     pub fn drop() -> IndexDropStatement {
         IndexDropStatement::new()
     }
+}
+
+impl IndexStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: IndexBuilder>(&self, index_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.build(index_builder),
+            Self::Drop(stat) => stat.build(index_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, index_builder: &dyn IndexBuilder) -> String {
+        match self {
+            Self::Create(stat) => stat.build_any(index_builder),
+            Self::Drop(stat) => stat.build_any(index_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: IndexBuilder>(&self, index_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.to_string(index_builder),
+            Self::Drop(stat) => stat.to_string(index_builder),
+        }
+    }
 }
\ No newline at end of file
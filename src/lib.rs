@@ -158,9 +158,9 @@
 //!     vec![
 //!         "SELECT `character` FROM `character`",
 //!         "LEFT JOIN `font` ON `character`.`font_id` = `font`.`id`",
-//!         "WHERE ((`size_w` + 1) * 2 = (`size_h` / 2) - 1)",
+//!         "WHERE (`size_w` + 1) * 2 = (`size_h` / 2) - 1",
 //!             "AND `size_w` IN (SELECT 3 + 2 * 2)",
-//!             "OR ((`character` LIKE 'D') AND (`character` LIKE 'E'))",
+//!             "OR (`character` LIKE 'D') AND (`character` LIKE 'E')",
 //!     ].join(" ")
 //! );
 //! ```
@@ -282,15 +282,15 @@
 //! 
 //! assert_eq!(
 //!     query.to_string(MysqlQueryBuilder),
-//!     r#"DELETE FROM `glyph` WHERE (`id` < 1) OR (`id` > 10)"#
+//!     r#"DELETE FROM `glyph` WHERE `id` < 1 OR `id` > 10"#
 //! );
 //! assert_eq!(
 //!     query.to_string(PostgresQueryBuilder),
-//!     r#"DELETE FROM "glyph" WHERE ("id" < 1) OR ("id" > 10)"#
+//!     r#"DELETE FROM "glyph" WHERE "id" < 1 OR "id" > 10"#
 //! );
 //! assert_eq!(
 //!     query.to_string(SqliteQueryBuilder),
-//!     r#"DELETE FROM `glyph` WHERE (`id` < 1) OR (`id` > 10)"#
+//!     r#"DELETE FROM `glyph` WHERE `id` < 1 OR `id` > 10"#
 //! );
 //! ```
 //! 
@@ -564,10 +564,12 @@
 //! ```
 
 pub mod backend;
+pub mod discovery;
 pub mod driver;
 pub mod extension;
 pub mod foreign_key;
 pub mod index;
+pub mod migration;
 pub mod query;
 pub mod table;
 pub mod error;
@@ -0,0 +1,362 @@
+//! Versioned schema migrations.
+//!
+//! A [`Migration`] describes one step of schema evolution with an `up` and a `down` list of statements.
+//! A [`Migrator`] applies registered migrations in order, and records each applied migration
+//! in a history table (`seaql_migrations` by default) so that it runs only once.
+//! The history table is created by the first [`Migrator::up`], before that no migration counts as applied.
+//!
+//! Each migration runs in a transaction. MySQL commits implicitly before and after every DDL statement,
+//! so a failing migration there is not rolled back and may leave its earlier statements applied.
+//!
+//! sea-query does not own a database connection, statements are executed through a [`MigrationConnection`].
+//! The rusqlite driver (`sea_query_driver_rusqlite!`) generates one out of the box.
+//!
+//! # Examples
+//!
+//! ```
+//! use sea_query::{*, migration::*, tests_cfg::*};
+//!
+//! struct CreateFont;
+//!
+//! impl Migration for CreateFont {
+//!     fn name(&self) -> &str {
+//!         "m20210601_000001_create_font"
+//!     }
+//!
+//!     fn up(&self) -> Vec<MigrationStatement> {
+//!         vec![
+//!             Table::create()
+//!                 .table(Font::Table)
+//!                 .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+//!                 .col(ColumnDef::new(Font::Name).string().not_null())
+//!                 .to_owned()
+//!                 .into(),
+//!         ]
+//!     }
+//!
+//!     fn down(&self) -> Vec<MigrationStatement> {
+//!         vec![Table::drop().table(Font::Table).to_owned().into()]
+//!     }
+//! }
+//!
+//! let migrator = Migrator::new(SqliteQueryBuilder).migration(CreateFont);
+//!
+//! assert_eq!(
+//!     migrator.history_table_statement().to_string(SqliteQueryBuilder),
+//!     "CREATE TABLE IF NOT EXISTS `seaql_migrations` ( `version` text(255) NOT NULL PRIMARY KEY, `applied_at` integer NOT NULL )"
+//! );
+//! assert_eq!(
+//!     CreateFont.down()[0].build(&SqliteQueryBuilder).0,
+//!     "DROP TABLE `font`"
+//! );
+//! ```
+
+use std::fmt;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::{backend::*, discovery::*, expr::*, query::*, schema::*, table::*, index::*, foreign_key::*, types::*, value::*};
+
+/// A versioned migration
+pub trait Migration {
+    /// Unique name of the migration, recorded in the history table
+    fn name(&self) -> &str;
+
+    /// Statements that apply the migration
+    fn up(&self) -> Vec<MigrationStatement>;
+
+    /// Statements that revert the migration
+    fn down(&self) -> Vec<MigrationStatement>;
+
+    /// Turn foreign key enforcement off while the migration runs, Sqlite only.
+    ///
+    /// Sqlite ignores `PRAGMA foreign_keys` inside a transaction, so [`Migrator`] executes
+    /// `PRAGMA foreign_keys = OFF` before the transaction begins and `PRAGMA foreign_keys = ON` after it ends.
+    /// Required when the migration rebuilds a table other tables reference,
+    /// see [`SqliteQueryBuilder::rebuild_table`](crate::backend::SqliteQueryBuilder::rebuild_table).
+    fn foreign_keys_off(&self) -> bool {
+        false
+    }
+}
+
+/// Statement executed as part of a migration
+#[derive(Debug, Clone)]
+pub enum MigrationStatement {
+    Schema(SchemaStatement),
+    Query(QueryStatement),
+    /// Backend specific SQL executed as is, e.g. the statements of
+    /// [`SqliteQueryBuilder::rebuild_table`](crate::backend::SqliteQueryBuilder::rebuild_table)
+    Raw(String),
+}
+
+/// Connection used by [`Migrator`] to execute statements
+pub trait MigrationConnection {
+    type Error;
+
+    /// Execute a statement with bound values
+    fn execute(&mut self, sql: &str, values: Values) -> Result<(), Self::Error>;
+
+    /// Execute a query with bound values, returning one `Vec<Value>` per row in select order
+    fn query(&mut self, sql: &str, values: Values) -> Result<Vec<Vec<Value>>, Self::Error>;
+
+    fn begin(&mut self) -> Result<(), Self::Error> {
+        self.execute("BEGIN", Values(Vec::new()))
+    }
+
+    fn commit(&mut self) -> Result<(), Self::Error> {
+        self.execute("COMMIT", Values(Vec::new()))
+    }
+
+    fn rollback(&mut self) -> Result<(), Self::Error> {
+        self.execute("ROLLBACK", Values(Vec::new()))
+    }
+}
+
+/// Applies [`Migration`]s in registration order
+pub struct Migrator<B> {
+    builder: B,
+    table: Rc<dyn Iden>,
+    migrations: Vec<Box<dyn Migration>>,
+}
+
+/// Whether a registered migration has been applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStatus {
+    pub name: String,
+    pub applied: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MigrationHistory {
+    Table,
+    Version,
+    AppliedAt,
+}
+
+impl Iden for MigrationHistory {
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "{}", match self {
+            Self::Table => "seaql_migrations",
+            Self::Version => "version",
+            Self::AppliedAt => "applied_at",
+        }).unwrap();
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for Migrator<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrator")
+            .field("builder", &self.builder)
+            .field("table", &self.table)
+            .field("migrations", &self.migrations.iter().map(|m| m.name()).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl MigrationStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string and values
+    pub fn build<B>(&self, builder: &B) -> (String, Values)
+        where B: QueryBuilder + TableBuilder + IndexBuilder + ForeignKeyBuilder {
+        match self {
+            Self::Schema(SchemaStatement::TableStatement(stat)) => (stat.build_any(builder), Values(Vec::new())),
+            Self::Schema(SchemaStatement::IndexStatement(stat)) => (stat.build_any(builder), Values(Vec::new())),
+            Self::Schema(SchemaStatement::ForeignKeyStatement(stat)) => (stat.build_any(builder), Values(Vec::new())),
+            Self::Query(QueryStatement::Select(stat)) => stat.build_any(builder),
+            Self::Query(QueryStatement::Insert(stat)) => stat.build_any(builder),
+            Self::Query(QueryStatement::Update(stat)) => stat.build_any(builder),
+            Self::Query(QueryStatement::Delete(stat)) => stat.build_any(builder),
+            Self::Raw(sql) => (sql.clone(), Values(Vec::new())),
+        }
+    }
+
+    /// Build corresponding SQL statements for certain database backend, more than one if the backend
+    /// can not apply all the alter options of a [`TableAlterStatement`] in a single statement
+    pub fn build_statements<B>(&self, builder: &B) -> Vec<(String, Values)>
+        where B: QueryBuilder + TableBuilder + IndexBuilder + ForeignKeyBuilder {
+        match self {
+            Self::Schema(SchemaStatement::TableStatement(TableStatement::Alter(stat))) => builder
+                .split_table_alter_statement(stat)
+                .iter()
+                .map(|alter| (alter.build_any(builder), Values(Vec::new())))
+                .collect(),
+            _ => vec![self.build(builder)],
+        }
+    }
+}
+
+macro_rules! impl_from_statement {
+    ( $( $stat: ty => $variant: expr ),* $(,)? ) => {
+        $(
+            impl From<$stat> for MigrationStatement {
+                fn from(stat: $stat) -> Self {
+                    $variant(stat)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_statement!(
+    SchemaStatement => Self::Schema,
+    QueryStatement => Self::Query,
+    TableStatement => |s| Self::Schema(SchemaStatement::TableStatement(s)),
+    IndexStatement => |s| Self::Schema(SchemaStatement::IndexStatement(s)),
+    ForeignKeyStatement => |s| Self::Schema(SchemaStatement::ForeignKeyStatement(s)),
+    TableCreateStatement => |s| Self::Schema(SchemaStatement::TableStatement(TableStatement::Create(s))),
+    TableAlterStatement => |s| Self::Schema(SchemaStatement::TableStatement(TableStatement::Alter(s))),
+    TableDropStatement => |s| Self::Schema(SchemaStatement::TableStatement(TableStatement::Drop(s))),
+    TableRenameStatement => |s| Self::Schema(SchemaStatement::TableStatement(TableStatement::Rename(s))),
+    TableTruncateStatement => |s| Self::Schema(SchemaStatement::TableStatement(TableStatement::Truncate(s))),
+    IndexCreateStatement => |s| Self::Schema(SchemaStatement::IndexStatement(IndexStatement::Create(s))),
+    IndexDropStatement => |s| Self::Schema(SchemaStatement::IndexStatement(IndexStatement::Drop(s))),
+    ForeignKeyCreateStatement => |s| Self::Schema(SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Create(s))),
+    ForeignKeyDropStatement => |s| Self::Schema(SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Drop(s))),
+    SelectStatement => |s| Self::Query(QueryStatement::Select(s)),
+    InsertStatement => |s| Self::Query(QueryStatement::Insert(s)),
+    UpdateStatement => |s| Self::Query(QueryStatement::Update(s)),
+    DeleteStatement => |s| Self::Query(QueryStatement::Delete(s)),
+    String => Self::Raw,
+);
+
+impl<B> Migrator<B>
+    where B: QueryBuilder + TableBuilder + IndexBuilder + ForeignKeyBuilder + DiscoveryBuilder {
+    /// Construct a new [`Migrator`] rendering statements with the given backend
+    pub fn new(builder: B) -> Self {
+        Self {
+            builder,
+            table: Rc::new(MigrationHistory::Table),
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Set the name of the history table, `seaql_migrations` by default
+    pub fn table<T>(mut self, table: T) -> Self
+        where T: Iden + 'static {
+        self.table = Rc::new(table);
+        self
+    }
+
+    /// Register a migration. Migrations are applied in registration order.
+    pub fn migration<M>(mut self, migration: M) -> Self
+        where M: Migration + 'static {
+        self.migrations.push(Box::new(migration));
+        self
+    }
+
+    /// Statement creating the history table, if it does not exist yet
+    pub fn history_table_statement(&self) -> TableCreateStatement {
+        let mut create = Table::create()
+            .if_not_exists()
+            .col(ColumnDef::new(MigrationHistory::Version).string_len(255).not_null().primary_key())
+            .col(ColumnDef::new(MigrationHistory::AppliedAt).big_integer().not_null())
+            .to_owned();
+        create.table = Some(self.table.clone());
+        create
+    }
+
+    /// Status of every registered migration, in registration order
+    pub fn status<C>(&self, conn: &mut C) -> Result<Vec<MigrationStatus>, C::Error>
+        where C: MigrationConnection {
+        let applied = self.applied(conn)?;
+        Ok(self.migrations.iter().map(|migration| MigrationStatus {
+            name: migration.name().to_owned(),
+            applied: applied.iter().any(|name| name == migration.name()),
+        }).collect())
+    }
+
+    /// Apply pending migrations, all of them or at most `steps`.
+    /// Return the names of the applied migrations.
+    ///
+    /// Each migration runs in its own transaction, a failing migration is rolled back and stops the run.
+    /// The error of the failing statement is returned, even if the rollback fails as well.
+    /// On MySQL, DDL statements commit implicitly and are not rolled back.
+    pub fn up<C>(&self, conn: &mut C, steps: Option<usize>) -> Result<Vec<String>, C::Error>
+        where C: MigrationConnection {
+        let applied = self.applied(conn)?;
+        let pending: Vec<_> = self.migrations.iter()
+            .filter(|migration| !applied.iter().any(|name| name == migration.name()))
+            .take(steps.unwrap_or(usize::MAX))
+            .collect();
+        if !pending.is_empty() {
+            conn.execute(&self.history_table_statement().build_any(&self.builder), Values(Vec::new()))?;
+        }
+        let mut names = Vec::new();
+        for migration in pending {
+            let applied_at = SystemTime::now().duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default();
+            let record = Query::insert()
+                .into_table(self.table.clone())
+                .columns(vec![MigrationHistory::Version, MigrationHistory::AppliedAt])
+                .values_panic(vec![migration.name().into(), applied_at.into()])
+                .to_owned();
+            self.run(conn, migration.as_ref(), migration.up(), record.into())?;
+            names.push(migration.name().to_owned());
+        }
+        Ok(names)
+    }
+
+    /// Revert applied migrations in reverse order, all of them or at most `steps`.
+    /// Return the names of the reverted migrations.
+    pub fn down<C>(&self, conn: &mut C, steps: Option<usize>) -> Result<Vec<String>, C::Error>
+        where C: MigrationConnection {
+        let applied = self.applied(conn)?;
+        let reverting = self.migrations.iter().rev()
+            .filter(|migration| applied.iter().any(|name| name == migration.name()))
+            .take(steps.unwrap_or(usize::MAX));
+        let mut names = Vec::new();
+        for migration in reverting {
+            let record = Query::delete()
+                .from_table(self.table.clone())
+                .and_where(Expr::col(MigrationHistory::Version).eq(migration.name()))
+                .to_owned();
+            self.run(conn, migration.as_ref(), migration.down(), record.into())?;
+            names.push(migration.name().to_owned());
+        }
+        Ok(names)
+    }
+
+    /// Read the names of the applied migrations, none if the history table does not exist yet
+    fn applied<C>(&self, conn: &mut C) -> Result<Vec<String>, C::Error>
+        where C: MigrationConnection {
+        let (sql, values) = self.builder.query_table_exists(&self.table.to_string()).build_any(&self.builder);
+        if conn.query(&sql, values)?.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (sql, values) = Query::select()
+            .column(MigrationHistory::Version)
+            .from(self.table.clone())
+            .order_by(MigrationHistory::Version, Order::Asc)
+            .build_any(&self.builder);
+        Ok(conn.query(&sql, values)?.into_iter().filter_map(|row| match row.into_iter().next() {
+            Some(Value::String(name)) => Some(*name),
+            _ => None,
+        }).collect())
+    }
+
+    /// Execute the statements of a migration followed by the history record in one transaction
+    fn run<C>(&self, conn: &mut C, migration: &dyn Migration, statements: Vec<MigrationStatement>, record: MigrationStatement) -> Result<(), C::Error>
+        where C: MigrationConnection {
+        if !migration.foreign_keys_off() {
+            return self.transaction(conn, statements, record);
+        }
+        conn.execute("PRAGMA foreign_keys = OFF", Values(Vec::new()))?;
+        let result = self.transaction(conn, statements, record);
+        conn.execute("PRAGMA foreign_keys = ON", Values(Vec::new()))?;
+        result
+    }
+
+    fn transaction<C>(&self, conn: &mut C, statements: Vec<MigrationStatement>, record: MigrationStatement) -> Result<(), C::Error>
+        where C: MigrationConnection {
+        conn.begin()?;
+        for statement in statements.iter().chain(std::iter::once(&record)) {
+            for (sql, values) in statement.build_statements(&self.builder) {
+                if let Err(err) = conn.execute(&sql, values) {
+                    // The statement error is more telling than a failed rollback
+                    let _ = conn.rollback();
+                    return Err(err);
+                }
+            }
+        }
+        conn.commit()
+    }
+}
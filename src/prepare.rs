@@ -14,21 +14,26 @@ where
     I: IntoIterator<Item = Value>,
 {
     let params: Vec<Value> = params.into_iter().collect();
+    let mut counter = 0;
+    inject_parameters_with_counter(sql, &params, &mut counter, query_builder)
+}
+
+fn inject_parameters_with_counter(sql: &str, params: &[Value], counter: &mut usize, query_builder: &dyn QueryBuilder) -> String {
+    let (placeholder, numbered) = query_builder.placeholder();
     let tokenizer = Tokenizer::new(sql);
     let tokens: Vec<Token> = tokenizer.iter().collect();
-    let mut counter = 0;
     let mut output = Vec::new();
     let mut i = 0;
     while i < tokens.len() {
         let token = &tokens[i];
         match token {
             Token::Punctuation(mark) => {
-                if mark == "?" {
-                    output.push(query_builder.value_to_string(&params[counter]));
-                    counter += 1;
+                if !numbered && mark == placeholder {
+                    output.push(query_builder.value_to_string(&params[*counter]));
+                    *counter += 1;
                     i += 1;
                     continue;
-                } else if mark == "$" && i + 1 < tokens.len() {
+                } else if numbered && mark == placeholder && i + 1 < tokens.len() {
                     if let Token::Unquoted(next) = &tokens[i + 1] {
                         if let Ok(num) = next.parse::<usize>() {
                             output.push(query_builder.value_to_string(&params[num - 1]));
@@ -39,6 +44,11 @@ where
                 }
                 output.push(mark.to_string())
             },
+            // Square brackets are array subscripts and constructors, e.g. `ARRAY[$1, $2]`
+            Token::Quoted(string) if string.starts_with('[') && string.ends_with(']') => {
+                let inner = &string[1..string.len() - 1];
+                output.push(format!("[{}]", inject_parameters_with_counter(inner, params, counter, query_builder)));
+            },
             _ => output.push(token.to_string())
         }
         i += 1;
@@ -121,7 +131,7 @@ mod tests {
     #[test]
     fn inject_parameters_6() {
         assert_eq!(inject_parameters("WHERE A = $1", vec!["B'C".into()], &PostgresQueryBuilder),
-            "WHERE A = E'B\\'C'");
+            "WHERE A = 'B''C'");
     }
 
     #[test]
@@ -130,4 +140,10 @@ mod tests {
             "x'ABCDEF'");
     }
 
+    #[test]
+    fn inject_parameters_8() {
+        assert_eq!(inject_parameters("WHERE A ? $1 AND B ?| ARRAY[$2, 'D']", vec!["B".into(), "C".into()], &PostgresQueryBuilder),
+            "WHERE A ? 'B' AND B ?| ARRAY['C', 'D']");
+    }
+
 }
@@ -0,0 +1,114 @@
+use crate::{expr::*, query::*};
+
+/// `CASE WHEN` expression, evaluating to the result of the first matching condition
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let query = Query::select()
+///     .column(Char::Character)
+///     .expr_as(
+///         Expr::case(Expr::col(Char::SizeW).gt(10), "large")
+///             .case(Expr::col(Char::SizeW).gt(5), "medium")
+///             .finally("small"),
+///         Alias::new("size"),
+///     )
+///     .from(Char::Table)
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"SELECT `character`, CASE WHEN `size_w` > 10 THEN 'large' WHEN `size_w` > 5 THEN 'medium' ELSE 'small' END AS `size` FROM `character`"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"SELECT "character", CASE WHEN "size_w" > 10 THEN 'large' WHEN "size_w" > 5 THEN 'medium' ELSE 'small' END AS "size" FROM "character""#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"SELECT `character`, CASE WHEN `size_w` > 10 THEN 'large' WHEN `size_w` > 5 THEN 'medium' ELSE 'small' END AS `size` FROM `character`"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CaseStatement {
+    pub(crate) when: Vec<CaseStatementCondition>,
+    pub(crate) r#else: Option<SimpleExpr>,
+}
+
+/// A `WHEN ... THEN ...` branch of [`CaseStatement`]
+#[derive(Debug, Clone)]
+pub struct CaseStatementCondition {
+    pub(crate) condition: SimpleExpr,
+    pub(crate) result: SimpleExpr,
+}
+
+impl Default for CaseStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CaseStatement {
+    /// Construct a new [`CaseStatement`]
+    pub fn new() -> Self {
+        Self {
+            when: Vec::new(),
+            r#else: None,
+        }
+    }
+
+    /// Add a `WHEN condition THEN result` branch. Branches are evaluated in order.
+    pub fn case<C, T>(mut self, condition: C, then: T) -> Self
+        where C: Into<SimpleExpr>, T: Into<SimpleExpr> {
+        self.when.push(CaseStatementCondition {
+            condition: condition.into(),
+            result: then.into(),
+        });
+        self
+    }
+
+    /// Set the `ELSE` result, used when no condition matches. Without it, the expression evaluates to `NULL`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value_expr(
+    ///         Glyph::Aspect,
+    ///         Expr::case(Expr::col(Glyph::Image).is_null(), 0)
+    ///             .finally(Expr::col(Glyph::Aspect).mul(2))
+    ///             .into(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"UPDATE "glyph" SET "aspect" = CASE WHEN "image" IS NULL THEN $1 ELSE "aspect" * $2 END"#.to_owned(),
+    ///         Values(vec![0.into(), 2.into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn finally<E>(mut self, otherwise: E) -> Self
+        where E: Into<SimpleExpr> {
+        self.r#else = Some(otherwise.into());
+        self
+    }
+}
+
+impl From<CaseStatement> for SimpleExpr {
+    fn from(case: CaseStatement) -> Self {
+        SimpleExpr::Case(Box::new(case))
+    }
+}
+
+impl From<CaseStatement> for SelectExpr {
+    fn from(case: CaseStatement) -> Self {
+        SimpleExpr::from(case).into()
+    }
+}
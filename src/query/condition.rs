@@ -0,0 +1,346 @@
+use crate::{expr::*, types::*};
+
+/// Junction used to combine the expressions of a [`Condition`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionType {
+    Any,
+    All,
+}
+
+/// Nested tree of conditions, to be used in `WHERE`, `HAVING` and `JOIN ... ON`
+///
+/// [`Condition::all`] joins its expressions with `AND`, [`Condition::any`] joins them with `OR`.
+/// Conditions can be nested arbitrarily, parentheses are only added where precedence requires them.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let query = Query::select()
+///     .column(Glyph::Image)
+///     .from(Glyph::Table)
+///     .cond_where(
+///         Cond::all()
+///             .add(Expr::col(Glyph::Aspect).is_in(vec![3, 4]))
+///             .add(
+///                 Cond::any()
+///                     .add(Expr::col(Glyph::Image).like("A%"))
+///                     .add(Expr::col(Glyph::Image).like("B%"))
+///             )
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"SELECT `image` FROM `glyph` WHERE `aspect` IN (3, 4) AND (`image` LIKE 'A%' OR `image` LIKE 'B%')"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"SELECT "image" FROM "glyph" WHERE "aspect" IN (3, 4) AND ("image" LIKE 'A%' OR "image" LIKE 'B%')"#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"SELECT `image` FROM `glyph` WHERE `aspect` IN (3, 4) AND (`image` LIKE 'A%' OR `image` LIKE 'B%')"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Condition {
+    pub(crate) negate: bool,
+    pub(crate) condition_type: ConditionType,
+    pub(crate) conditions: Vec<ConditionExpression>,
+}
+
+/// Shorthand for [`Condition`]
+pub type Cond = Condition;
+
+/// An expression inside a [`Condition`], either a nested condition or a plain expression
+#[derive(Debug, Clone)]
+pub enum ConditionExpression {
+    Condition(Condition),
+    SimpleExpr(SimpleExpr),
+}
+
+/// Condition of a statement, built by `and_where` / `or_where` and `cond_where`
+#[derive(Debug, Clone)]
+pub struct ConditionHolder {
+    pub(crate) contents: ConditionHolderContents,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum ConditionHolderContents {
+    Empty,
+    Condition(Condition),
+}
+
+/// Conversion into [`Condition`], so that a single [`SimpleExpr`] can be used wherever a condition is expected
+pub trait IntoCondition {
+    fn into_condition(self) -> Condition;
+}
+
+impl Condition {
+    /// Construct a condition whose expressions are joined with `AND`.
+    /// Without any expression, it is always true and renders nothing at the top level.
+    pub fn all() -> Self {
+        Self {
+            negate: false,
+            condition_type: ConditionType::All,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Construct a condition whose expressions are joined with `OR`.
+    /// Without any expression, it is always false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Image)
+    ///     .from(Glyph::Table)
+    ///     .cond_where(Cond::any())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE 1 = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "image" FROM "glyph" WHERE 1 = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE 1 = 2"#
+    /// );
+    /// ```
+    pub fn any() -> Self {
+        Self {
+            negate: false,
+            condition_type: ConditionType::Any,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Add an expression or a nested condition.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<C>(mut self, condition: C) -> Self
+        where C: Into<ConditionExpression> {
+        let mut expr: ConditionExpression = condition.into();
+        if let ConditionExpression::Condition(c) = &mut expr {
+            // A junction of a single expression is the expression itself
+            if c.conditions.len() == 1 && !c.negate {
+                expr = c.conditions.pop().unwrap();
+            }
+        }
+        self.conditions.push(expr);
+        self
+    }
+
+    /// Add an expression or a nested condition if it is `Some`, handy for building conditions conditionally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let image: Option<&str> = None;
+    /// let query = Query::select()
+    ///     .column(Glyph::Image)
+    ///     .from(Glyph::Table)
+    ///     .cond_where(
+    ///         Cond::all()
+    ///             .add_option(Some(Expr::col(Glyph::Aspect).gt(1)))
+    ///             .add_option(image.map(|image| Expr::col(Glyph::Image).like(image)))
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE `aspect` > 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "image" FROM "glyph" WHERE "aspect" > 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE `aspect` > 1"#
+    /// );
+    /// ```
+    pub fn add_option<C>(self, condition: Option<C>) -> Self
+        where C: Into<ConditionExpression> {
+        match condition {
+            Some(condition) => self.add(condition),
+            None => self,
+        }
+    }
+
+    /// Negate the condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Image)
+    ///     .from(Glyph::Table)
+    ///     .cond_where(
+    ///         Cond::any()
+    ///             .add(Expr::col(Glyph::Aspect).lt(1))
+    ///             .add(Expr::col(Glyph::Aspect).gt(10))
+    ///             .not()
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE NOT (`aspect` < 1 OR `aspect` > 10)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "image" FROM "glyph" WHERE NOT ("aspect" < 1 OR "aspect" > 10)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE NOT (`aspect` < 1 OR `aspect` > 10)"#
+    /// );
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(mut self) -> Self {
+        self.negate = !self.negate;
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.conditions.len()
+    }
+
+    /// An empty, non-negated `all` condition does not filter anything
+    pub(crate) fn is_always_true(&self) -> bool {
+        self.is_empty() && !self.negate && self.condition_type == ConditionType::All
+    }
+}
+
+impl ConditionExpression {
+    /// Whether the expression has to be parenthesized when joined by `parent` with other expressions.
+    /// As `AND` binds tighter than `OR`, only an `OR` junction inside an `AND` junction needs them.
+    pub(crate) fn need_parentheses(&self, parent: ConditionType) -> bool {
+        if parent != ConditionType::All {
+            return false;
+        }
+        match self {
+            Self::Condition(c) => !c.negate && c.len() > 1 && c.condition_type == ConditionType::Any,
+            Self::SimpleExpr(e) => e.get_bin_oper() == Some(BinOper::Or),
+        }
+    }
+}
+
+impl From<Condition> for ConditionExpression {
+    fn from(condition: Condition) -> Self {
+        ConditionExpression::Condition(condition)
+    }
+}
+
+impl From<SimpleExpr> for ConditionExpression {
+    fn from(expr: SimpleExpr) -> Self {
+        ConditionExpression::SimpleExpr(expr)
+    }
+}
+
+impl IntoCondition for Condition {
+    fn into_condition(self) -> Condition {
+        self
+    }
+}
+
+impl IntoCondition for SimpleExpr {
+    fn into_condition(self) -> Condition {
+        Condition::all().add(self)
+    }
+}
+
+impl Default for ConditionHolder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConditionHolder {
+    pub(crate) fn new() -> Self {
+        Self {
+            contents: ConditionHolderContents::Empty,
+        }
+    }
+
+    pub(crate) fn new_with_condition(condition: Condition) -> Self {
+        Self {
+            contents: ConditionHolderContents::Condition(condition),
+        }
+    }
+
+    /// Combine with the current condition as a left operand, so that the conditions are evaluated in the order they are added.
+    pub(crate) fn fold_and_or(&mut self, condition: LogicalChainOper) {
+        let (condition_type, expr) = match condition {
+            LogicalChainOper::And(expr) => (ConditionType::All, expr),
+            LogicalChainOper::Or(expr) => (ConditionType::Any, expr),
+        };
+        let junction = match condition_type {
+            ConditionType::All => Condition::all(),
+            ConditionType::Any => Condition::any(),
+        };
+        self.contents = ConditionHolderContents::Condition(
+            match std::mem::replace(&mut self.contents, ConditionHolderContents::Empty) {
+                ConditionHolderContents::Empty => junction.add(expr),
+                ConditionHolderContents::Condition(current) => {
+                    if current.condition_type == condition_type && !current.negate {
+                        current.add(expr)
+                    } else {
+                        junction.add(current).add(expr)
+                    }
+                },
+            }
+        );
+    }
+
+    /// Combine with the current condition by `AND`, a condition that is always true is dropped.
+    pub(crate) fn add_condition(&mut self, condition: Condition) {
+        if condition.is_always_true() {
+            return;
+        }
+        self.contents = ConditionHolderContents::Condition(
+            match std::mem::replace(&mut self.contents, ConditionHolderContents::Empty) {
+                ConditionHolderContents::Empty => condition,
+                ConditionHolderContents::Condition(current) => {
+                    if current.condition_type == ConditionType::All && !current.negate {
+                        current.add(condition)
+                    } else {
+                        Condition::all().add(current).add(condition)
+                    }
+                },
+            }
+        );
+    }
+
+    /// Convert into a [`Condition`], always true if empty.
+    pub(crate) fn to_condition(&self) -> Condition {
+        match &self.contents {
+            ConditionHolderContents::Empty => Condition::all(),
+            ConditionHolderContents::Condition(condition) => condition.clone(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        match &self.contents {
+            ConditionHolderContents::Empty => true,
+            ConditionHolderContents::Condition(condition) => condition.is_always_true(),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{backend::QueryBuilder, types::*, expr::*, value::*, prepare::*};
+use crate::{backend::QueryBuilder, types::*, expr::*, query::*, value::*, prepare::*};
 
 /// Delete existing rows from the table
 /// 
@@ -15,23 +15,25 @@ use crate::{backend::QueryBuilder, types::*, expr::*, value::*, prepare::*};
 /// 
 /// assert_eq!(
 ///     query.to_string(MysqlQueryBuilder),
-///     r#"DELETE FROM `glyph` WHERE (`id` < 1) OR (`id` > 10)"#
+///     r#"DELETE FROM `glyph` WHERE `id` < 1 OR `id` > 10"#
 /// );
 /// assert_eq!(
 ///     query.to_string(PostgresQueryBuilder),
-///     r#"DELETE FROM "glyph" WHERE ("id" < 1) OR ("id" > 10)"#
+///     r#"DELETE FROM "glyph" WHERE "id" < 1 OR "id" > 10"#
 /// );
 /// assert_eq!(
 ///     query.to_string(SqliteQueryBuilder),
-///     r#"DELETE FROM `glyph` WHERE (`id` < 1) OR (`id` > 10)"#
+///     r#"DELETE FROM `glyph` WHERE `id` < 1 OR `id` > 10"#
 /// );
 /// ```
 #[derive(Debug, Clone)]
 pub struct DeleteStatement {
     pub(crate) table: Option<Box<TableRef>>,
-    pub(crate) wherei: Option<Box<SimpleExpr>>,
+    pub(crate) join: Vec<JoinExpr>,
+    pub(crate) wherei: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
+    pub(crate) returning: Vec<SelectExpr>,
 }
 
 impl Default for DeleteStatement {
@@ -45,9 +47,11 @@ impl DeleteStatement {
     pub fn new() -> Self {
         Self {
             table: None,
-            wherei: None,
+            join: Vec::new(),
+            wherei: ConditionHolder::new(),
             orders: Vec::new(),
             limit: None,
+            returning: Vec::new(),
         }
     }
 
@@ -83,6 +87,59 @@ impl DeleteStatement {
         self
     }
 
+    /// Inner join with other table to delete rows matching it.
+    /// Rendered as `DELETE ... FROM ... JOIN` on MySQL and as `DELETE FROM ... USING` on Postgres,
+    /// where the join condition goes into the `WHERE` clause. Not supported by Sqlite.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::delete()
+    ///     .from_table(Char::Table)
+    ///     .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .and_where(Expr::tbl(Font::Table, Font::Name).eq("Arial"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE `character` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id` WHERE `font`.`name` = 'Arial'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "character" USING "font" WHERE "character"."font_id" = "font"."id" AND "font"."name" = 'Arial'"#
+    /// );
+    /// ```
+    pub fn inner_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+        where R: IntoTableRef, C: IntoCondition {
+        self.join(JoinType::InnerJoin, tbl_ref, condition)
+    }
+
+    /// Left join with other table. Only supported by MySQL.
+    pub fn left_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+        where R: IntoTableRef, C: IntoCondition {
+        self.join(JoinType::LeftJoin, tbl_ref, condition)
+    }
+
+    /// Join with other table by [`JoinType`].
+    /// Postgres only supports [`JoinType::Join`] and [`JoinType::InnerJoin`] here.
+    ///
+    /// # Examples
+    ///
+    /// See [`DeleteStatement::inner_join`]
+    pub fn join<R, C>(&mut self, join: JoinType, tbl_ref: R, condition: C) -> &mut Self
+        where R: IntoTableRef, C: IntoCondition {
+        self.join.push(JoinExpr {
+            join,
+            table: Box::new(tbl_ref.into_table_ref()),
+            on: Some(JoinOn::Condition(Box::new(ConditionHolder::new_with_condition(condition.into_condition())))),
+            natural: false,
+            lateral: false,
+        });
+        self
+    }
+
     /// And where condition.
     /// 
     /// # Examples
@@ -98,19 +155,20 @@ impl DeleteStatement {
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"DELETE FROM `glyph` WHERE (`id` > 1) AND (`id` < 10)"#
+    ///     r#"DELETE FROM `glyph` WHERE `id` > 1 AND `id` < 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"DELETE FROM "glyph" WHERE ("id" > 1) AND ("id" < 10)"#
+    ///     r#"DELETE FROM "glyph" WHERE "id" > 1 AND "id" < 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"DELETE FROM `glyph` WHERE (`id` > 1) AND (`id` < 10)"#
+    ///     r#"DELETE FROM `glyph` WHERE `id` > 1 AND `id` < 10"#
     /// );
     /// ```
     pub fn and_where(&mut self, other: SimpleExpr) -> &mut Self {
-        self.and_or_where(BinOper::And, other)
+        self.wherei.fold_and_or(LogicalChainOper::And(other));
+        self
     }
 
     /// And where condition.
@@ -128,52 +186,65 @@ impl DeleteStatement {
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"DELETE FROM `glyph` WHERE (`id` < 1) OR (`id` > 10)"#
+    ///     r#"DELETE FROM `glyph` WHERE `id` < 1 OR `id` > 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"DELETE FROM "glyph" WHERE ("id" < 1) OR ("id" > 10)"#
+    ///     r#"DELETE FROM "glyph" WHERE "id" < 1 OR "id" > 10"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"DELETE FROM `glyph` WHERE (`id` < 1) OR (`id` > 10)"#
+    ///     r#"DELETE FROM `glyph` WHERE `id` < 1 OR `id` > 10"#
     /// );
     /// ```
     pub fn or_where(&mut self, other: SimpleExpr) -> &mut Self {
-        self.and_or_where(BinOper::Or, other)
-    }
-
-    fn and_or_where(&mut self, bopr: BinOper, right: SimpleExpr) -> &mut Self {
-        self.wherei = Self::merge_expr(
-            self.wherei.take(), 
-            match bopr {
-                BinOper::And => BinOper::And,
-                BinOper::Or => BinOper::Or,
-                _ => panic!("not allow"),
-            },
-            right
-        );
+        self.wherei.fold_and_or(LogicalChainOper::Or(other));
         self
     }
 
-    fn merge_expr(left: Option<Box<SimpleExpr>>, bopr: BinOper, right: SimpleExpr) -> Option<Box<SimpleExpr>> {
-        Some(Box::new(match left {
-            Some(left) => SimpleExpr::Binary(
-                left,
-                bopr,
-                Box::new(right)
-            ),
-            None => right,
-        }))
+    /// Where condition, expressed as a [`Condition`] tree. Calling it repeatedly combines the conditions with `AND`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .cond_where(
+    ///         Cond::all()
+    ///             .add(Expr::col(Glyph::Image).is_null())
+    ///             .add(
+    ///                 Cond::any()
+    ///                     .add(Expr::col(Glyph::Id).lt(1))
+    ///                     .add(Expr::col(Glyph::Id).gt(10))
+    ///             )
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `image` IS NULL AND (`id` < 1 OR `id` > 10)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "image" IS NULL AND ("id" < 1 OR "id" > 10)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `image` IS NULL AND (`id` < 1 OR `id` > 10)"#
+    /// );
+    /// ```
+    pub fn cond_where<C>(&mut self, condition: C) -> &mut Self
+        where C: IntoCondition {
+        self.wherei.add_condition(condition.into_condition());
+        self
     }
 
     /// Order by column.
     pub fn order_by<T>(&mut self, col: T, order: Order) -> &mut Self 
         where T: IntoColumnRef {
-        self.orders.push(OrderExpr {
-            expr: SimpleExpr::Column(col.into_column_ref()),
-            order,
-        });
+        self.orders.push(OrderExpr::col(col, order));
         self
     }
 
@@ -190,10 +261,13 @@ impl DeleteStatement {
 
     /// Order by [`SimpleExpr`].
     pub fn order_by_expr(&mut self, expr: SimpleExpr, order: Order) -> &mut Self {
-        self.orders.push(OrderExpr {
-            expr,
-            order,
-        });
+        self.orders.push(OrderExpr::expr(expr, order));
+        self
+    }
+
+    /// Order by [`OrderExpr`], which can specify the position of nulls and the collation.
+    pub fn add_order_by(&mut self, order_expr: OrderExpr) -> &mut Self {
+        self.orders.push(order_expr);
         self
     }
 
@@ -205,10 +279,7 @@ impl DeleteStatement {
     {
         let mut orders = cols
             .into_iter()
-            .map(|(c, order)| OrderExpr {
-                expr: SimpleExpr::Custom(c.to_string()),
-                order,
-            })
+            .map(|(c, order)| OrderExpr::expr(SimpleExpr::Custom(c.to_string()), order))
             .collect();
         self.orders.append(&mut orders);
         self
@@ -222,10 +293,7 @@ impl DeleteStatement {
     {
         let mut orders = cols
             .into_iter()
-            .map(|(c, order)| OrderExpr {
-                expr: SimpleExpr::Column(c.into_column_ref()),
-                order,
-            })
+            .map(|(c, order)| OrderExpr::col(c, order))
             .collect();
         self.orders.append(&mut orders);
         self
@@ -253,6 +321,57 @@ impl DeleteStatement {
         self
     }
 
+    /// RETURNING expressions of the deleted rows. Postgres and Sqlite only, MySQL panics.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .returning(Query::select().columns(vec![Glyph::Id, Glyph::Image]).take())
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "image" LIKE 'A%' RETURNING "id", "image""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `image` LIKE 'A%' RETURNING `id`, `image`"#
+    /// );
+    /// ```
+    pub fn returning(&mut self, select: SelectStatement) -> &mut Self {
+        self.returning = select.selects;
+        self
+    }
+
+    /// RETURNING a column of the deleted rows. Postgres and Sqlite only, MySQL panics.
+    /// Wrapper over [`DeleteStatement::returning()`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .returning_col(Glyph::Id)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "image" LIKE 'A%' RETURNING "id""#
+    /// );
+    /// ```
+    pub fn returning_col<C>(&mut self, col: C) -> &mut Self
+        where C: IntoIden {
+        self.returning(Query::select().column(col.into_iden()).take())
+    }
+
     /// Build corresponding SQL statement for certain database backend and collect query parameters
     /// 
     /// # Examples
@@ -139,7 +139,7 @@ impl InsertStatement {
         self.values(values).unwrap()
     }
 
-    /// RETURNING expressions. Postgres only. 
+    /// RETURNING expressions. Postgres and Sqlite only. 
     /// 
     /// ```
     /// use sea_query::{*, tests_cfg::*};
@@ -165,7 +165,7 @@ impl InsertStatement {
         self
     }
 
-    /// RETURNING a column after insertion. Postgres only. This is equivalent to MySQL's LAST_INSERT_ID.
+    /// RETURNING a column after insertion. Postgres and Sqlite only. This is equivalent to MySQL's LAST_INSERT_ID.
     /// Wrapper over [`InsertStatement::returning()`].
     /// 
     /// ```
@@ -6,16 +6,22 @@
 //! - Query Insert, see [`InsertStatement`]
 //! - Query Update, see [`UpdateStatement`]
 //! - Query Delete, see [`DeleteStatement`]
+//! - Case expression, see [`CaseStatement`]
+//! - Nested conditions, see [`Condition`]
 
 mod select;
 mod insert;
 mod update;
 mod delete;
+mod case;
+mod condition;
 
 pub use select::*;
 pub use insert::*;
 pub use update::*;
 pub use delete::*;
+pub use case::*;
+pub use condition::*;
 
 /// Shorthand for constructing any table query
 #[derive(Debug, Clone)]
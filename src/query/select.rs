@@ -1,5 +1,5 @@
 use std::rc::Rc;
-use crate::{backend::QueryBuilder, types::*, expr::*, value::*, prepare::*};
+use crate::{backend::QueryBuilder, types::*, expr::*, query::*, value::*, prepare::*};
 use std::iter::FromIterator;
 
 /// Select rows from an existing table
@@ -34,15 +34,18 @@ use std::iter::FromIterator;
 #[derive(Debug, Clone)]
 pub struct SelectStatement {
     pub(crate) distinct: Option<SelectDistinct>,
+    pub(crate) distinct_on: Vec<SimpleExpr>,
     pub(crate) selects: Vec<SelectExpr>,
     pub(crate) from: Option<Box<TableRef>>,
     pub(crate) join: Vec<JoinExpr>,
-    pub(crate) wherei: Vec<LogicalChainOper>,
+    pub(crate) wherei: ConditionHolder,
     pub(crate) groups: Vec<SimpleExpr>,
-    pub(crate) having: Vec<LogicalChainOper>,
+    pub(crate) grouping_sets: Vec<GroupingSet>,
+    pub(crate) having: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
     pub(crate) offset: Option<Value>,
+    pub(crate) lock: Option<LockClause>,
 }
 
 /// List of distinct keywords that can be used in select statement
@@ -53,6 +56,39 @@ pub enum SelectDistinct {
     DistinctRow,
 }
 
+/// Grouping set construct used in `GROUP BY`, producing subtotal rows
+#[derive(Debug, Clone)]
+pub enum GroupingSet {
+    Rollup(Vec<SimpleExpr>),
+    Cube(Vec<SimpleExpr>),
+    Sets(Vec<Vec<SimpleExpr>>),
+}
+
+/// Strength of the row lock taken by a select statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockType {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+/// What a select statement does when the rows to lock are already locked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockBehavior {
+    Nowait,
+    SkipLocked,
+}
+
+/// Row locking clause (`FOR UPDATE`, `FOR SHARE` ...) of a select statement
+#[derive(Debug, Clone)]
+pub struct LockClause {
+    pub(crate) r#type: LockType,
+    pub(crate) tables: Vec<Rc<dyn Iden>>,
+    pub(crate) behavior: Option<LockBehavior>,
+    pub(crate) optional: bool,
+}
+
 /// Select expression used in select statement
 #[derive(Debug, Clone)]
 pub struct SelectExpr {
@@ -66,6 +102,8 @@ pub struct JoinExpr {
     pub join: JoinType,
     pub table: Box<TableRef>,
     pub on: Option<JoinOn>,
+    pub natural: bool,
+    pub lateral: bool,
 }
 
 impl Into<SelectExpr> for SimpleExpr {
@@ -77,6 +115,12 @@ impl Into<SelectExpr> for SimpleExpr {
     }
 }
 
+impl From<SelectStatement> for SimpleExpr {
+    fn from(select: SelectStatement) -> Self {
+        SimpleExpr::SubQuery(None, Box::new(select))
+    }
+}
+
 impl Default for SelectStatement {
     fn default() -> Self {
         Self::new()
@@ -88,15 +132,18 @@ impl SelectStatement {
     pub fn new() -> Self {
         Self {
             distinct: None,
+            distinct_on: Vec::new(),
             selects: Vec::new(),
             from: None,
             join: Vec::new(),
-            wherei: Vec::new(),
+            wherei: ConditionHolder::new(),
             groups: Vec::new(),
-            having: Vec::new(),
+            grouping_sets: Vec::new(),
+            having: ConditionHolder::new(),
             orders: Vec::new(),
             limit: None,
             offset: None,
+            lock: None,
         }
     }
 
@@ -104,15 +151,18 @@ impl SelectStatement {
     pub fn take(&mut self) -> Self {
         Self {
             distinct: self.distinct.take(),
+            distinct_on: std::mem::take(&mut self.distinct_on),
             selects: std::mem::replace(&mut self.selects, Vec::new()),
             from: self.from.take(),
             join: std::mem::replace(&mut self.join, Vec::new()),
-            wherei: std::mem::replace(&mut self.wherei, Vec::new()),
+            wherei: std::mem::take(&mut self.wherei),
             groups: std::mem::replace(&mut self.groups, Vec::new()),
-            having: std::mem::replace(&mut self.having, Vec::new()),
+            grouping_sets: std::mem::take(&mut self.grouping_sets),
+            having: std::mem::take(&mut self.having),
             orders: std::mem::replace(&mut self.orders, Vec::new()),
             limit: self.limit.take(),
             offset: self.offset.take(),
+            lock: self.lock.take(),
         }
     }
 
@@ -232,8 +282,64 @@ impl SelectStatement {
     }
 
     /// Select distinct
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .distinct()
+    ///     .column(Char::FontId)
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT DISTINCT `font_id` FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT DISTINCT "font_id" FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT DISTINCT `font_id` FROM `character`"#
+    /// );
+    /// ```
     pub fn distinct(&mut self) -> &mut Self {
         self.distinct = Some(SelectDistinct::Distinct);
+        self.distinct_on.clear();
+        self
+    }
+
+    /// Select distinct on columns, keeping the first row of each group in `ORDER BY` order. Postgres only.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .distinct_on(vec![Char::FontId])
+    ///     .columns(vec![Char::FontId, Char::Character])
+    ///     .from(Char::Table)
+    ///     .order_by(Char::FontId, Order::Asc)
+    ///     .order_by(Char::Id, Order::Desc)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT DISTINCT ON ("font_id") "font_id", "character" FROM "character" ORDER BY "font_id" ASC, "id" DESC"#
+    /// );
+    /// ```
+    pub fn distinct_on<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.distinct = None;
+        self.distinct_on = columns_to_exprs(cols);
         self
     }
 
@@ -659,8 +765,8 @@ impl SelectStatement {
     ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
     /// );
     /// ```
-    pub fn left_join<R>(&mut self, tbl_ref: R, condition: SimpleExpr) -> &mut Self 
-        where R: IntoTableRef {
+    pub fn left_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self 
+        where R: IntoTableRef, C: IntoCondition {
         self.join(JoinType::LeftJoin, tbl_ref, condition)
     }
 
@@ -691,12 +797,41 @@ impl SelectStatement {
     ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id`"#
     /// );
     /// ```
-    pub fn inner_join<R>(&mut self, tbl_ref: R, condition: SimpleExpr) -> &mut Self 
-        where R: IntoTableRef {
+    pub fn inner_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self 
+        where R: IntoTableRef, C: IntoCondition {
         self.join(JoinType::InnerJoin, tbl_ref, condition)
     }
 
+    /// Full outer join. Postgres only.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .column((Font::Table, Font::Name))
+    ///     .from(Char::Table)
+    ///     .full_outer_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "font"."name" FROM "character" FULL OUTER JOIN "font" ON "character"."font_id" = "font"."id""#
+    /// );
+    /// ```
+    pub fn full_outer_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self 
+        where R: IntoTableRef, C: IntoCondition {
+        self.join(JoinType::FullOuterJoin, tbl_ref, condition)
+    }
+
     /// Join with other table by [`JoinType`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on [`JoinType::CrossJoin`], which takes no join condition, use [`SelectStatement::cross_join`] instead.
+    /// The same applies to the other join methods taking a condition or a column list.
     /// 
     /// # Examples
     /// 
@@ -718,14 +853,46 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character", "font"."name" FROM "character" RIGHT JOIN "font" ON "character"."font_id" = "font"."id""#
     /// );
+    /// ```
+    ///
+    /// The join condition can also be a [`Condition`] tree.
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .table_column(Font::Table, Font::Name)
+    ///     .from(Char::Table)
+    ///     .join(
+    ///         JoinType::LeftJoin,
+    ///         Font::Table,
+    ///         Cond::all()
+    ///             .add(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///             .add(
+    ///                 Cond::any()
+    ///                     .add(Expr::tbl(Font::Table, Font::Name).like("A%"))
+    ///                     .add(Expr::tbl(Font::Table, Font::Name).is_null())
+    ///             )
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id` AND (`font`.`name` LIKE 'A%' OR `font`.`name` IS NULL)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "font"."name" FROM "character" LEFT JOIN "font" ON "character"."font_id" = "font"."id" AND ("font"."name" LIKE 'A%' OR "font"."name" IS NULL)"#
+    /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id` AND (`font`.`name` LIKE 'A%' OR `font`.`name` IS NULL)"#
     /// );
     /// ```
-    pub fn join<R>(&mut self, join: JoinType, tbl_ref: R, condition: SimpleExpr) -> &mut Self 
-        where R: IntoTableRef {
-        self.join_join(join, tbl_ref.into_table_ref(), JoinOn::Condition(Box::new(condition)))
+    pub fn join<R, C>(&mut self, join: JoinType, tbl_ref: R, condition: C) -> &mut Self 
+        where R: IntoTableRef, C: IntoCondition {
+        self.join_join(join, tbl_ref.into_table_ref(), Some(JoinOn::Condition(Box::new(ConditionHolder::new_with_condition(condition.into_condition())))), false)
     }
 
     /// Join with other table by [`JoinType`], assigning an alias to the joined table.
@@ -755,22 +922,18 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character", "font"."name" FROM "character" RIGHT JOIN "font" AS "f" ON "character"."font_id" = "font"."id""#
     /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` AS `f` ON `character`.`font_id` = `font`.`id`"#
-    /// );
     /// ```
-    pub fn join_as<R, A>(&mut self, join: JoinType, tbl_ref: R, alias: A, condition: SimpleExpr) -> &mut Self 
-        where R: IntoTableRef, A: IntoIden {
-        self.join_join(join, tbl_ref.into_table_ref().alias(alias.into_iden()), JoinOn::Condition(Box::new(condition)))
+    pub fn join_as<R, A, C>(&mut self, join: JoinType, tbl_ref: R, alias: A, condition: C) -> &mut Self 
+        where R: IntoTableRef, A: IntoIden, C: IntoCondition {
+        self.join_join(join, tbl_ref.into_table_ref().alias(alias.into_iden()), Some(JoinOn::Condition(Box::new(ConditionHolder::new_with_condition(condition.into_condition())))), false)
     }
 
     #[deprecated(
         since = "0.6.1",
         note = "Please use the [`SelectStatement::join_as`] instead"
     )]
-    pub fn join_alias<R, A>(&mut self, join: JoinType, tbl_ref: R, alias: A, condition: SimpleExpr) -> &mut Self 
-        where R: IntoTableRef, A: IntoIden {
+    pub fn join_alias<R, A, C>(&mut self, join: JoinType, tbl_ref: R, alias: A, condition: C) -> &mut Self 
+        where R: IntoTableRef, A: IntoIden, C: IntoCondition {
         self.join_as(join, tbl_ref, alias, condition)
     }
 
@@ -811,16 +974,174 @@ impl SelectStatement {
     /// );
     /// ```
     /// 
-    pub fn join_subquery<T>(&mut self, join: JoinType, query: SelectStatement, alias: T, condition: SimpleExpr) -> &mut Self
-        where T: IntoIden {
-        self.join_join(join, TableRef::SubQuery(query, alias.into_iden()), JoinOn::Condition(Box::new(condition)))
+    pub fn join_subquery<T, C>(&mut self, join: JoinType, query: SelectStatement, alias: T, condition: C) -> &mut Self
+        where T: IntoIden, C: IntoCondition {
+        self.join_join(join, TableRef::SubQuery(query, alias.into_iden()), Some(JoinOn::Condition(Box::new(ConditionHolder::new_with_condition(condition.into_condition())))), false)
+    }
+
+    /// Join with sub-query which can refer to the columns of preceding tables. Postgres and MySQL only.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Font::Name)
+    ///     .column((Alias::new("c"), Char::Character))
+    ///     .from(Font::Table)
+    ///     .join_lateral(
+    ///         JoinType::LeftJoin,
+    ///         Query::select()
+    ///             .column(Char::Character)
+    ///             .from(Char::Table)
+    ///             .and_where(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///             .order_by(Char::Id, Order::Asc)
+    ///             .limit(1)
+    ///             .take(),
+    ///         Alias::new("c"),
+    ///         Cond::all(),
+    ///     )
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `name`, `c`.`character` FROM `font` LEFT JOIN LATERAL (SELECT `character` FROM `character` WHERE `character`.`font_id` = `font`.`id` ORDER BY `id` ASC LIMIT 1) AS `c` ON 1 = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name", "c"."character" FROM "font" LEFT JOIN LATERAL (SELECT "character" FROM "character" WHERE "character"."font_id" = "font"."id" ORDER BY "id" ASC LIMIT 1) AS "c" ON 1 = 1"#
+    /// );
+    /// ```
+    pub fn join_lateral<T, C>(&mut self, join: JoinType, query: SelectStatement, alias: T, condition: C) -> &mut Self
+        where T: IntoIden, C: IntoCondition {
+        self.join_join(join, TableRef::SubQuery(query, alias.into_iden()), Some(JoinOn::Condition(Box::new(ConditionHolder::new_with_condition(condition.into_condition())))), true)
+    }
+
+    /// Join with other table on the columns of the same names, listed in `USING`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .column(Glyph::Image)
+    ///     .from(Char::Table)
+    ///     .join_using(JoinType::InnerJoin, Glyph::Table, vec![Char::Id])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `image` FROM `character` INNER JOIN `glyph` USING (`id`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "image" FROM "character" INNER JOIN "glyph" USING ("id")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `image` FROM `character` INNER JOIN `glyph` USING (`id`)"#
+    /// );
+    /// ```
+    pub fn join_using<R, T, I>(&mut self, join: JoinType, tbl_ref: R, columns: I) -> &mut Self
+        where R: IntoTableRef, T: IntoIden, I: IntoIterator<Item = T> {
+        let columns = columns
+            .into_iter()
+            .map(|col| SimpleExpr::Column(ColumnRef::Column(col.into_iden())))
+            .collect();
+        self.join_join(join, tbl_ref.into_table_ref(), Some(JoinOn::Columns(columns)), false)
+    }
+
+    /// Natural join, on all the columns of the same names.
+    ///
+    /// # Panics
+    ///
+    /// Panics on [`JoinType::CrossJoin`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .column(Glyph::Image)
+    ///     .from(Char::Table)
+    ///     .natural_join(JoinType::LeftJoin, Glyph::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `image` FROM `character` NATURAL LEFT JOIN `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "image" FROM "character" NATURAL LEFT JOIN "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `image` FROM `character` NATURAL LEFT JOIN `glyph`"#
+    /// );
+    /// ```
+    pub fn natural_join<R>(&mut self, join: JoinType, tbl_ref: R) -> &mut Self
+        where R: IntoTableRef {
+        if join == JoinType::CrossJoin {
+            panic!("CROSS JOIN can not be natural");
+        }
+        self.join.push(JoinExpr {
+            join,
+            table: Box::new(tbl_ref.into_table_ref()),
+            on: None,
+            natural: true,
+            lateral: false,
+        });
+        self
+    }
+
+    /// Cross join, without any join condition.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .column(Font::Name)
+    ///     .from(Char::Table)
+    ///     .cross_join(Font::Table)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `name` FROM `character` CROSS JOIN `font`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "name" FROM "character" CROSS JOIN "font""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `name` FROM `character` CROSS JOIN `font`"#
+    /// );
+    /// ```
+    pub fn cross_join<R>(&mut self, tbl_ref: R) -> &mut Self
+        where R: IntoTableRef {
+        self.join_join(JoinType::CrossJoin, tbl_ref.into_table_ref(), None, false)
     }
 
-    fn join_join(&mut self, join: JoinType, table: TableRef, on: JoinOn) -> &mut Self {
+    fn join_join(&mut self, join: JoinType, table: TableRef, on: Option<JoinOn>, lateral: bool) -> &mut Self {
+        if join == JoinType::CrossJoin && on.is_some() {
+            panic!("CROSS JOIN does not take a join condition, use `cross_join` instead");
+        }
         self.join.push(JoinExpr {
             join,
             table: Box::new(table),
-            on: Some(on),
+            on,
+            natural: false,
+            lateral,
         });
         self
     }
@@ -850,10 +1171,6 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character", "font"."name" FROM "character" RIGHT JOIN "font" ON "character"."font_id" = "font"."id" GROUP BY "character""#
     /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id` GROUP BY `character`"#
-    /// );
     /// ```
     /// 
     /// ```
@@ -877,10 +1194,6 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character", "font"."name" FROM "character" RIGHT JOIN "font" ON "character"."font_id" = "font"."id" GROUP BY "character"."character""#
     /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id` GROUP BY `character`.`character`"#
-    /// );
     /// ```
     pub fn group_by_columns<T, I>(&mut self, cols: I) -> &mut Self
     where
@@ -910,7 +1223,12 @@ impl SelectStatement {
         )
     }
 
-    /// And where condition.
+    /// Group by `ROLLUP` of columns, adding subtotal rows for each prefix of the columns and a grand total row.
+    /// Rendered as `WITH ROLLUP` on MySQL, where it has to be the only grouping. Not supported by Sqlite.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics without any column.
     /// 
     /// # Examples
     /// 
@@ -918,39 +1236,34 @@ impl SelectStatement {
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .table_column(Glyph::Table, Glyph::Image)
-    ///     .from(Glyph::Table)
-    ///     .and_where(Expr::tbl(Glyph::Table, Glyph::Aspect).is_in(vec![3, 4]))
-    ///     .and_where(Expr::tbl(Glyph::Table, Glyph::Image).like("A%"))
+    ///     .columns(vec![Char::FontId, Char::FontSize])
+    ///     .expr(Func::sum(Expr::col(Char::SizeW)))
+    ///     .from(Char::Table)
+    ///     .group_by_rollup(vec![Char::FontId, Char::FontSize])
     ///     .to_owned();
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `glyph`.`image` FROM `glyph` WHERE `glyph`.`aspect` IN (3, 4) AND `glyph`.`image` LIKE 'A%'"#
+    ///     r#"SELECT `font_id`, `font_size`, SUM(`size_w`) FROM `character` GROUP BY `font_id`, `font_size` WITH ROLLUP"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "glyph"."image" FROM "glyph" WHERE "glyph"."aspect" IN (3, 4) AND "glyph"."image" LIKE 'A%'"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `glyph`.`image` FROM `glyph` WHERE `glyph`.`aspect` IN (3, 4) AND `glyph`.`image` LIKE 'A%'"#
+    ///     r#"SELECT "font_id", "font_size", SUM("size_w") FROM "character" GROUP BY ROLLUP ("font_id", "font_size")"#
     /// );
     /// ```
-    pub fn and_where(&mut self, other: SimpleExpr) -> &mut Self {
-        self.wherei.push(LogicalChainOper::And(other));
-        self
-    }
-
-    /// And where condition, variation of [`SelectStatement::and_where`].
-    pub fn and_where_option(&mut self, other: Option<SimpleExpr>) -> &mut Self {
-        if let Some(other) = other {
-            self.wherei.push(LogicalChainOper::And(other));
-        }
-        self
+    pub fn group_by_rollup<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.add_grouping_set(GroupingSet::Rollup(columns_to_exprs(cols)))
     }
 
-    /// Or where condition.
+    /// Group by `CUBE` of columns, adding subtotal rows for every combination of the columns. Postgres only.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics without any column.
     /// 
     /// # Examples
     /// 
@@ -958,10 +1271,126 @@ impl SelectStatement {
     /// use sea_query::{*, tests_cfg::*};
     /// 
     /// let query = Query::select()
-    ///     .table_column(Glyph::Table, Glyph::Image)
-    ///     .from(Glyph::Table)
-    ///     .or_where(Expr::tbl(Glyph::Table, Glyph::Aspect).is_in(vec![3, 4]))
-    ///     .or_where(Expr::tbl(Glyph::Table, Glyph::Image).like("A%"))
+    ///     .columns(vec![Char::FontId, Char::FontSize])
+    ///     .expr(Func::count(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_cube(vec![Char::FontId, Char::FontSize])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "font_size", COUNT("id") FROM "character" GROUP BY CUBE ("font_id", "font_size")"#
+    /// );
+    /// ```
+    pub fn group_by_cube<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.add_grouping_set(GroupingSet::Cube(columns_to_exprs(cols)))
+    }
+
+    /// Group by `GROUPING SETS`, each set of columns being grouped separately. Postgres only.
+    /// An empty set stands for the grand total.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Char::FontId, Char::FontSize])
+    ///     .expr(Func::count(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_grouping_sets(vec![
+    ///         vec![Char::FontId],
+    ///         vec![Char::FontSize],
+    ///         vec![],
+    ///     ])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "font_size", COUNT("id") FROM "character" GROUP BY GROUPING SETS (("font_id"), ("font_size"), ())"#
+    /// );
+    /// ```
+    pub fn group_by_grouping_sets<T, I, S>(&mut self, sets: S) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+        S: IntoIterator<Item = I>,
+    {
+        self.add_grouping_set(GroupingSet::Sets(sets.into_iter().map(columns_to_exprs).collect()))
+    }
+
+    /// Add a [`GroupingSet`] to `GROUP BY`, after the plain group by expressions.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics on a `ROLLUP` or `CUBE` without any column, or `GROUPING SETS` without any set.
+    pub fn add_grouping_set(&mut self, grouping_set: GroupingSet) -> &mut Self {
+        match &grouping_set {
+            GroupingSet::Rollup(exprs) if exprs.is_empty() => panic!("ROLLUP requires at least one column"),
+            GroupingSet::Cube(exprs) if exprs.is_empty() => panic!("CUBE requires at least one column"),
+            GroupingSet::Sets(sets) if sets.is_empty() => panic!("GROUPING SETS requires at least one set"),
+            _ => {},
+        }
+        self.grouping_sets.push(grouping_set);
+        self
+    }
+
+    /// And where condition.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .table_column(Glyph::Table, Glyph::Image)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::tbl(Glyph::Table, Glyph::Aspect).is_in(vec![3, 4]))
+    ///     .and_where(Expr::tbl(Glyph::Table, Glyph::Image).like("A%"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `glyph`.`image` FROM `glyph` WHERE `glyph`.`aspect` IN (3, 4) AND `glyph`.`image` LIKE 'A%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "glyph"."image" FROM "glyph" WHERE "glyph"."aspect" IN (3, 4) AND "glyph"."image" LIKE 'A%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `glyph`.`image` FROM `glyph` WHERE `glyph`.`aspect` IN (3, 4) AND `glyph`.`image` LIKE 'A%'"#
+    /// );
+    /// ```
+    pub fn and_where(&mut self, other: SimpleExpr) -> &mut Self {
+        self.wherei.fold_and_or(LogicalChainOper::And(other));
+        self
+    }
+
+    /// And where condition, variation of [`SelectStatement::and_where`].
+    pub fn and_where_option(&mut self, other: Option<SimpleExpr>) -> &mut Self {
+        if let Some(other) = other {
+            self.wherei.fold_and_or(LogicalChainOper::And(other));
+        }
+        self
+    }
+
+    /// Or where condition.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .table_column(Glyph::Table, Glyph::Image)
+    ///     .from(Glyph::Table)
+    ///     .or_where(Expr::tbl(Glyph::Table, Glyph::Aspect).is_in(vec![3, 4]))
+    ///     .or_where(Expr::tbl(Glyph::Table, Glyph::Image).like("A%"))
     ///     .to_owned();
     /// 
     /// assert_eq!(
@@ -978,7 +1407,48 @@ impl SelectStatement {
     /// );
     /// ```
     pub fn or_where(&mut self, other: SimpleExpr) -> &mut Self {
-        self.wherei.push(LogicalChainOper::Or(other));
+        self.wherei.fold_and_or(LogicalChainOper::Or(other));
+        self
+    }
+
+    /// Where condition, expressed as a [`Condition`] tree. Calling it repeatedly combines the conditions with `AND`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Image)
+    ///     .from(Glyph::Table)
+    ///     .cond_where(
+    ///         Cond::any()
+    ///             .add(
+    ///                 Cond::all()
+    ///                     .add(Expr::col(Glyph::Aspect).gt(1))
+    ///                     .add(Expr::col(Glyph::Aspect).lt(3))
+    ///             )
+    ///             .add(Expr::col(Glyph::Image).like("A%"))
+    ///     )
+    ///     .cond_where(Expr::col(Glyph::Id).ne(5))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE (`aspect` > 1 AND `aspect` < 3 OR `image` LIKE 'A%') AND `id` <> 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "image" FROM "glyph" WHERE ("aspect" > 1 AND "aspect" < 3 OR "image" LIKE 'A%') AND "id" <> 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `image` FROM `glyph` WHERE (`aspect` > 1 AND `aspect` < 3 OR `image` LIKE 'A%') AND `id` <> 5"#
+    /// );
+    /// ```
+    pub fn cond_where<C>(&mut self, condition: C) -> &mut Self
+        where C: IntoCondition {
+        self.wherei.add_condition(condition.into_condition());
         self
     }
 
@@ -1051,7 +1521,7 @@ impl SelectStatement {
     /// );
     /// ```
     pub fn and_having(&mut self, other: SimpleExpr) -> &mut Self {
-        self.having.push(LogicalChainOper::And(other));
+        self.having.fold_and_or(LogicalChainOper::And(other));
         self
     }
 
@@ -1087,7 +1557,47 @@ impl SelectStatement {
     /// );
     /// ```
     pub fn or_having(&mut self, other: SimpleExpr) -> &mut Self {
-        self.having.push(LogicalChainOper::Or(other));
+        self.having.fold_and_or(LogicalChainOper::Or(other));
+        self
+    }
+
+    /// Having condition, expressed as a [`Condition`] tree. Calling it repeatedly combines the conditions with `AND`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .expr(Expr::col(Glyph::Image).max())
+    ///     .from(Glyph::Table)
+    ///     .group_by_columns(vec![
+    ///         Glyph::Aspect,
+    ///     ])
+    ///     .cond_having(
+    ///         Cond::any()
+    ///             .add(Expr::col(Glyph::Aspect).lt(1))
+    ///             .add(Expr::col(Glyph::Aspect).gt(10))
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `aspect`, MAX(`image`) FROM `glyph` GROUP BY `aspect` HAVING `aspect` < 1 OR `aspect` > 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "aspect", MAX("image") FROM "glyph" GROUP BY "aspect" HAVING "aspect" < 1 OR "aspect" > 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `aspect`, MAX(`image`) FROM `glyph` GROUP BY `aspect` HAVING `aspect` < 1 OR `aspect` > 10"#
+    /// );
+    /// ```
+    pub fn cond_having<C>(&mut self, condition: C) -> &mut Self
+        where C: IntoCondition {
+        self.having.add_condition(condition.into_condition());
         self
     }
 
@@ -1121,10 +1631,7 @@ impl SelectStatement {
     /// ```
     pub fn order_by<T>(&mut self, col: T, order: Order) -> &mut Self 
         where T: IntoColumnRef {
-        self.orders.push(OrderExpr {
-            expr: SimpleExpr::Column(col.into_column_ref()),
-            order,
-        });
+        self.orders.push(OrderExpr::col(col, order));
         self
     }
 
@@ -1140,10 +1647,50 @@ impl SelectStatement {
 
     /// Order by [`SimpleExpr`].
     pub fn order_by_expr(&mut self, expr: SimpleExpr, order: Order) -> &mut Self {
-        self.orders.push(OrderExpr {
-            expr,
-            order,
-        });
+        self.orders.push(OrderExpr::expr(expr, order));
+        self
+    }
+
+    /// Order by [`OrderExpr`], which can specify the position of nulls, the collation,
+    /// or order by the position of the value in a list.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .add_order_by(OrderExpr::col(Font::Variant, Order::Asc).field(vec!["Regular".into(), "Bold".into()]))
+    ///     .add_order_by(OrderExpr::col(Font::Language, Order::Desc).nulls(NullOrdering::Last))
+    ///     .add_order_by(OrderExpr::col(Font::Name, Order::Asc).collate(Alias::new("C")))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"SELECT `name` FROM `font` ORDER BY FIELD(`variant`, 'Regular', 'Bold') ASC,"#,
+    ///         r#"ISNULL(`language`) ASC, `language` DESC, `name` COLLATE `C` ASC"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"SELECT "name" FROM "font" ORDER BY CASE WHEN "variant" = 'Regular' THEN 1 WHEN "variant" = 'Bold' THEN 2 ELSE 0 END ASC,"#,
+    ///         r#""language" DESC NULLS LAST, "name" COLLATE "C" ASC"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     vec![
+    ///         r#"SELECT `name` FROM `font` ORDER BY CASE WHEN `variant` = 'Regular' THEN 1 WHEN `variant` = 'Bold' THEN 2 ELSE 0 END ASC,"#,
+    ///         r#"`language` DESC NULLS LAST, `name` COLLATE `C` ASC"#,
+    ///     ].join(" ")
+    /// );
+    /// ```
+    pub fn add_order_by(&mut self, order_expr: OrderExpr) -> &mut Self {
+        self.orders.push(order_expr);
         self
     }
 
@@ -1155,10 +1702,7 @@ impl SelectStatement {
     {
         let mut orders = cols
             .into_iter()
-            .map(|(c, order)| OrderExpr {
-                expr: SimpleExpr::Custom(c.to_string()),
-                order,
-            })
+            .map(|(c, order)| OrderExpr::expr(SimpleExpr::Custom(c.to_string()), order))
             .collect();
         self.orders.append(&mut orders);
         self
@@ -1172,10 +1716,7 @@ impl SelectStatement {
     {
         let mut orders = cols
             .into_iter()
-            .map(|(c, order)| OrderExpr {
-                expr: SimpleExpr::Column(c.into_column_ref()),
-                order,
-            })
+            .map(|(c, order)| OrderExpr::col(c, order))
             .collect();
         self.orders.append(&mut orders);
         self
@@ -1197,6 +1738,129 @@ impl SelectStatement {
         )
     }
 
+    /// Keyset (cursor) pagination: order by the given columns and, if a cursor is given,
+    /// only keep the rows coming after it in that order. The cursor holds the values of the
+    /// columns of the last row of the previous page, in the same order as the columns.
+    /// 
+    /// The predicate is combined by `AND` with the conditions added by either [`SelectStatement::and_where`]
+    /// or [`SelectStatement::cond_where`]. When all columns are sorted in the
+    /// same direction, it is a single row value comparison, otherwise it is expanded column by column.
+    /// Without any column, the statement is left unchanged.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the cursor does not have one value per column.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Glyph::Id, Glyph::Image])
+    ///     .from(Glyph::Table)
+    ///     .keyset_paginate(
+    ///         vec![(Glyph::Aspect, Order::Asc), (Glyph::Id, Order::Asc)],
+    ///         Some(vec![2.5.into(), 10.into()])
+    ///     )
+    ///     .limit(20)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id`, `image` FROM `glyph` WHERE (`aspect`, `id`) > (2.5, 10) ORDER BY `aspect` ASC, `id` ASC LIMIT 20"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id", "image" FROM "glyph" WHERE ("aspect", "id") > (2.5, 10) ORDER BY "aspect" ASC, "id" ASC LIMIT 20"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id`, `image` FROM `glyph` WHERE (`aspect`, `id`) > (2.5, 10) ORDER BY `aspect` ASC, `id` ASC LIMIT 20"#
+    /// );
+    /// ```
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .columns(vec![Glyph::Id, Glyph::Image])
+    ///     .from(Glyph::Table)
+    ///     .keyset_paginate(
+    ///         vec![(Glyph::Aspect, Order::Desc), (Glyph::Id, Order::Asc)],
+    ///         Some(vec![2.5.into(), 10.into()])
+    ///     )
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id`, `image` FROM `glyph` WHERE (`aspect` < 2.5) OR ((`aspect` = 2.5) AND (`id` > 10)) ORDER BY `aspect` DESC, `id` ASC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id", "image" FROM "glyph" WHERE ("aspect" < 2.5) OR (("aspect" = 2.5) AND ("id" > 10)) ORDER BY "aspect" DESC, "id" ASC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id`, `image` FROM `glyph` WHERE (`aspect` < 2.5) OR ((`aspect` = 2.5) AND (`id` > 10)) ORDER BY `aspect` DESC, `id` ASC"#
+    /// );
+    /// ```
+    pub fn keyset_paginate<T, I>(&mut self, columns: I, cursor: Option<Vec<Value>>) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = (T, Order)>,
+    {
+        let columns: Vec<(ColumnRef, Order)> = columns
+            .into_iter()
+            .map(|(c, order)| (c.into_column_ref(), order))
+            .collect();
+        if let Some(cursor) = &cursor {
+            if cursor.len() != columns.len() {
+                panic!("Keyset cursor has {} values for {} columns", cursor.len(), columns.len());
+            }
+        }
+        if columns.is_empty() {
+            return self;
+        }
+        if let Some(cursor) = cursor {
+            let compare = |col: &ColumnRef, order: &Order, value: Value| {
+                let col = Expr::expr(SimpleExpr::Column(col.clone()));
+                match order {
+                    Order::Asc => col.gt(value),
+                    Order::Desc => col.lt(value),
+                }
+            };
+            let mut rows = columns.iter().zip(cursor);
+            let predicate = if columns.len() == 1 {
+                let ((col, order), value) = rows.next().unwrap();
+                Some(compare(col, order, value))
+            } else if columns.iter().all(|(_, order)| *order == columns[0].1) {
+                let (cols, values): (Vec<_>, Vec<_>) = rows
+                    .map(|((col, _), value)| (SimpleExpr::Column(col.clone()), SimpleExpr::Value(value)))
+                    .unzip();
+                let tuple = Expr::tuple(cols);
+                Some(match columns[0].1 {
+                    Order::Asc => tuple.gt(SimpleExpr::Tuple(values)),
+                    Order::Desc => tuple.lt(SimpleExpr::Tuple(values)),
+                })
+            } else {
+                // a > x OR (a = x AND (b < y OR (b = y AND ...)))
+                rows.rev().fold(None, |rest: Option<SimpleExpr>, ((col, order), value)| {
+                    let compare = compare(col, order, value.clone());
+                    Some(match rest {
+                        Some(rest) => compare.or(Expr::expr(SimpleExpr::Column(col.clone())).eq(value).and(rest)),
+                        None => compare,
+                    })
+                })
+            };
+            if let Some(predicate) = predicate {
+                self.wherei.fold_and_or(LogicalChainOper::And(predicate));
+            }
+        }
+        self.orders.extend(columns.into_iter().map(|(col, order)| OrderExpr::expr(SimpleExpr::Column(col), order)));
+        self
+    }
+
     /// Limit the number of returned rows.
     /// 
     /// # Examples
@@ -1260,6 +1924,161 @@ impl SelectStatement {
         self
     }
 
+    /// Lock the selected rows.
+    /// 
+    /// Mysql has no `NO KEY UPDATE` and `KEY SHARE`, building them panics there.
+    /// Sqlite has no row locking, building a lock clause panics there unless it is made optional
+    /// by [`SelectStatement::lock_optional`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq(5))
+    ///     .lock(LockType::Update)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5 FOR UPDATE"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 FOR UPDATE"#
+    /// );
+    /// ```
+    pub fn lock(&mut self, r#type: LockType) -> &mut Self {
+        self.lock = Some(LockClause {
+            r#type,
+            tables: Vec::new(),
+            behavior: None,
+            optional: false,
+        });
+        self
+    }
+
+    /// Lock the selected rows, only those of the given tables.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .lock_with_tables(LockType::Share, vec![Char::Table])
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id` FOR SHARE OF `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" INNER JOIN "font" ON "character"."font_id" = "font"."id" FOR SHARE OF "character""#
+    /// );
+    /// ```
+    pub fn lock_with_tables<T, I>(&mut self, r#type: LockType, tables: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.lock = Some(LockClause {
+            r#type,
+            tables: tables.into_iter().map(|t| t.into_iden()).collect(),
+            behavior: None,
+            optional: false,
+        });
+        self
+    }
+
+    /// Lock the selected rows, either failing (`NOWAIT`) or skipping (`SKIP LOCKED`) the rows already locked.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .limit(1)
+    ///     .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` LIMIT 1 FOR UPDATE SKIP LOCKED"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" LIMIT 1 FOR UPDATE SKIP LOCKED"#
+    /// );
+    /// ```
+    pub fn lock_with_behavior(&mut self, r#type: LockType, behavior: LockBehavior) -> &mut Self {
+        self.lock = Some(LockClause {
+            r#type,
+            tables: Vec::new(),
+            behavior: Some(behavior),
+            optional: false,
+        });
+        self
+    }
+
+    /// Lock the selected rows of the given tables, with a wait policy.
+    /// See [`SelectStatement::lock_with_tables`] and [`SelectStatement::lock_with_behavior`].
+    pub fn lock_with_tables_behavior<T, I>(&mut self, r#type: LockType, tables: I, behavior: LockBehavior) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.lock = Some(LockClause {
+            r#type,
+            tables: tables.into_iter().map(|t| t.into_iden()).collect(),
+            behavior: Some(behavior),
+            optional: false,
+        });
+        self
+    }
+
+    /// Let backends without row locking omit the lock clause set before, instead of panicking.
+    /// Sqlite locks the whole database within a transaction, so the clause can be left out there.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq(5))
+    ///     .lock(LockType::Update)
+    ///     .lock_optional()
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 FOR UPDATE"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5"#
+    /// );
+    /// ```
+    pub fn lock_optional(&mut self) -> &mut Self {
+        if let Some(lock) = &mut self.lock {
+            lock.optional = true;
+        }
+        self
+    }
+
     /// Build corresponding SQL statement for certain database backend and collect query parameters
     /// 
     /// # Examples
@@ -1369,3 +2188,13 @@ impl SelectStatement {
         inject_parameters(&sql, values.0, &query_builder)
     }
 }
+
+fn columns_to_exprs<T, I>(cols: I) -> Vec<SimpleExpr>
+where
+    T: IntoColumnRef,
+    I: IntoIterator<Item = T>,
+{
+    cols.into_iter()
+        .map(|c| SimpleExpr::Column(c.into_column_ref()))
+        .collect()
+}
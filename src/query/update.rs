@@ -1,6 +1,6 @@
 #[cfg(feature="with-json")]
 use serde_json::Value as JsonValue;
-use crate::{backend::QueryBuilder, types::*, expr::*, value::*, prepare::*};
+use crate::{backend::QueryBuilder, types::*, expr::*, query::*, value::*, prepare::*};
 
 /// Update existing rows in the table
 /// 
@@ -34,10 +34,12 @@ use crate::{backend::QueryBuilder, types::*, expr::*, value::*, prepare::*};
 #[derive(Debug, Clone)]
 pub struct UpdateStatement {
     pub(crate) table: Option<Box<TableRef>>,
+    pub(crate) join: Vec<JoinExpr>,
     pub(crate) values: Vec<(String, Box<SimpleExpr>)>,
-    pub(crate) wherei: Option<Box<SimpleExpr>>,
+    pub(crate) wherei: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
+    pub(crate) returning: Vec<SelectExpr>,
 }
 
 impl Default for UpdateStatement {
@@ -51,10 +53,12 @@ impl UpdateStatement {
     pub fn new() -> Self {
         Self {
             table: None,
+            join: Vec::new(),
             values: Vec::new(),
-            wherei: None,
+            wherei: ConditionHolder::new(),
             orders: Vec::new(),
             limit: None,
+            returning: Vec::new(),
         }
     }
 
@@ -80,6 +84,64 @@ impl UpdateStatement {
         self.table(table)
     }
 
+    /// Inner join with other table to update rows matching it.
+    /// Rendered as `UPDATE ... JOIN ... SET` on MySQL and as `UPDATE ... SET ... FROM` on Postgres and Sqlite,
+    /// where the join condition goes into the `WHERE` clause.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .value(Char::FontSize, 8.into())
+    ///     .and_where(Expr::tbl(Font::Table, Font::Name).eq("Arial"))
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id` SET `character`.`font_size` = 8 WHERE `font`.`name` = 'Arial'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET "font_size" = 8 FROM "font" WHERE "character"."font_id" = "font"."id" AND "font"."name" = 'Arial'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `character` SET `font_size` = 8 FROM `font` WHERE `character`.`font_id` = `font`.`id` AND `font`.`name` = 'Arial'"#
+    /// );
+    /// ```
+    pub fn inner_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+        where R: IntoTableRef, C: IntoCondition {
+        self.join(JoinType::InnerJoin, tbl_ref, condition)
+    }
+
+    /// Left join with other table. Only supported by MySQL.
+    pub fn left_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+        where R: IntoTableRef, C: IntoCondition {
+        self.join(JoinType::LeftJoin, tbl_ref, condition)
+    }
+
+    /// Join with other table by [`JoinType`].
+    /// Postgres and Sqlite only support [`JoinType::Join`] and [`JoinType::InnerJoin`] here.
+    ///
+    /// # Examples
+    ///
+    /// See [`UpdateStatement::inner_join`]
+    pub fn join<R, C>(&mut self, join: JoinType, tbl_ref: R, condition: C) -> &mut Self
+        where R: IntoTableRef, C: IntoCondition {
+        self.join.push(JoinExpr {
+            join,
+            table: Box::new(tbl_ref.into_table_ref()),
+            on: Some(JoinOn::Condition(Box::new(ConditionHolder::new_with_condition(condition.into_condition())))),
+            natural: false,
+            lateral: false,
+        });
+        self
+    }
+
     /// Update column value by [`SimpleExpr`].
     /// 
     /// # Examples
@@ -255,19 +317,20 @@ impl UpdateStatement {
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE (`id` > 1) AND (`id` < 3)"#
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `id` > 1 AND `id` < 3"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"UPDATE "glyph" SET "aspect" = 2.1345, "image" = '235m' WHERE ("id" > 1) AND ("id" < 3)"#
+    ///     r#"UPDATE "glyph" SET "aspect" = 2.1345, "image" = '235m' WHERE "id" > 1 AND "id" < 3"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE (`id` > 1) AND (`id` < 3)"#
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `id` > 1 AND `id` < 3"#
     /// );
     /// ```
     pub fn and_where(&mut self, other: SimpleExpr) -> &mut Self {
-        self.and_or_where(BinOper::And, other)
+        self.wherei.fold_and_or(LogicalChainOper::And(other));
+        self
     }
 
     /// Or where condition.
@@ -289,52 +352,65 @@ impl UpdateStatement {
     /// 
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE (`aspect` < 1) OR (`aspect` > 3)"#
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `aspect` < 1 OR `aspect` > 3"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"UPDATE "glyph" SET "aspect" = 2.1345, "image" = '235m' WHERE ("aspect" < 1) OR ("aspect" > 3)"#
+    ///     r#"UPDATE "glyph" SET "aspect" = 2.1345, "image" = '235m' WHERE "aspect" < 1 OR "aspect" > 3"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE (`aspect` < 1) OR (`aspect` > 3)"#
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `aspect` < 1 OR `aspect` > 3"#
     /// );
     /// ```
     pub fn or_where(&mut self, other: SimpleExpr) -> &mut Self {
-        self.and_or_where(BinOper::Or, other)
-    }
-
-    fn and_or_where(&mut self, bopr: BinOper, right: SimpleExpr) -> &mut Self {
-        self.wherei = Self::merge_expr(
-            self.wherei.take(), 
-            match bopr {
-                BinOper::And => BinOper::And,
-                BinOper::Or => BinOper::Or,
-                _ => panic!("not allow"),
-            },
-            right
-        );
+        self.wherei.fold_and_or(LogicalChainOper::Or(other));
         self
     }
 
-    fn merge_expr(left: Option<Box<SimpleExpr>>, bopr: BinOper, right: SimpleExpr) -> Option<Box<SimpleExpr>> {
-        Some(Box::new(match left {
-            Some(left) => SimpleExpr::Binary(
-                left,
-                bopr,
-                Box::new(right)
-            ),
-            None => right,
-        }))
+    /// Where condition, expressed as a [`Condition`] tree. Calling it repeatedly combines the conditions with `AND`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .values(vec![
+    ///         (Glyph::Aspect, 2.1345.into()),
+    ///     ])
+    ///     .cond_where(
+    ///         Cond::any()
+    ///             .add(Expr::col(Glyph::Aspect).lt(1))
+    ///             .add(Expr::col(Glyph::Aspect).gt(3))
+    ///     )
+    ///     .cond_where(Expr::col(Glyph::Image).is_not_null())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345 WHERE (`aspect` < 1 OR `aspect` > 3) AND `image` IS NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "aspect" = 2.1345 WHERE ("aspect" < 1 OR "aspect" > 3) AND "image" IS NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345 WHERE (`aspect` < 1 OR `aspect` > 3) AND `image` IS NOT NULL"#
+    /// );
+    /// ```
+    pub fn cond_where<C>(&mut self, condition: C) -> &mut Self
+        where C: IntoCondition {
+        self.wherei.add_condition(condition.into_condition());
+        self
     }
 
     /// Order by column.
     pub fn order_by<T>(&mut self, col: T, order: Order) -> &mut Self 
         where T: IntoColumnRef {
-        self.orders.push(OrderExpr {
-            expr: SimpleExpr::Column(col.into_column_ref()),
-            order,
-        });
+        self.orders.push(OrderExpr::col(col, order));
         self
     }
 
@@ -350,10 +426,13 @@ impl UpdateStatement {
 
     /// Order by [`SimpleExpr`].
     pub fn order_by_expr(&mut self, expr: SimpleExpr, order: Order) -> &mut Self {
-        self.orders.push(OrderExpr {
-            expr,
-            order,
-        });
+        self.orders.push(OrderExpr::expr(expr, order));
+        self
+    }
+
+    /// Order by [`OrderExpr`], which can specify the position of nulls and the collation.
+    pub fn add_order_by(&mut self, order_expr: OrderExpr) -> &mut Self {
+        self.orders.push(order_expr);
         self
     }
 
@@ -365,10 +444,7 @@ impl UpdateStatement {
     {
         let mut orders = cols
             .into_iter()
-            .map(|(c, order)| OrderExpr {
-                expr: SimpleExpr::Custom(c.to_string()),
-                order,
-            })
+            .map(|(c, order)| OrderExpr::expr(SimpleExpr::Custom(c.to_string()), order))
             .collect();
         self.orders.append(&mut orders);
         self
@@ -382,10 +458,7 @@ impl UpdateStatement {
     {
         let mut orders = cols
             .into_iter()
-            .map(|(c, order)| OrderExpr {
-                expr: SimpleExpr::Column(c.into_column_ref()),
-                order,
-            })
+            .map(|(c, order)| OrderExpr::col(c, order))
             .collect();
         self.orders.append(&mut orders);
         self
@@ -414,6 +487,59 @@ impl UpdateStatement {
         self
     }
 
+    /// RETURNING expressions of the updated rows. Postgres and Sqlite only, MySQL panics.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Aspect, 2.into())
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .returning(Query::select().columns(vec![Glyph::Id, Glyph::Image]).take())
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "aspect" = 2 WHERE "image" LIKE 'A%' RETURNING "id", "image""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = 2 WHERE `image` LIKE 'A%' RETURNING `id`, `image`"#
+    /// );
+    /// ```
+    pub fn returning(&mut self, select: SelectStatement) -> &mut Self {
+        self.returning = select.selects;
+        self
+    }
+
+    /// RETURNING a column of the updated rows. Postgres and Sqlite only, MySQL panics.
+    /// Wrapper over [`UpdateStatement::returning()`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Aspect, 2.into())
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .returning_col(Glyph::Id)
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "aspect" = 2 WHERE "image" LIKE 'A%' RETURNING "id""#
+    /// );
+    /// ```
+    pub fn returning_col<C>(&mut self, col: C) -> &mut Self
+        where C: IntoIden {
+        self.returning(Query::select().column(col.into_iden()).take())
+    }
+
     /// Build corresponding SQL statement for certain database backend and collect query parameters
     /// 
     /// # Examples
@@ -1,10 +1,194 @@
 //! Schema definition & alternations statements
 
+use std::rc::Rc;
 use super::*;
+use crate::error::*;
 
 #[derive(Debug, Clone)]
 pub enum SchemaStatement {
     TableStatement(TableStatement),
     IndexStatement(IndexStatement),
     ForeignKeyStatement(ForeignKeyStatement),
-}
\ No newline at end of file
+}
+
+impl SchemaStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T>(&self, schema_builder: T) -> String
+        where T: TableBuilder + IndexBuilder + ForeignKeyBuilder {
+        match self {
+            Self::TableStatement(stat) => stat.build(schema_builder),
+            Self::IndexStatement(stat) => stat.build(schema_builder),
+            Self::ForeignKeyStatement(stat) => stat.build(schema_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T>(&self, schema_builder: T) -> String
+        where T: TableBuilder + IndexBuilder + ForeignKeyBuilder {
+        self.build(schema_builder)
+    }
+}
+
+/// Compute the statements that migrate table `current` into table `desired`.
+///
+/// Columns are matched by name: missing columns are added, extra columns are dropped
+/// and columns with a different type or specification are modified, all in a single alter statement.
+/// A renamed column therefore shows up as a drop followed by an add.
+/// Named indexes and foreign keys are matched by name and recreated when their definition changed,
+/// unnamed ones are matched by definition. Primary keys are left untouched, columns are compared
+/// and modified without their `PRIMARY KEY` specification.
+///
+/// Statements are ordered so that foreign keys and indexes are dropped before columns change,
+/// and created after.
+///
+/// # Errors
+///
+/// Fails if an unnamed index or foreign key of `current` has to be dropped, as it cannot be referred to,
+/// e.g. foreign keys discovered on Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let current = Table::create()
+///     .table(Font::Table)
+///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Font::Name).string_len(100))
+///     .col(ColumnDef::new(Font::Variant).text())
+///     .to_owned();
+///
+/// let desired = Table::create()
+///     .table(Font::Table)
+///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Font::Name).string_len(255).not_null())
+///     .col(ColumnDef::new(Font::Language).text())
+///     .index(Index::create().name("idx-font-name").col(Font::Name))
+///     .to_owned();
+///
+/// assert_eq!(
+///     diff_table(&desired, &current)
+///         .unwrap()
+///         .iter()
+///         .map(|stat| stat.to_string(MysqlQueryBuilder))
+///         .collect::<Vec<_>>(),
+///     vec![
+///         "ALTER TABLE `font` DROP COLUMN `variant`, ADD COLUMN `language` text, MODIFY COLUMN `name` varchar(255) NOT NULL",
+///         "CREATE INDEX `idx-font-name` ON `font` (`name`)",
+///     ]
+/// );
+/// ```
+pub fn diff_table(desired: &TableCreateStatement, current: &TableCreateStatement) -> Result<Vec<SchemaStatement>> {
+    let table = desired.table.clone().or_else(|| current.table.clone())
+        .expect("No table name found");
+    let mut statements = Vec::new();
+
+    let foreign_key_changed = |a: &ForeignKeyCreateStatement, list: &[ForeignKeyCreateStatement]| {
+        !list.iter().any(|b| same_foreign_key(a, b))
+    };
+    for foreign_key in current.foreign_keys.iter() {
+        if foreign_key_changed(foreign_key, &desired.foreign_keys) {
+            let name = foreign_key.foreign_key.name.as_ref().ok_or_else(|| Error::UnnamedForeignKeyDrop {
+                table: table.to_string(),
+                columns: join_idens(foreign_key.foreign_key.columns.iter()),
+            })?;
+            let mut drop = ForeignKeyDropStatement::new().name(name);
+            drop.table = Some(table.clone());
+            statements.push(SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Drop(drop)));
+        }
+    }
+
+    let index_changed = |a: &IndexCreateStatement, list: &[IndexCreateStatement]| {
+        !a.primary && !list.iter().any(|b| same_index(a, b))
+    };
+    for index in current.indexes.iter() {
+        if index_changed(index, &desired.indexes) {
+            let name = index.index.name.as_ref().ok_or_else(|| Error::UnnamedIndexDrop {
+                table: table.to_string(),
+                columns: join_idens(index.index.columns.iter().map(|c| &c.name)),
+            })?;
+            let mut drop = IndexDropStatement::new().name(name);
+            drop.table = Some(table.clone());
+            statements.push(SchemaStatement::IndexStatement(IndexStatement::Drop(drop)));
+        }
+    }
+
+    let mut alter = TableAlterStatement::new();
+    alter.table = Some(table.clone());
+    for column in current.columns.iter() {
+        if !desired.columns.iter().any(|c| c.name.as_ref() == column.name.as_ref()) {
+            alter.options.push(TableAlterOption::DropColumn(column.name.clone()));
+        }
+    }
+    for column in desired.columns.iter() {
+        if !current.columns.iter().any(|c| c.name.as_ref() == column.name.as_ref()) {
+            alter.options.push(TableAlterOption::AddColumn(column.clone()));
+        }
+    }
+    for column in desired.columns.iter() {
+        if let Some(existing) = current.columns.iter().find(|c| c.name.as_ref() == column.name.as_ref()) {
+            if !same_column(column, existing) {
+                let mut column = column.clone();
+                column.spec.retain(|spec| !matches!(spec, ColumnSpec::PrimaryKey));
+                alter.options.push(TableAlterOption::ModifyColumn(column));
+            }
+        }
+    }
+    if !alter.options.is_empty() {
+        statements.push(SchemaStatement::TableStatement(TableStatement::Alter(alter)));
+    }
+
+    for index in desired.indexes.iter() {
+        if index_changed(index, &current.indexes) {
+            let mut create = index.clone();
+            create.table = Some(table.clone());
+            statements.push(SchemaStatement::IndexStatement(IndexStatement::Create(create)));
+        }
+    }
+
+    for foreign_key in desired.foreign_keys.iter() {
+        if foreign_key_changed(foreign_key, &current.foreign_keys) {
+            let mut create = foreign_key.clone();
+            create.foreign_key.table = Some(table.clone());
+            statements.push(SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Create(create)));
+        }
+    }
+
+    Ok(statements)
+}
+
+/// Column type and specification are equal, regardless of the order of the specification
+/// and ignoring the primary key
+fn same_column(a: &ColumnDef, b: &ColumnDef) -> bool {
+    let spec = |c: &ColumnDef| -> Vec<ColumnSpec> {
+        c.spec.iter().filter(|spec| !matches!(spec, ColumnSpec::PrimaryKey)).cloned().collect()
+    };
+    let (a_spec, b_spec) = (spec(a), spec(b));
+    a.types == b.types
+        && a_spec.len() == b_spec.len()
+        && a_spec.iter().all(|spec| b_spec.contains(spec))
+}
+
+fn join_idens<'a, I>(idens: I) -> String
+    where I: Iterator<Item = &'a Rc<dyn Iden>> {
+    idens.map(|iden| iden.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// Index definition is equal, ignoring the table it is attached to
+fn same_index(a: &IndexCreateStatement, b: &IndexCreateStatement) -> bool {
+    a.index == b.index
+        && a.primary == b.primary
+        && a.unique == b.unique
+        && a.index_type == b.index_type
+}
+
+/// Foreign key definition is equal, ignoring the table it is attached to
+fn same_foreign_key(a: &ForeignKeyCreateStatement, b: &ForeignKeyCreateStatement) -> bool {
+    let (a, b) = (&a.foreign_key, &b.foreign_key);
+    a.name == b.name
+        && a.ref_table == b.ref_table
+        && a.columns == b.columns
+        && a.ref_columns == b.ref_columns
+        && a.on_delete == b.on_delete
+        && a.on_update == b.on_update
+}
@@ -1,5 +1,5 @@
 use std::rc::Rc;
-use crate::{ColumnDef, backend::TableBuilder, types::*, prepare::*};
+use crate::{ColumnDef, ForeignKeyCreateStatement, IndexCreateStatement, backend::TableBuilder, types::*, prepare::*};
 
 /// Alter a table
 /// 
@@ -26,10 +26,45 @@ use crate::{ColumnDef, backend::TableBuilder, types::*, prepare::*};
 ///     r#"ALTER TABLE `font` ADD COLUMN `new_col` integer NOT NULL DEFAULT 100"#,
 /// );
 /// ```
+///
+/// Several alterations can be combined. MySQL and Postgres apply them in a single statement,
+/// while Sqlite accepts one alteration per statement, so they are split into statements joined by `; `.
+/// Use [`TableAlterStatement::build_statements`] to get the statements separately, e.g. for drivers
+/// executing a single statement at a time.
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let table = Table::alter()
+///     .table(Font::Table)
+///     .add_column(ColumnDef::new(Alias::new("new_col")).integer())
+///     .rename_column(Alias::new("old_col"), Alias::new("old_column"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     table.to_string(MysqlQueryBuilder),
+///     r#"ALTER TABLE `font` ADD COLUMN `new_col` int, RENAME COLUMN `old_col` TO `old_column`"#
+/// );
+/// assert_eq!(
+///     table.to_string(PostgresQueryBuilder),
+///     r#"ALTER TABLE "font" ADD COLUMN "new_col" integer, RENAME COLUMN "old_col" TO "old_column""#
+/// );
+/// assert_eq!(
+///     table.to_string(SqliteQueryBuilder),
+///     r#"ALTER TABLE `font` ADD COLUMN `new_col` integer; ALTER TABLE `font` RENAME COLUMN `old_col` TO `old_column`"#
+/// );
+/// assert_eq!(
+///     table.build_statements(SqliteQueryBuilder),
+///     vec![
+///         r#"ALTER TABLE `font` ADD COLUMN `new_col` integer"#,
+///         r#"ALTER TABLE `font` RENAME COLUMN `old_col` TO `old_column`"#,
+///     ]
+/// );
+/// ```
 #[derive(Debug, Clone)]
 pub struct TableAlterStatement {
     pub(crate) table: Option<Rc<dyn Iden>>,
-    pub(crate) alter_option: Option<TableAlterOption>,
+    pub(crate) options: Vec<TableAlterOption>,
 }
 
 /// All available table alter options
@@ -39,6 +74,9 @@ pub enum TableAlterOption {
     ModifyColumn(ColumnDef),
     RenameColumn(Rc<dyn Iden>, Rc<dyn Iden>),
     DropColumn(Rc<dyn Iden>),
+    AddForeignKey(ForeignKeyCreateStatement),
+    DropForeignKey(String),
+    AddIndex(IndexCreateStatement),
 }
 
 impl Default for TableAlterStatement {
@@ -52,7 +90,7 @@ impl TableAlterStatement {
     pub fn new() -> Self {
         Self {
             table: None,
-            alter_option: None,
+            options: Vec::new(),
         }
     }
 
@@ -152,7 +190,7 @@ impl TableAlterStatement {
         self.alter_option(TableAlterOption::RenameColumn(Rc::new(from_name), Rc::new(to_name)))
     }
 
-    /// Add a column to existing table
+    /// Drop a column from an existing table
     /// 
     /// # Examples
     /// 
@@ -179,11 +217,126 @@ impl TableAlterStatement {
         self.alter_option(TableAlterOption::DropColumn(Rc::new(col_name)))
     }
 
+    /// Add a foreign key constraint to an existing table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let table = Table::alter()
+    ///     .table(Char::Table)
+    ///     .add_foreign_key(
+    ///         ForeignKey::create()
+    ///             .name("FK_character_font")
+    ///             .from(Char::Table, Char::FontId)
+    ///             .to(Font::Table, Font::Id)
+    ///             .on_delete(ForeignKeyAction::Cascade)
+    ///     )
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"ALTER TABLE `character` ADD CONSTRAINT `FK_character_font`"#,
+    ///         r#"FOREIGN KEY (`font_id`) REFERENCES `font` (`id`) ON DELETE CASCADE"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"ALTER TABLE "character" ADD CONSTRAINT "FK_character_font""#,
+    ///         r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id") ON DELETE CASCADE"#,
+    ///     ].join(" ")
+    /// );
+    /// // Sqlite does not support modification of foreign key constraints to existing tables
+    /// ```
+    pub fn add_foreign_key(self, foreign_key: ForeignKeyCreateStatement) -> Self {
+        self.alter_option(TableAlterOption::AddForeignKey(foreign_key))
+    }
+
+    /// Drop a foreign key constraint from an existing table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let table = Table::alter()
+    ///     .table(Char::Table)
+    ///     .drop_foreign_key("FK_character_font")
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `character` DROP FOREIGN KEY `FK_character_font`"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "character" DROP CONSTRAINT "FK_character_font""#
+    /// );
+    /// // Sqlite does not support modification of foreign key constraints to existing tables
+    /// ```
+    pub fn drop_foreign_key(self, name: &str) -> Self {
+        self.alter_option(TableAlterOption::DropForeignKey(name.into()))
+    }
+
+    /// Add an index to an existing table.
+    /// Sqlite, and Postgres for an index that is neither unique nor a primary key, create the index in a separate statement.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let table = Table::alter()
+    ///     .table(Glyph::Table)
+    ///     .add_index(Index::create().name("idx-glyph-aspect").col(Glyph::Aspect).unique())
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `glyph` ADD UNIQUE KEY `idx-glyph-aspect` (`aspect`)"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "glyph" ADD CONSTRAINT "idx-glyph-aspect" UNIQUE ("aspect")"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX `idx-glyph-aspect` ON `glyph` (`aspect`)"#
+    /// );
+    /// ```
+    pub fn add_index(self, index: IndexCreateStatement) -> Self {
+        self.alter_option(TableAlterOption::AddIndex(index))
+    }
+
     fn alter_option(mut self, alter_option: TableAlterOption) -> Self {
-        self.alter_option = Some(alter_option);
+        self.options.push(alter_option);
         self
     }
 
+    /// Split into statements of one alter option each
+    pub fn split(&self) -> Vec<TableAlterStatement> {
+        self.options
+            .iter()
+            .map(|option| Self {
+                table: self.table.clone(),
+                options: vec![option.clone()],
+            })
+            .collect()
+    }
+
+    /// Build corresponding SQL statements for certain database backend and return SQL strings,
+    /// more than one if the backend can not apply all the alter options in a single statement
+    pub fn build_statements<T: TableBuilder>(&self, table_builder: T) -> Vec<String> {
+        table_builder
+            .split_table_alter_statement(self)
+            .iter()
+            .map(|alter| alter.build_any(&table_builder))
+            .collect()
+    }
+
     /// Build corresponding SQL statement for certain database backend and return SQL string
     pub fn build<T: TableBuilder>(&self, table_builder: T) -> String {
         let mut sql = SqlWriter::new();
@@ -11,7 +11,7 @@ pub struct ColumnDef {
 }
 
 /// All column types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColumnType {
     Char(Option<u32>),
     String(Option<u32>),
@@ -35,8 +35,15 @@ pub enum ColumnType {
     Custom(Rc<dyn Iden>),
 }
 
+impl<T: 'static> From<T> for ColumnType
+    where T: Iden {
+    fn from(iden: T) -> Self {
+        ColumnType::Custom(Rc::new(iden))
+    }
+}
+
 /// All column specification keywords
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColumnSpec {
     Null,
     NotNull,
@@ -47,6 +54,15 @@ pub enum ColumnSpec {
     Extra(String),
 }
 
+impl PartialEq for ColumnDef {
+    fn eq(&self, other: &Self) -> bool {
+        self.table == other.table
+            && self.name.as_ref() == other.name.as_ref()
+            && self.types == other.types
+            && self.spec == other.spec
+    }
+}
+
 impl ColumnDef {
     /// Construct a table column
     pub fn new<T: 'static>(name: T) -> Self
@@ -83,6 +83,7 @@ pub struct TableCreateStatement {
     pub(crate) indexes: Vec<IndexCreateStatement>,
     pub(crate) foreign_keys: Vec<ForeignKeyCreateStatement>,
     pub(crate) if_not_exists: bool,
+    pub(crate) fts5: Option<Vec<(String, String)>>,
 }
 
 /// All available table options
@@ -116,6 +117,7 @@ impl TableCreateStatement {
             indexes: Vec::new(),
             foreign_keys: Vec::new(),
             if_not_exists: false,
+            fts5: None,
         }
     }
 
@@ -256,6 +258,41 @@ impl TableCreateStatement {
         self
     }
 
+    /// Create a virtual table using the FTS5 full-text search module, indexing the text of its columns.
+    /// Column types are ignored, a column can be left out of the index with `.extra("UNINDEXED".to_owned())`.
+    /// Sqlite only.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// 
+    /// let table = Table::create()
+    ///     .table(Alias::new("character_fts"))
+    ///     .if_not_exists()
+    ///     .fts5()
+    ///     .col(ColumnDef::new(Char::Character))
+    ///     .col(ColumnDef::new(Char::FontId).extra("UNINDEXED".to_owned()))
+    ///     .fts5_option("tokenize", "porter unicode61")
+    ///     .to_owned();
+    /// 
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     "CREATE VIRTUAL TABLE IF NOT EXISTS `character_fts` USING fts5(`character`, `font_id` UNINDEXED, tokenize = 'porter unicode61')"
+    /// );
+    /// ```
+    pub fn fts5(&mut self) -> &mut Self {
+        self.fts5.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// Set an option of the FTS5 module, e.g. `tokenize`, or `content` for an external content table.
+    /// Implies [`TableCreateStatement::fts5`]. Sqlite only, panics on build if `key` is not an FTS5 option.
+    pub fn fts5_option(&mut self, key: &str, value: &str) -> &mut Self {
+        self.fts5.get_or_insert_with(Vec::new).push((key.to_owned(), value.to_owned()));
+        self
+    }
+
     fn opt(&mut self, option: TableOpt) -> &mut Self {
         self.options.push(option);
         self
@@ -2,7 +2,7 @@
 
 use std::fmt;
 use std::rc::Rc;
-use crate::{query::*, expr::*};
+use crate::{query::*, expr::*, value::*};
 
 /// Identifier in query
 pub trait Iden {
@@ -38,6 +38,12 @@ impl fmt::Debug for dyn Iden {
     }
 }
 
+impl PartialEq for dyn Iden {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
 /// Column references
 #[derive(Debug, Clone)]
 pub enum ColumnRef {
@@ -68,6 +74,16 @@ pub trait IntoTableRef {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UnOper {
     Not,
+    Neg,
+}
+
+/// Operator applied to a sub-query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubQueryOper {
+    Exists,
+    Any,
+    Some,
+    All,
 }
 
 /// Binary operator
@@ -93,6 +109,32 @@ pub enum BinOper {
     Sub,
     Mul,
     Div,
+    Mod,
+    Concat,
+    BitAnd,
+    BitOr,
+    LShift,
+    RShift,
+    ILike,
+    NotILike,
+    Escape,
+    SimilarTo,
+    NotSimilarTo,
+    Regexp,
+    NotRegexp,
+    IsDistinctFrom,
+    IsNotDistinctFrom,
+    JsonGet,
+    JsonGetText,
+    JsonGetPath,
+    JsonGetPathText,
+    JsonContains,
+    JsonContainedBy,
+    JsonHasKey,
+    JsonHasAnyKey,
+    JsonHasAllKeys,
+    Matches,
+    MatchesBoolean,
 }
 
 /// Logical chain operator
@@ -108,7 +150,12 @@ pub enum JoinType {
     Join,
     InnerJoin,
     LeftJoin,
+    /// Sqlite supports it since 3.39
     RightJoin,
+    /// Postgres, and Sqlite since 3.39
+    FullOuterJoin,
+    /// Only valid with [`SelectStatement::cross_join`](crate::query::SelectStatement::cross_join), without a join condition
+    CrossJoin,
 }
 
 /// Order expression
@@ -116,12 +163,16 @@ pub enum JoinType {
 pub struct OrderExpr {
     pub(crate) expr: SimpleExpr,
     pub(crate) order: Order,
+    pub(crate) field: Option<Vec<Value>>,
+    pub(crate) nulls: Option<NullOrdering>,
+    pub(crate) collation: Option<Rc<dyn Iden>>,
 }
 
 /// Join on types
 #[derive(Debug, Clone)]
 pub enum JoinOn {
-    Condition(Box<SimpleExpr>),
+    /// `ON` condition. Holds a [`ConditionHolder`] since 0.11, it used to be a `Box<SimpleExpr>`.
+    Condition(Box<ConditionHolder>),
     Columns(Vec<SimpleExpr>),
 }
 
@@ -132,6 +183,13 @@ pub enum Order {
     Desc,
 }
 
+/// Position of nulls in ordering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullOrdering {
+    First,
+    Last,
+}
+
 /// Helper for create name alias
 #[derive(Debug, Clone)]
 pub struct Alias(String);
@@ -221,6 +279,46 @@ impl TableRef {
     }
 }
 
+impl OrderExpr {
+    /// Order by column.
+    pub fn col<T>(col: T, order: Order) -> Self
+        where T: IntoColumnRef {
+        Self::expr(SimpleExpr::Column(col.into_column_ref()), order)
+    }
+
+    /// Order by [`SimpleExpr`].
+    pub fn expr(expr: SimpleExpr, order: Order) -> Self {
+        Self {
+            expr,
+            order,
+            field: None,
+            nulls: None,
+            collation: None,
+        }
+    }
+
+    /// Order by the position of the value in the list instead, values not in the list come first.
+    /// Rendered as `FIELD` on MySQL and a `CASE` expression on others.
+    pub fn field(mut self, values: Vec<Value>) -> Self {
+        self.field = Some(values);
+        self
+    }
+
+    /// Put nulls first or last, i.e. `NULLS FIRST` / `NULLS LAST`.
+    /// Emulated by ordering by `ISNULL(...)` beforehand on MySQL.
+    pub fn nulls(mut self, nulls: NullOrdering) -> Self {
+        self.nulls = Some(nulls);
+        self
+    }
+
+    /// Compare with the given collation, i.e. `COLLATE`.
+    pub fn collate<T>(mut self, collation: T) -> Self
+        where T: IntoIden {
+        self.collation = Some(collation.into_iden());
+        self
+    }
+}
+
 impl Alias {
     pub fn new(n: &str) -> Self {
         Self(n.to_owned())
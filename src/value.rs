@@ -170,6 +170,34 @@ mod with_uuid {
     }
 }
 
+/// Conversion into a row of values, see [`Expr::in_tuples`](crate::Expr::in_tuples)
+pub trait IntoValueTuple {
+    fn into_value_tuple(self) -> Vec<Value>;
+}
+
+impl IntoValueTuple for Vec<Value> {
+    fn into_value_tuple(self) -> Vec<Value> {
+        self
+    }
+}
+
+macro_rules! impl_into_value_tuple {
+    ( $( $T:ident : $i:tt ),+ ) => {
+        impl<$( $T ),+> IntoValueTuple for ( $( $T, )+ )
+            where $( $T: Into<Value> ),+ {
+            fn into_value_tuple(self) -> Vec<Value> {
+                vec![ $( self.$i.into() ),+ ]
+            }
+        }
+    };
+}
+
+impl_into_value_tuple!(A: 0, B: 1);
+impl_into_value_tuple!(A: 0, B: 1, C: 2);
+impl_into_value_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_into_value_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_into_value_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
 impl Value {
     pub fn is_json(&self) -> bool {
         #[cfg(feature="with-json")]
@@ -226,7 +254,7 @@ impl Value {
     }
 }
 
-/// Escape a SQL string literal
+/// Escape a SQL string literal with backslashes, as understood by MySQL
 pub fn escape_string(string: &str) -> String {
     string
         .replace("\\", "\\\\")
@@ -240,7 +268,7 @@ pub fn escape_string(string: &str) -> String {
         .replace("\r", "\\r")
 }
 
-/// Unescape a SQL string literal
+/// Unescape a SQL string literal escaped by [`escape_string`]
 pub fn unescape_string(input: &str) -> String {
     let mut escape = false;
     let mut output = String::new();
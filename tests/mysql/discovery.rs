@@ -0,0 +1,76 @@
+use super::*;
+use sea_query::discovery::*;
+
+#[test]
+fn discover_1() {
+    assert_eq!(
+        MysqlQueryBuilder.query_table_exists("seaql_migrations").to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `table_name` FROM `information_schema`.`tables`",
+            "WHERE `table_schema` = DATABASE() AND `table_name` = 'seaql_migrations'",
+        ].join(" ")
+    );
+    assert_eq!(
+        MysqlQueryBuilder.query_columns("query", "font").to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `column_name`, `column_type`, `is_nullable`, `column_default`, `extra`",
+            "FROM `information_schema`.`columns`",
+            "WHERE `table_schema` = 'query' AND `table_name` = 'font'",
+            "ORDER BY `ordinal_position` ASC",
+        ].join(" ")
+    );
+    assert_eq!(
+        MysqlQueryBuilder.query_foreign_keys("query", "character").to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `kcu`.`constraint_name`, `kcu`.`column_name`, `kcu`.`referenced_table_name`, `kcu`.`referenced_column_name`, `rc`.`update_rule`, `rc`.`delete_rule`",
+            "FROM `information_schema`.`key_column_usage` AS `kcu`",
+            "JOIN `information_schema`.`referential_constraints` AS `rc`",
+            "ON (`rc`.`constraint_schema` = `kcu`.`constraint_schema`) AND (`rc`.`constraint_name` = `kcu`.`constraint_name`)",
+            "WHERE `kcu`.`table_schema` = 'query' AND `kcu`.`table_name` = 'character'",
+            "ORDER BY `kcu`.`constraint_name` ASC, `kcu`.`ordinal_position` ASC",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn discover_2() {
+    let table = MysqlQueryBuilder.parse_table(
+        "character",
+        &[
+            vec!["id".into(), "int(11)".into(), "NO".into(), Value::Null, "auto_increment".into()],
+            vec!["character".into(), "varchar(255)".into(), "NO".into(), "A".into(), "".into()],
+            vec!["price".into(), "decimal(10,2) unsigned".into(), "YES".into(), "0.5".into(), "".into()],
+            vec!["font_id".into(), "int(11)".into(), "YES".into(), Value::Null, "".into()],
+            vec!["created".into(), "timestamp".into(), "NO".into(), "CURRENT_TIMESTAMP".into(), "DEFAULT_GENERATED on update CURRENT_TIMESTAMP".into()],
+            vec!["code".into(), "char(3)".into(), "NO".into(), "123".into(), "".into()],
+            vec!["size".into(), "enum('1','2')".into(), "NO".into(), "1".into(), "".into()],
+        ],
+        &[
+            vec!["PRIMARY".into(), 0.into(), "id".into(), Value::Null, "BTREE".into()],
+            vec!["idx-character".into(), 1.into(), "character".into(), 8.into(), "BTREE".into()],
+            vec!["idx-character".into(), 1.into(), "font_id".into(), Value::Null, "BTREE".into()],
+        ],
+        &[
+            vec!["FK_font".into(), "font_id".into(), "font".into(), "id".into(), "CASCADE".into(), "RESTRICT".into()],
+        ],
+    );
+    assert_eq!(
+        table.to_create_statement().to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `character` (",
+                "`id` int(11) NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+                "`character` varchar(255) NOT NULL DEFAULT 'A',",
+                "`price` decimal(10,2) unsigned DEFAULT 0.5,",
+                "`font_id` int(11),",
+                "`created` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,",
+                "`code` char(3) NOT NULL DEFAULT '123',",
+                "`size` enum('1','2') NOT NULL DEFAULT '1',",
+                "CONSTRAINT `FK_font` FOREIGN KEY (`font_id`) REFERENCES `font` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE",
+            ")",
+        ].join(" ")
+    );
+    assert_eq!(
+        table.to_index_create_statements().iter().map(|index| index.to_string(MysqlQueryBuilder)).collect::<Vec<_>>(),
+        vec!["CREATE INDEX `idx-character` ON `character` (`character` (8), `font_id`)"]
+    );
+}
@@ -3,5 +3,7 @@ mod table;
 mod online;
 mod index;
 mod foreign_key;
+mod discovery;
+mod schema;
 
 use sea_query_test::*;
\ No newline at end of file
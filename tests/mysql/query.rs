@@ -283,7 +283,7 @@ fn select_18() {
             .and_where(Expr::col(Glyph::Aspect).between(3, 5))
             .and_where(Expr::col(Glyph::Aspect).not_between(8, 10))
             .to_string(MysqlQueryBuilder),
-        "SELECT `aspect` FROM `glyph` WHERE (`aspect` BETWEEN 3 AND 5) AND (`aspect` NOT BETWEEN 8 AND 10)"
+        "SELECT `aspect` FROM `glyph` WHERE `aspect` BETWEEN 3 AND 5 AND `aspect` NOT BETWEEN 8 AND 10"
     );
 }
 
@@ -339,7 +339,7 @@ fn select_22() {
             .or_where(Expr::col(Char::Character).like("D").and(Expr::col(Char::Character).like("E")))
             .and_where(Expr::col(Char::Character).like("F").or(Expr::col(Char::Character).like("G")))
             .to_string(MysqlQueryBuilder),
-        "SELECT `character` FROM `character` WHERE `character` LIKE 'C' OR ((`character` LIKE 'D') AND (`character` LIKE 'E')) AND ((`character` LIKE 'F') OR (`character` LIKE 'G'))"
+        "SELECT `character` FROM `character` WHERE (`character` LIKE 'C' OR (`character` LIKE 'D') AND (`character` LIKE 'E')) AND ((`character` LIKE 'F') OR (`character` LIKE 'G'))"
     );
 }
 
@@ -443,7 +443,7 @@ fn select_29() {
             .or_where(Expr::col(Char::SizeH).eq(4))
             .and_where(Expr::col(Char::SizeH).eq(5))
             .to_string(MysqlQueryBuilder),
-        "SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` = 3 OR `size_h` = 4 AND `size_h` = 5"
+        "SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w` = 3 OR `size_h` = 4) AND `size_h` = 5"
     );
 }
 
@@ -521,8 +521,7 @@ fn select_34() {
             .to_string(MysqlQueryBuilder),
         vec![
             "SELECT `aspect`, MAX(`image`) FROM `glyph` GROUP BY `aspect`",
-            "HAVING ((`aspect` > 2) OR (`aspect` < 8))",
-                "OR ((`aspect` > 12) AND (`aspect` < 18))",
+            "HAVING ((`aspect` > 2) OR (`aspect` < 8) OR (`aspect` > 12) AND (`aspect` < 18))",
                 "AND ((`aspect` > 22) OR (`aspect` < 28))",
                 "OR `aspect` > 32",
         ].join(" ")
@@ -543,6 +542,599 @@ fn select_35() {
     assert_eq!(values.0, vec![]);
 }
 
+#[test]
+fn select_36() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(
+            Expr::case(Expr::col(Glyph::Aspect).gt(2), "wide")
+                .case(Expr::col(Glyph::Aspect).is_null(), Expr::col(Glyph::Image))
+        )
+        .from(Glyph::Table)
+        .order_by_expr(
+            Expr::case(Expr::col(Glyph::Image).is_null(), 1).finally(0).into(),
+            Order::Desc
+        )
+        .build(MysqlQueryBuilder);
+
+    assert_eq!(statement, "SELECT `id`, CASE WHEN `aspect` > ? THEN ? WHEN `aspect` IS NULL THEN `image` END FROM `glyph` ORDER BY CASE WHEN `image` IS NULL THEN ? ELSE ? END DESC");
+    assert_eq!(values.0, vec![2.into(), "wide".into(), 1.into(), 0.into()]);
+}
+
+#[test]
+fn select_37() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(Expr::col(Glyph::Aspect).cast_as(ColumnType::BigInteger(None)))
+        .from(Glyph::Table)
+        .and_where(Expr::expr(Expr::col(Glyph::Image).cast_as(ColumnType::String(Some(10)))).eq("24B0E11951B03B07F8300FD003983F03F0780060"))
+        .and_where(Expr::expr(Expr::col(Glyph::Aspect).cast_as(ColumnType::Decimal(Some((8, 2))))).lt(2.5))
+        .build(MysqlQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT `id`, CAST(`aspect` AS SIGNED) FROM `glyph` WHERE CAST(`image` AS CHAR(10)) = ? AND CAST(`aspect` AS DECIMAL(8, 2)) < ?"#);
+    assert_eq!(values.0, vec!["24B0E11951B03B07F8300FD003983F03F0780060".into(), 2.5.into()]);
+}
+
+#[test]
+fn select_38() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .cond_where(
+            Cond::all()
+                .add(Expr::col(Glyph::Image).is_not_null())
+                .add(
+                    Cond::any()
+                        .add(Expr::col(Glyph::Aspect).lt(1))
+                        .add(Expr::col(Glyph::Aspect).gt(10))
+                )
+                .add(
+                    Cond::all()
+                        .add(Expr::col(Glyph::Aspect).eq(5))
+                        .add(Expr::col(Glyph::Image).like("A%"))
+                        .not()
+                )
+        )
+        .build(MysqlQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT `id` FROM `glyph` WHERE `image` IS NOT NULL AND (`aspect` < ? OR `aspect` > ?) AND NOT (`aspect` = ? AND `image` LIKE ?)"#);
+    assert_eq!(values.0, vec![1.into(), 10.into(), 5.into(), "A%".into()]);
+}
+
+#[test]
+fn select_39() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .column((Font::Table, Font::Name))
+            .from(Char::Table)
+            .left_join(
+                Font::Table,
+                Cond::all()
+                    .add(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+                    .add(
+                        Cond::any()
+                            .add(Expr::tbl(Font::Table, Font::Name).like("A%"))
+                            .add(Expr::tbl(Font::Table, Font::Name).is_null())
+                    )
+            )
+            .group_by_columns(vec![
+                Char::Character,
+            ])
+            .cond_having(
+                Cond::any()
+                    .add(Cond::all().add(Expr::col(Char::SizeW).gt(2)).not())
+                    .add(
+                        Cond::all()
+                            .add(Expr::col(Char::SizeH).eq(1))
+                            .add(Expr::col(Char::SizeW).eq(1))
+                    )
+                    .add(Cond::any())
+            )
+            .to_string(MysqlQueryBuilder),
+        vec![
+            r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id` AND (`font`.`name` LIKE 'A%' OR `font`.`name` IS NULL)"#,
+            r#"GROUP BY `character` HAVING NOT (`size_w` > 2) OR `size_h` = 1 AND `size_w` = 1 OR 1 = 2"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_40() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .or_where(Expr::col(Glyph::Aspect).lt(1))
+            .or_where(Expr::col(Glyph::Aspect).gt(10))
+            .cond_where(Expr::col(Glyph::Image).like("A%"))
+            .and_where(Expr::col(Glyph::Id).ne(5))
+            .to_string(MysqlQueryBuilder),
+        "SELECT `id` FROM `glyph` WHERE (`aspect` < 1 OR `aspect` > 10) AND `image` LIKE 'A%' AND `id` <> 5"
+    );
+}
+
+#[test]
+fn select_41() {
+    let (statement, values) = Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .and_where(Expr::exists(
+            Query::select()
+                .column(Font::Id)
+                .from(Font::Table)
+                .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+                .take()
+        ))
+        .and_where(Expr::not_exists(
+            Query::select()
+                .column(Glyph::Id)
+                .from(Glyph::Table)
+                .and_where(Expr::col(Glyph::Aspect).gt(2))
+                .take()
+        ))
+        .and_where(Expr::col(Char::SizeW).gt(
+            Query::select()
+                .expr(Expr::col(Char::SizeW).max())
+                .from(Char::Table)
+                .take()
+        ))
+        .build(MysqlQueryBuilder);
+
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT `character` FROM `character` WHERE EXISTS(SELECT `id` FROM `font` WHERE `font`.`id` = `character`.`font_id`)"#,
+            r#"AND NOT EXISTS(SELECT `id` FROM `glyph` WHERE `aspect` > ?)"#,
+            r#"AND `size_w` > (SELECT MAX(`size_w`) FROM `character`)"#,
+        ].join(" ")
+    );
+    assert_eq!(values.0, vec![2.into()]);
+}
+
+#[test]
+fn select_42() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(Expr::some(
+                Query::select().column(Font::Id).from(Font::Table).take()
+            )))
+            .and_where(Expr::col(Char::SizeW).gte(Expr::all(
+                Query::select().column(Char::SizeH).from(Char::Table).take()
+            )))
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `character` FROM `character` WHERE `font_id` = SOME(SELECT `id` FROM `font`) AND `size_w` >= ALL(SELECT `size_h` FROM `character`)"#
+    );
+}
+
+#[test]
+fn select_43() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Aspect), Expr::col(Glyph::Id)])
+                .gte(Expr::tuple(vec![Expr::val(2.5), Expr::val(10)]))
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)])
+                .in_tuples(vec![(1, "a"), (2, "b")])
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)])
+                .not_in_tuples(Vec::<(i32, &str)>::new())
+        )
+        .build(MysqlQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT `id` FROM `glyph` WHERE (`aspect`, `id`) >= (?, ?) AND (`id`, `image`) IN ((?, ?), (?, ?)) AND ? = ?"#);
+    assert_eq!(values.0, vec![2.5.into(), 10.into(), 1.into(), "a".into(), 2.into(), "b".into(), 1.into(), 1.into()]);
+}
+
+#[test]
+fn select_44() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Image).is_not_null())
+            .keyset_paginate(
+                vec![(Glyph::Aspect, Order::Desc), (Glyph::Image, Order::Asc), (Glyph::Id, Order::Asc)],
+                Some(vec![2.5.into(), "x".into(), 7.into()])
+            )
+            .limit(10)
+            .to_string(MysqlQueryBuilder),
+        vec![
+            r#"SELECT `id` FROM `glyph` WHERE `image` IS NOT NULL"#,
+            r#"AND ((`aspect` < 2.5) OR ((`aspect` = 2.5) AND ((`image` > 'x') OR ((`image` = 'x') AND (`id` > 7)))))"#,
+            r#"ORDER BY `aspect` DESC, `image` ASC, `id` ASC LIMIT 10"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .keyset_paginate(vec![(Glyph::Id, Order::Desc)], None)
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `id` FROM `glyph` ORDER BY `id` DESC"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .keyset_paginate(Vec::<(Glyph, Order)>::new(), Some(vec![]))
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `id` FROM `glyph`"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .cond_where(Cond::any().add(Expr::col(Glyph::Image).is_null()).add(Expr::col(Glyph::Aspect).gt(1)))
+            .keyset_paginate(vec![(Glyph::Id, Order::Asc)], Some(vec![7.into()]))
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `id` FROM `glyph` WHERE (`image` IS NULL OR `aspect` > 1) AND `id` > 7 ORDER BY `id` ASC"#
+    );
+}
+
+#[test]
+fn select_45() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).gt(1))
+            .order_by(Glyph::Id, Order::Asc)
+            .limit(5)
+            .lock_with_tables_behavior(LockType::Update, vec![Glyph::Table], LockBehavior::Nowait)
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `id` FROM `glyph` WHERE `aspect` > 1 ORDER BY `id` ASC LIMIT 5 FOR UPDATE OF `glyph` NOWAIT"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .lock(LockType::Share)
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `character` FROM `character` FOR SHARE"#
+    );
+}
+
+#[test]
+fn select_46() {
+    assert_eq!(
+        Query::select()
+            .column((Char::Table, Char::Character))
+            .column((Alias::new("g"), Glyph::Image))
+            .from(Char::Table)
+            .join_using(JoinType::Join, Font::Table, vec![Font::Id, Font::Name])
+            .natural_join(JoinType::InnerJoin, Alias::new("font_variant"))
+            .cross_join(Alias::new("language"))
+            .join_lateral(
+                JoinType::LeftJoin,
+                Query::select()
+                    .column(Glyph::Image)
+                    .from(Glyph::Table)
+                    .and_where(Expr::tbl(Glyph::Table, Glyph::Id).equals(Char::Table, Char::Id))
+                    .take(),
+                Alias::new("g"),
+                Cond::all()
+            )
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `character`.`character`, `g`.`image` FROM `character`",
+            "JOIN `font` USING (`id`, `name`)",
+            "NATURAL INNER JOIN `font_variant`",
+            "CROSS JOIN `language`",
+            "LEFT JOIN LATERAL (SELECT `image` FROM `glyph` WHERE `glyph`.`id` = `character`.`id`) AS `g` ON 1 = 1",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support FULL OUTER JOIN")]
+fn select_47() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .full_outer_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_48() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Char::FontId, Char::FontSize])
+            .expr(Func::grouping(vec![Expr::col(Char::FontId), Expr::col(Char::FontSize)]))
+            .expr(Func::sum(Expr::col(Char::SizeW)))
+            .from(Char::Table)
+            .group_by_rollup(vec![Char::FontId, Char::FontSize])
+            .and_having(Expr::col(Char::FontId).is_not_null())
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `font_id`, `font_size`, GROUPING(`font_id`, `font_size`), SUM(`size_w`) FROM `character`",
+            "GROUP BY `font_id`, `font_size` WITH ROLLUP",
+            "HAVING `font_id` IS NOT NULL",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql only supports a single ROLLUP as the whole GROUP BY clause")]
+fn select_49() {
+    Query::select()
+        .column(Char::FontId)
+        .from(Char::Table)
+        .group_by_columns(vec![Char::Character])
+        .group_by_rollup(vec![Char::FontId])
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support DISTINCT ON")]
+fn select_50() {
+    Query::select()
+        .distinct_on(vec![Char::FontId])
+        .column(Char::Character)
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_51() {
+    let metadata = || Expr::col((Glyph::Table, Alias::new("metadata")));
+    assert_eq!(
+        Query::select()
+            .expr(metadata().get_json_path(vec![Value::from("tags"), 0.into()]))
+            .expr(metadata().cast_json_field("font"))
+            .expr(Func::json_agg(Expr::col(Glyph::Id)))
+            .from(Glyph::Table)
+            .and_where(metadata().json_has_all_keys(vec!["size", "weight"]))
+            .and_where(metadata().json_contained_by(Expr::val(r#"{"bold": true}"#)))
+            .group_by_columns(vec![(Glyph::Table, Alias::new("metadata"))])
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT JSON_EXTRACT(`glyph`.`metadata`, '$.tags[0]'), JSON_UNQUOTE(JSON_EXTRACT(`glyph`.`metadata`, '$.font')), JSON_ARRAYAGG(`id`)",
+            "FROM `glyph`",
+            "WHERE JSON_CONTAINS_PATH(`glyph`.`metadata`, 'all', '$.size', '$.weight')",
+            "AND JSON_CONTAINS('{\\\"bold\\\": true}', `glyph`.`metadata`)",
+            "GROUP BY `glyph`.`metadata`",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support jsonb_set function")]
+fn select_52() {
+    Query::select()
+        .expr(Func::jsonb_set(Expr::col(Alias::new("metadata")), vec!["font"], Expr::val("null")))
+        .from(Glyph::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_53() {
+    let columns = || Expr::tuple(vec![Expr::col(Char::Character), Expr::col(Char::FontId)]);
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_as(columns().match_rank("sea"), Alias::new("rank"))
+            .from(Char::Table)
+            .and_where(columns().matches_boolean("+sea -orm"))
+            .order_by(Alias::new("rank"), Order::Desc)
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `id`, MATCH (`character`, `font_id`) AGAINST ('sea') AS `rank`",
+            "FROM `character`",
+            "WHERE MATCH (`character`, `font_id`) AGAINST ('+sea -orm' IN BOOLEAN MODE)",
+            "ORDER BY `rank` DESC",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_54() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::expr(Expr::col(Font::Name).concat(" ")).concat(Expr::col(Font::Variant)))
+            .expr(Expr::expr(Expr::col(Font::Id).bit_or(3)).right_shift(1))
+            .from(Font::Table)
+            .and_where(Expr::col(Font::Name).not_ilike(LikeExpr::str("a!_%").escape('!')))
+            .and_where(Expr::col(Font::Language).regexp("^en"))
+            .and_where(Expr::col(Font::Language).is_not_distinct_from(Expr::col(Font::Variant)))
+            .and_where(Expr::expr(Expr::col(Font::Id).modulo(2)).eq(1))
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT CONCAT(`name`, ' ', `variant`), (`id` | 3) >> 1 FROM `font`",
+            "WHERE LOWER(`name`) NOT LIKE LOWER('a!_%') ESCAPE '!'",
+            "AND `language` REGEXP '^en'",
+            "AND `language` <=> `variant`",
+            "AND `id` % 2 = 1",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support SIMILAR TO")]
+fn select_55() {
+    Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .and_where(Expr::col(Font::Name).similar_to("%(a|b)%"))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_56() {
+    assert_eq!(
+        Query::select()
+            .expr(-(Expr::col(Char::SizeW) + Expr::col(Char::SizeH)))
+            .expr(Expr::col(Char::SizeW) * (Expr::col(Char::SizeH) + 1) / 2)
+            .expr(Expr::col(Char::SizeW) - (Expr::col(Char::SizeH) - Expr::col(Char::FontSize)))
+            .from(Char::Table)
+            .and_where(!(Expr::col(Char::FontId).is_null() | Expr::col(Char::FontId).eq(0)))
+            .and_where((-Expr::col(Char::SizeW)).lt(0) & (Expr::col(Char::Id) % 2).eq(1))
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT - (`size_w` + `size_h`), (`size_w` * (`size_h` + 1)) / 2, `size_w` - (`size_h` - `font_size`)",
+            "FROM `character`",
+            "WHERE NOT ((`font_id` IS NULL) OR (`font_id` = 0))",
+            "AND (- `size_w` < 0) AND (`id` % 2 = 1)",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_57() {
+    let created_at = || Expr::col(Alias::new("created_at"));
+    assert_eq!(
+        Query::select()
+            .expr(Func::lower(Func::replace(Expr::col(Font::Name), " ", "_")))
+            .expr(Func::least(vec![Expr::col(Font::Id), Expr::val(1)]))
+            .expr(Func::date_trunc(DateTimeField::Minute, created_at()))
+            .expr(Func::extract(DateTimeField::Second, created_at()))
+            .from(Font::Table)
+            .and_where(Expr::expr(Func::coalesce(vec![created_at().into_simple_expr(), Func::now()])).gt(Func::current_date()))
+            .order_by_expr(Func::random(), Order::Asc)
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT LOWER(REPLACE(`name`, ' ', '_')), LEAST(`id`, 1),",
+            "CAST(DATE_FORMAT(`created_at`, '%Y-%m-%d %H:%i:00') AS DATETIME), EXTRACT(SECOND FROM `created_at`)",
+            "FROM `font`",
+            "WHERE COALESCE(`created_at`, NOW()) > CURRENT_DATE",
+            "ORDER BY RAND() ASC",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_58() {
+    assert_eq!(
+        Query::select()
+            .column(Char::FontId)
+            .expr(Func::count(Expr::col(Char::Character)).distinct())
+            .expr(Func::count(Expr::cust("*")).filter(Expr::col(Char::SizeW).gt(10)))
+            .expr(Func::string_agg(Expr::col(Char::Character), ", ").distinct().order_by(Char::SizeW, Order::Desc).order_by(Char::Id, Order::Asc))
+            .expr(Func::sum(Expr::col(Char::SizeH)).distinct().filter(Expr::col(Char::SizeW).lt(5)))
+            .from(Char::Table)
+            .group_by_columns(vec![Char::FontId])
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `font_id`, COUNT(DISTINCT `character`), COUNT(CASE WHEN `size_w` > 10 THEN 1 END),",
+            "GROUP_CONCAT(DISTINCT `character` ORDER BY `size_w` DESC, `id` ASC SEPARATOR ', '),",
+            "SUM(DISTINCT CASE WHEN `size_w` < 5 THEN `size_h` END)",
+            "FROM `character` GROUP BY `font_id`",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql only supports ORDER BY in GROUP_CONCAT among aggregate functions")]
+fn select_59() {
+    Query::select()
+        .expr(Func::json_agg(Expr::col(Char::Character)).order_by(Char::Id, Order::Asc))
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_60() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Name)
+            .from(Font::Table)
+            .add_order_by(OrderExpr::col(Font::Language, Order::Asc).nulls(NullOrdering::Last))
+            .add_order_by(OrderExpr::col(Font::Variant, Order::Asc).field(vec!["Bold".into(), "Regular".into()]).nulls(NullOrdering::First))
+            .add_order_by(OrderExpr::col(Font::Name, Order::Desc).collate(Alias::new("utf8mb4_bin")))
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "SELECT `name` FROM `font` ORDER BY ISNULL(`language`) ASC, `language` ASC,",
+            "ISNULL(`variant`) DESC, FIELD(`variant`, 'Bold', 'Regular') ASC,",
+            "`name` COLLATE `utf8mb4_bin` DESC",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_61() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Name)
+            .from(Font::Table)
+            .add_order_by(OrderExpr::col(Font::Variant, Order::Desc).field(vec!["Bold".into(), "Regular".into()]))
+            .to_string(MysqlQueryBuilder),
+        "SELECT `name` FROM `font` ORDER BY FIELD(`variant`, 'Bold', 'Regular') DESC"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Custom expression has no expression for placeholder $2")]
+fn select_62() {
+    Query::select()
+        .expr(Expr::cust_with_exprs("IFNULL($1, $2)", vec![Expr::col(Char::SizeW)]))
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_63() {
+    let (statement, values) = Query::select()
+        .expr(Expr::col(Alias::new("metadata")).cast_json_field("font"))
+        .expr(Expr::col(Alias::new("metadata")).cast_json_path(vec!["author", "name"]))
+        .from(Glyph::Table)
+        .build(MysqlQueryBuilder);
+    assert_eq!(
+        statement,
+        "SELECT JSON_UNQUOTE(JSON_EXTRACT(`metadata`, ?)), JSON_UNQUOTE(JSON_EXTRACT(`metadata`, ?)) FROM `glyph`"
+    );
+    assert_eq!(
+        values,
+        Values(vec![
+            Value::String(Box::new("$.font".to_owned())),
+            Value::String(Box::new("$.author.name".to_owned())),
+        ])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support FOR NO KEY UPDATE")]
+fn select_64() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .lock(LockType::NoKeyUpdate)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support FOR KEY SHARE")]
+fn select_65() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .lock(LockType::KeyShare)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_66() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::col(Char::SizeW) - (Expr::col(Char::SizeH) + Expr::col(Char::FontSize) + 1))
+            .from(Char::Table)
+            .and_where((Expr::col(Char::FontId) & (Expr::col(Char::SizeW).eq(1) & Expr::col(Char::SizeH).eq(2) & Expr::col(Char::Id).eq(3))).into())
+            .and_where((Expr::col(Char::FontId) | (Expr::col(Char::SizeW).eq(1) | Expr::col(Char::SizeH).eq(2))).into())
+            .to_string(MysqlQueryBuilder),
+        vec![
+            r#"SELECT `size_w` - (`size_h` + `font_size` + 1) FROM `character`"#,
+            r#"WHERE `font_id` AND ((`size_w` = 1) AND (`size_h` = 2) AND (`id` = 3))"#,
+            r#"AND (`font_id` OR ((`size_w` = 1) OR (`size_h` = 2)))"#,
+        ].join(" ")
+    );
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 #[cfg(feature="with-json")]
@@ -627,6 +1219,18 @@ fn insert_5() {
     );
 }
 
+#[test]
+fn insert_6() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .values_panic(vec!["It's a \\n\n".into(), vec![0x00u8, 0x27, 0xFF].into()])
+            .to_string(MysqlQueryBuilder),
+        "INSERT INTO `glyph` (`image`, `aspect`) VALUES ('It\\'s a \\\\n\\n', x'0027FF')"
+    );
+}
+
 #[test]
 fn update_1() {
     assert_eq!(
@@ -679,6 +1283,56 @@ fn update_3() {
     );
 }
 
+#[test]
+fn update_4() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![
+                (Glyph::Aspect, 1.into()),
+            ])
+            .or_where(Expr::col(Glyph::Id).eq(1))
+            .or_where(Expr::col(Glyph::Id).eq(2))
+            .and_where(Expr::col(Glyph::Image).is_null())
+            .to_string(MysqlQueryBuilder),
+        r#"UPDATE `glyph` SET `aspect` = 1 WHERE (`id` = 1 OR `id` = 2) AND `image` IS NULL"#
+    );
+}
+
+#[test]
+fn update_5() {
+    assert_eq!(
+        Query::update()
+            .table(Char::Table)
+            .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .left_join(Glyph::Table, Expr::tbl(Char::Table, Char::Character).equals(Glyph::Table, Glyph::Image))
+            .values(vec![
+                (Char::FontSize, 8.into()),
+                (Char::SizeW, 2.into()),
+            ])
+            .and_where(Expr::tbl(Font::Table, Font::Language).eq("en"))
+            .and_where(Expr::tbl(Glyph::Table, Glyph::Id).is_null())
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "UPDATE `character`",
+            "INNER JOIN `font` ON `character`.`font_id` = `font`.`id`",
+            "LEFT JOIN `glyph` ON `character`.`character` = `glyph`.`image`",
+            "SET `character`.`font_size` = 8, `character`.`size_w` = 2",
+            "WHERE `font`.`language` = 'en' AND `glyph`.`id` IS NULL",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support RETURNING in update and delete statements")]
+fn update_6() {
+    Query::update()
+        .table(Glyph::Table)
+        .value(Glyph::Aspect, 2.into())
+        .returning_col(Glyph::Id)
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -690,4 +1344,45 @@ fn delete_1() {
             .to_string(MysqlQueryBuilder),
         "DELETE FROM `glyph` WHERE `id` = 1 ORDER BY `id` ASC LIMIT 1"
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn delete_2() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .cond_where(Cond::all())
+            .cond_where(Cond::all().add(Expr::col(Glyph::Id).gt(10)))
+            .to_string(MysqlQueryBuilder),
+        r#"DELETE FROM `glyph` WHERE `id` > 10"#
+    );
+}
+
+#[test]
+fn delete_3() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Char::Table)
+            .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .cond_where(
+                Cond::any()
+                    .add(Expr::tbl(Font::Table, Font::Language).eq("en"))
+                    .add(Expr::tbl(Font::Table, Font::Variant).eq("Bold"))
+            )
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "DELETE `character` FROM `character`",
+            "INNER JOIN `font` ON `character`.`font_id` = `font`.`id`",
+            "WHERE `font`.`language` = 'en' OR `font`.`variant` = 'Bold'",
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support RETURNING in update and delete statements")]
+fn delete_4() {
+    Query::delete()
+        .from_table(Glyph::Table)
+        .returning_col(Glyph::Id)
+        .to_string(MysqlQueryBuilder);
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn diff_1() {
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().auto_increment().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).big_integer().not_null().auto_increment().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .to_owned();
+
+    assert_eq!(
+        diff_table(&desired, &current)
+            .unwrap()
+            .iter()
+            .map(|stat| stat.to_string(MysqlQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec!["ALTER TABLE `font` MODIFY COLUMN `id` bigint NOT NULL AUTO_INCREMENT"]
+    );
+}
@@ -203,4 +203,32 @@ fn alter_5() {
 #[should_panic(expected = "No alter option found")]
 fn alter_6() {
     Table::alter().to_string(MysqlQueryBuilder);
-}
\ No newline at end of file
+}
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Char::Table)
+            .add_column(ColumnDef::new(Alias::new("size_d")).integer())
+            .modify_column(ColumnDef::new(Char::Character).string_len(255).not_null())
+            .drop_column(Char::SizeW)
+            .add_foreign_key(
+                ForeignKey::create()
+                    .name("FK_character_font")
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+            )
+            .drop_foreign_key("FK_character_glyph")
+            .add_index(Index::create().name("idx-character-size_h").col(Char::SizeH))
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "ALTER TABLE `character`",
+                "ADD COLUMN `size_d` int,",
+                "MODIFY COLUMN `character` varchar(255) NOT NULL,",
+                "DROP COLUMN `size_w`,",
+                "ADD CONSTRAINT `FK_character_font` FOREIGN KEY (`font_id`) REFERENCES `font` (`id`),",
+                "DROP FOREIGN KEY `FK_character_glyph`,",
+                "ADD KEY `idx-character-size_h` (`size_h`)",
+        ].join(" ")
+    );
+}
@@ -0,0 +1,82 @@
+use super::*;
+use sea_query::discovery::*;
+
+#[test]
+fn discover_1() {
+    assert_eq!(
+        PostgresQueryBuilder.query_table_exists("seaql_migrations").to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "table_name" FROM "information_schema"."tables""#,
+            r#"WHERE "table_schema" = current_schema() AND "table_name" = 'seaql_migrations'"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        PostgresQueryBuilder.query_indexes("public", "character").to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "i"."relname", "ix"."indisunique", "ix"."indisprimary", "a"."attname", "am"."amname""#,
+            r#"FROM "pg_catalog"."pg_class" AS "t""#,
+            r#"JOIN "pg_catalog"."pg_index" AS "ix" ON "ix"."indrelid" = "t"."oid""#,
+            r#"JOIN "pg_catalog"."pg_class" AS "i" ON "i"."oid" = "ix"."indexrelid""#,
+            r#"JOIN "pg_catalog"."pg_am" AS "am" ON "am"."oid" = "i"."relam""#,
+            r#"JOIN "pg_catalog"."pg_namespace" AS "n" ON "n"."oid" = "t"."relnamespace""#,
+            r#"JOIN "pg_catalog"."pg_attribute" AS "a" ON ("a"."attrelid" = "t"."oid") AND "a"."attnum" = ANY("ix"."indkey")"#,
+            r#"WHERE "n"."nspname" = 'public' AND "t"."relname" = 'character'"#,
+            r#"ORDER BY "i"."relname" ASC, array_position("ix"."indkey"::int2[], "a"."attnum") ASC"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        PostgresQueryBuilder.query_foreign_keys("public", "character").to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "kcu"."constraint_name", "kcu"."column_name", "rcu"."table_name", "rcu"."column_name", "rc"."update_rule", "rc"."delete_rule""#,
+            r#"FROM "information_schema"."referential_constraints" AS "rc""#,
+            r#"JOIN "information_schema"."key_column_usage" AS "kcu""#,
+            r#"ON ("kcu"."constraint_schema" = "rc"."constraint_schema") AND ("kcu"."constraint_name" = "rc"."constraint_name")"#,
+            r#"JOIN "information_schema"."key_column_usage" AS "rcu""#,
+            r#"ON ("rcu"."constraint_schema" = "rc"."unique_constraint_schema") AND ("rcu"."constraint_name" = "rc"."unique_constraint_name")"#,
+            r#"AND ("rcu"."ordinal_position" = "kcu"."position_in_unique_constraint")"#,
+            r#"WHERE "kcu"."table_schema" = 'public' AND "kcu"."table_name" = 'character'"#,
+            r#"ORDER BY "kcu"."constraint_name" ASC, "kcu"."ordinal_position" ASC"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn discover_2() {
+    let table = PostgresQueryBuilder.parse_table(
+        "character",
+        &[
+            vec!["id".into(), "integer".into(), "int4".into(), Value::Null, 32.into(), 0.into(), "NO".into(), "nextval('character_id_seq'::regclass)".into()],
+            vec!["character".into(), "character varying".into(), "varchar".into(), 255.into(), Value::Null, Value::Null, "NO".into(), "'A'::character varying".into()],
+            vec!["price".into(), "numeric".into(), "numeric".into(), Value::Null, 10.into(), 2.into(), "YES".into(), Value::Null],
+            vec!["font_id".into(), "integer".into(), "int4".into(), Value::Null, 32.into(), 0.into(), "YES".into(), Value::Null],
+            vec!["created".into(), "timestamp without time zone".into(), "timestamp".into(), Value::Null, Value::Null, Value::Null, "NO".into(), "now()".into()],
+            vec!["mood".into(), "USER-DEFINED".into(), "mood".into(), Value::Null, Value::Null, Value::Null, "YES".into(), Value::Null],
+        ],
+        &[
+            vec!["character_pkey".into(), true.into(), true.into(), "id".into(), "btree".into()],
+            vec!["idx-character".into(), true.into(), false.into(), "character".into(), "btree".into()],
+            vec!["idx-character".into(), true.into(), false.into(), "font_id".into(), "btree".into()],
+        ],
+        &[
+            vec!["FK_font".into(), "font_id".into(), "font".into(), "id".into(), "NO ACTION".into(), "CASCADE".into()],
+        ],
+    );
+    assert_eq!(
+        table.to_create_statement().to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "character" ("#,
+                r#""id" serial NOT NULL PRIMARY KEY,"#,
+                r#""character" varchar(255) NOT NULL DEFAULT 'A',"#,
+                r#""price" decimal(10, 2),"#,
+                r#""font_id" integer,"#,
+                r#""created" timestamp NOT NULL DEFAULT now(),"#,
+                r#""mood" mood,"#,
+                r#"CONSTRAINT "FK_font" FOREIGN KEY ("font_id") REFERENCES "font" ("id") ON DELETE CASCADE ON UPDATE NO ACTION"#,
+            r#")"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        table.to_index_create_statements().iter().map(|index| index.to_string(PostgresQueryBuilder)).collect::<Vec<_>>(),
+        vec![r#"CREATE UNIQUE INDEX "idx-character" ON "character" ("character", "font_id")"#]
+    );
+}
@@ -1,7 +1,9 @@
+mod discovery;
 mod foreign_key;
 mod index;
 mod online;
 mod query;
+mod schema;
 mod table;
 mod types;
 
@@ -283,7 +283,7 @@ fn select_18() {
             .and_where(Expr::col(Glyph::Aspect).between(3, 5))
             .and_where(Expr::col(Glyph::Aspect).not_between(8, 10))
             .to_string(PostgresQueryBuilder),
-        r#"SELECT "aspect" FROM "glyph" WHERE ("aspect" BETWEEN 3 AND 5) AND ("aspect" NOT BETWEEN 8 AND 10)"#
+        r#"SELECT "aspect" FROM "glyph" WHERE "aspect" BETWEEN 3 AND 5 AND "aspect" NOT BETWEEN 8 AND 10"#
     );
 }
 
@@ -339,7 +339,7 @@ fn select_22() {
             .or_where(Expr::col(Char::Character).like("D").and(Expr::col(Char::Character).like("E")))
             .and_where(Expr::col(Char::Character).like("F").or(Expr::col(Char::Character).like("G")))
             .to_string(PostgresQueryBuilder),
-        r#"SELECT "character" FROM "character" WHERE "character" LIKE 'C' OR (("character" LIKE 'D') AND ("character" LIKE 'E')) AND (("character" LIKE 'F') OR ("character" LIKE 'G'))"#
+        r#"SELECT "character" FROM "character" WHERE ("character" LIKE 'C' OR ("character" LIKE 'D') AND ("character" LIKE 'E')) AND (("character" LIKE 'F') OR ("character" LIKE 'G'))"#
     );
 }
 
@@ -443,7 +443,7 @@ fn select_29() {
             .or_where(Expr::col(Char::SizeH).eq(4))
             .and_where(Expr::col(Char::SizeH).eq(5))
             .to_string(PostgresQueryBuilder),
-        r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" = 3 OR "size_h" = 4 AND "size_h" = 5"#
+        r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE ("size_w" = 3 OR "size_h" = 4) AND "size_h" = 5"#
     );
 }
 
@@ -521,8 +521,7 @@ fn select_34() {
             .to_string(PostgresQueryBuilder),
         vec![
             r#"SELECT "aspect", MAX("image") FROM "glyph" GROUP BY "aspect""#,
-            r#"HAVING (("aspect" > 2) OR ("aspect" < 8))"#,
-                r#"OR (("aspect" > 12) AND ("aspect" < 18))"#,
+            r#"HAVING (("aspect" > 2) OR ("aspect" < 8) OR ("aspect" > 12) AND ("aspect" < 18))"#,
                 r#"AND (("aspect" > 22) OR ("aspect" < 28))"#,
                 r#"OR "aspect" > 32"#,
         ].join(" ")
@@ -543,6 +542,632 @@ fn select_35() {
     assert_eq!(values.0, vec![]);
 }
 
+#[test]
+fn select_36() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(
+            Expr::case(Expr::col(Glyph::Aspect).gt(2), "wide")
+                .case(Expr::col(Glyph::Aspect).is_null(), Expr::col(Glyph::Image))
+        )
+        .from(Glyph::Table)
+        .order_by_expr(
+            Expr::case(Expr::col(Glyph::Image).is_null(), 1).finally(0).into(),
+            Order::Desc
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT "id", CASE WHEN "aspect" > $1 THEN $2 WHEN "aspect" IS NULL THEN "image" END FROM "glyph" ORDER BY CASE WHEN "image" IS NULL THEN $3 ELSE $4 END DESC"#);
+    assert_eq!(values.0, vec![2.into(), "wide".into(), 1.into(), 0.into()]);
+}
+
+#[test]
+fn select_37() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(Expr::col(Glyph::Aspect).cast_as(ColumnType::BigInteger(None)))
+        .from(Glyph::Table)
+        .and_where(Expr::expr(Expr::col(Glyph::Image).cast_as(ColumnType::String(Some(10)))).eq("24B0E11951B03B07F8300FD003983F03F0780060"))
+        .and_where(Expr::expr(Expr::col(Glyph::Aspect).cast_as(ColumnType::Decimal(Some((8, 2))))).lt(2.5))
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT "id", CAST("aspect" AS bigint) FROM "glyph" WHERE CAST("image" AS varchar(10)) = $1 AND CAST("aspect" AS decimal(8, 2)) < $2"#);
+    assert_eq!(values.0, vec!["24B0E11951B03B07F8300FD003983F03F0780060".into(), 2.5.into()]);
+}
+
+#[test]
+fn select_38() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .cond_where(
+            Cond::all()
+                .add(Expr::col(Glyph::Image).is_not_null())
+                .add(
+                    Cond::any()
+                        .add(Expr::col(Glyph::Aspect).lt(1))
+                        .add(Expr::col(Glyph::Aspect).gt(10))
+                )
+                .add(
+                    Cond::all()
+                        .add(Expr::col(Glyph::Aspect).eq(5))
+                        .add(Expr::col(Glyph::Image).like("A%"))
+                        .not()
+                )
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT "id" FROM "glyph" WHERE "image" IS NOT NULL AND ("aspect" < $1 OR "aspect" > $2) AND NOT ("aspect" = $3 AND "image" LIKE $4)"#);
+    assert_eq!(values.0, vec![1.into(), 10.into(), 5.into(), "A%".into()]);
+}
+
+#[test]
+fn select_39() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .column((Font::Table, Font::Name))
+            .from(Char::Table)
+            .left_join(
+                Font::Table,
+                Cond::all()
+                    .add(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+                    .add(
+                        Cond::any()
+                            .add(Expr::tbl(Font::Table, Font::Name).like("A%"))
+                            .add(Expr::tbl(Font::Table, Font::Name).is_null())
+                    )
+            )
+            .group_by_columns(vec![
+                Char::Character,
+            ])
+            .cond_having(
+                Cond::any()
+                    .add(Cond::all().add(Expr::col(Char::SizeW).gt(2)).not())
+                    .add(
+                        Cond::all()
+                            .add(Expr::col(Char::SizeH).eq(1))
+                            .add(Expr::col(Char::SizeW).eq(1))
+                    )
+                    .add(Cond::any())
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "character", "font"."name" FROM "character" LEFT JOIN "font" ON "character"."font_id" = "font"."id" AND ("font"."name" LIKE 'A%' OR "font"."name" IS NULL)"#,
+            r#"GROUP BY "character" HAVING NOT ("size_w" > 2) OR "size_h" = 1 AND "size_w" = 1 OR 1 = 2"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_40() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .or_where(Expr::col(Glyph::Aspect).lt(1))
+            .or_where(Expr::col(Glyph::Aspect).gt(10))
+            .cond_where(Expr::col(Glyph::Image).like("A%"))
+            .and_where(Expr::col(Glyph::Id).ne(5))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE ("aspect" < 1 OR "aspect" > 10) AND "image" LIKE 'A%' AND "id" <> 5"#
+    );
+}
+
+#[test]
+fn select_41() {
+    let (statement, values) = Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .and_where(Expr::exists(
+            Query::select()
+                .column(Font::Id)
+                .from(Font::Table)
+                .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+                .take()
+        ))
+        .and_where(Expr::not_exists(
+            Query::select()
+                .column(Glyph::Id)
+                .from(Glyph::Table)
+                .and_where(Expr::col(Glyph::Aspect).gt(2))
+                .take()
+        ))
+        .and_where(Expr::col(Char::SizeW).gt(
+            Query::select()
+                .expr(Expr::col(Char::SizeW).max())
+                .from(Char::Table)
+                .take()
+        ))
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT "character" FROM "character" WHERE EXISTS(SELECT "id" FROM "font" WHERE "font"."id" = "character"."font_id")"#,
+            r#"AND NOT EXISTS(SELECT "id" FROM "glyph" WHERE "aspect" > $1)"#,
+            r#"AND "size_w" > (SELECT MAX("size_w") FROM "character")"#,
+        ].join(" ")
+    );
+    assert_eq!(values.0, vec![2.into()]);
+}
+
+#[test]
+fn select_42() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(Expr::some(
+                Query::select().column(Font::Id).from(Font::Table).take()
+            )))
+            .and_where(Expr::col(Char::SizeW).gte(Expr::all(
+                Query::select().column(Char::SizeH).from(Char::Table).take()
+            )))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "character" FROM "character" WHERE "font_id" = SOME(SELECT "id" FROM "font") AND "size_w" >= ALL(SELECT "size_h" FROM "character")"#
+    );
+}
+
+#[test]
+fn select_43() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Aspect), Expr::col(Glyph::Id)])
+                .gte(Expr::tuple(vec![Expr::val(2.5), Expr::val(10)]))
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)])
+                .in_tuples(vec![(1, "a"), (2, "b")])
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)])
+                .not_in_tuples(Vec::<(i32, &str)>::new())
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT "id" FROM "glyph" WHERE ("aspect", "id") >= ($1, $2) AND ("id", "image") IN (($3, $4), ($5, $6)) AND $7 = $8"#);
+    assert_eq!(values.0, vec![2.5.into(), 10.into(), 1.into(), "a".into(), 2.into(), "b".into(), 1.into(), 1.into()]);
+}
+
+#[test]
+fn select_44() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Image).is_not_null())
+            .keyset_paginate(
+                vec![(Glyph::Aspect, Order::Desc), (Glyph::Image, Order::Asc), (Glyph::Id, Order::Asc)],
+                Some(vec![2.5.into(), "x".into(), 7.into()])
+            )
+            .limit(10)
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "id" FROM "glyph" WHERE "image" IS NOT NULL"#,
+            r#"AND (("aspect" < 2.5) OR (("aspect" = 2.5) AND (("image" > 'x') OR (("image" = 'x') AND ("id" > 7)))))"#,
+            r#"ORDER BY "aspect" DESC, "image" ASC, "id" ASC LIMIT 10"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .keyset_paginate(vec![(Glyph::Id, Order::Desc)], None)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" ORDER BY "id" DESC"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .keyset_paginate(Vec::<(Glyph, Order)>::new(), Some(vec![]))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph""#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .cond_where(Cond::any().add(Expr::col(Glyph::Image).is_null()).add(Expr::col(Glyph::Aspect).gt(1)))
+            .keyset_paginate(vec![(Glyph::Id, Order::Asc)], Some(vec![7.into()]))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE ("image" IS NULL OR "aspect" > 1) AND "id" > 7 ORDER BY "id" ASC"#
+    );
+}
+
+#[test]
+fn select_45() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).gt(1))
+            .order_by(Glyph::Id, Order::Asc)
+            .limit(5)
+            .lock_with_tables_behavior(LockType::NoKeyUpdate, vec![Glyph::Table], LockBehavior::Nowait)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE "aspect" > 1 ORDER BY "id" ASC LIMIT 5 FOR NO KEY UPDATE OF "glyph" NOWAIT"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .lock(LockType::KeyShare)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "character" FROM "character" FOR KEY SHARE"#
+    );
+}
+
+#[test]
+fn select_46() {
+    assert_eq!(
+        Query::select()
+            .column((Char::Table, Char::Character))
+            .column((Alias::new("g"), Glyph::Image))
+            .from(Char::Table)
+            .full_outer_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .join_using(JoinType::RightJoin, Alias::new("font_variant"), vec![Font::Variant])
+            .natural_join(JoinType::FullOuterJoin, Alias::new("language"))
+            .join_lateral(
+                JoinType::Join,
+                Query::select()
+                    .column(Glyph::Image)
+                    .from(Glyph::Table)
+                    .and_where(Expr::tbl(Glyph::Table, Glyph::Id).equals(Char::Table, Char::Id))
+                    .take(),
+                Alias::new("g"),
+                Expr::tbl(Alias::new("g"), Glyph::Image).is_not_null()
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "character"."character", "g"."image" FROM "character""#,
+            r#"FULL OUTER JOIN "font" ON "character"."font_id" = "font"."id""#,
+            r#"RIGHT JOIN "font_variant" USING ("variant")"#,
+            r#"NATURAL FULL OUTER JOIN "language""#,
+            r#"JOIN LATERAL (SELECT "image" FROM "glyph" WHERE "glyph"."id" = "character"."id") AS "g" ON "g"."image" IS NOT NULL"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_47() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Char::Character, Char::FontId, Char::FontSize])
+            .expr(Func::grouping(vec![Expr::col(Char::FontId)]))
+            .expr(Func::count(Expr::col(Char::Id)))
+            .from(Char::Table)
+            .group_by_columns(vec![Char::Character])
+            .group_by_rollup(vec![Char::FontId])
+            .group_by_cube(vec![(Char::Table, Char::FontSize), (Char::Table, Char::SizeW)])
+            .group_by_grouping_sets(vec![vec![Char::SizeH, Char::SizeW], vec![]])
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "character", "font_id", "font_size", GROUPING("font_id"), COUNT("id") FROM "character""#,
+            r#"GROUP BY "character", ROLLUP ("font_id"),"#,
+            r#"CUBE ("character"."font_size", "character"."size_w"),"#,
+            r#"GROUPING SETS (("size_h", "size_w"), ())"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_48() {
+    assert_eq!(
+        Query::select()
+            .distinct_on(vec![(Char::Table, Char::FontId), (Char::Table, Char::FontSize)])
+            .column((Char::Table, Char::Character))
+            .from(Char::Table)
+            .order_by((Char::Table, Char::FontId), Order::Asc)
+            .order_by((Char::Table, Char::FontSize), Order::Asc)
+            .order_by((Char::Table, Char::Id), Order::Desc)
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT DISTINCT ON ("character"."font_id", "character"."font_size") "character"."character""#,
+            r#"FROM "character""#,
+            r#"ORDER BY "character"."font_id" ASC, "character"."font_size" ASC, "character"."id" DESC"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_49() {
+    let metadata = || Expr::col((Glyph::Table, Alias::new("metadata")));
+    assert_eq!(
+        Query::select()
+            .expr(metadata().get_json_field("font"))
+            .expr(metadata().cast_json_path(vec!["tags", "0"]))
+            .expr(Func::json_agg(Expr::col(Glyph::Id)))
+            .from(Glyph::Table)
+            .and_where(metadata().json_has_key("font"))
+            .and_where(metadata().json_has_any_keys(vec!["size", "weight"]))
+            .and_where(metadata().json_contains(Expr::val(r#"{"bold": true}"#)))
+            .group_by_columns(vec![(Glyph::Table, Alias::new("metadata"))])
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "glyph"."metadata" -> 'font', "glyph"."metadata" #>> ARRAY['tags', '0'], json_agg("id")"#,
+            r#"FROM "glyph""#,
+            r#"WHERE "glyph"."metadata" ? 'font'"#,
+            r#"AND "glyph"."metadata" ?| ARRAY['size', 'weight']"#,
+            r#"AND "glyph"."metadata" @> '{"bold": true}'"#,
+            r#"GROUP BY "glyph"."metadata""#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_50() {
+    let (statement, values) = Query::select()
+        .expr(Func::jsonb_set(
+            Expr::col(Alias::new("metadata")),
+            vec!["font", "family \"sans\""],
+            Expr::val(r#""Arial""#),
+        ))
+        .from(Glyph::Table)
+        .and_where(Expr::col(Alias::new("metadata")).json_has_all_keys(vec!["font", "size"]))
+        .build(PostgresQueryBuilder);
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT jsonb_set("metadata", $1, $2) FROM "glyph""#,
+            r#"WHERE "metadata" ?& ARRAY[$3, $4]"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            Value::String(Box::new(r#"{"font","family \"sans\""}"#.to_owned())),
+            Value::String(Box::new(r#""Arial""#.to_owned())),
+            Value::String(Box::new("font".to_owned())),
+            Value::String(Box::new("size".to_owned())),
+        ]
+    );
+}
+
+#[test]
+fn select_51() {
+    let columns = || Expr::tuple(vec![Expr::col(Char::Character), Expr::col(Char::FontId)]);
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_as(columns().match_rank("sea"), Alias::new("rank"))
+            .from(Char::Table)
+            .and_where(columns().matches_boolean("sea \"query builder\" -orm"))
+            .order_by(Alias::new("rank"), Order::Desc)
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "id", ts_rank(to_tsvector(concat_ws(' ', "character", "font_id")), plainto_tsquery('sea')) AS "rank""#,
+            r#"FROM "character""#,
+            r#"WHERE to_tsvector(concat_ws(' ', "character", "font_id")) @@ websearch_to_tsquery('sea "query builder" -orm')"#,
+            r#"ORDER BY "rank" DESC"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_52() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::expr(Expr::col(Font::Name).concat(" ")).concat(Expr::col(Font::Variant)))
+            .expr(Expr::expr(Expr::col(Font::Id).bit_and(3)).left_shift(1))
+            .from(Font::Table)
+            .and_where(Expr::col(Font::Name).ilike(LikeExpr::str("a!_%").escape('!')))
+            .and_where(Expr::col(Font::Variant).not_similar_to("%(bold|italic)%"))
+            .and_where(Expr::col(Font::Language).not_regexp("^en"))
+            .and_where(Expr::col(Font::Language).is_distinct_from(Expr::col(Font::Variant)))
+            .and_where(Expr::expr(Expr::col(Font::Id).modulo(2)).eq(1))
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "name" || ' ' || "variant", ("id" & 3) << 1 FROM "font""#,
+            r#"WHERE "name" ILIKE 'a!_%' ESCAPE '!'"#,
+            r#"AND "variant" NOT SIMILAR TO '%(bold|italic)%'"#,
+            r#"AND "language" !~ '^en'"#,
+            r#"AND "language" IS DISTINCT FROM "variant""#,
+            r#"AND "id" % 2 = 1"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_53() {
+    assert_eq!(
+        Query::select()
+            .expr(-(Expr::col(Char::SizeW) + Expr::col(Char::SizeH)))
+            .expr(Expr::col(Char::SizeW) * (Expr::col(Char::SizeH) + 1) / 2)
+            .expr(Expr::col(Char::SizeW) - (Expr::col(Char::SizeH) - Expr::col(Char::FontSize)))
+            .from(Char::Table)
+            .and_where(!(Expr::col(Char::FontId).is_null() | Expr::col(Char::FontId).eq(0)))
+            .and_where((-Expr::col(Char::SizeW)).lt(0) & (Expr::col(Char::Id) % 2).eq(1))
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT - ("size_w" + "size_h"), ("size_w" * ("size_h" + 1)) / 2, "size_w" - ("size_h" - "font_size")"#,
+            r#"FROM "character""#,
+            r#"WHERE NOT (("font_id" IS NULL) OR ("font_id" = 0))"#,
+            r#"AND (- "size_w" < 0) AND ("id" % 2 = 1)"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_54() {
+    let created_at = || Expr::col(Alias::new("created_at"));
+    assert_eq!(
+        Query::select()
+            .expr(Func::upper(Func::trim(Expr::col(Font::Name))))
+            .expr(Func::substring(Func::concat(vec![Expr::col(Font::Name), Expr::col(Font::Variant)]), 1, None))
+            .expr(Func::round(Func::abs(Expr::col(Font::Id)), None))
+            .expr(Func::date_trunc(DateTimeField::Day, created_at()))
+            .expr(Func::extract(DateTimeField::Hour, created_at()))
+            .from(Font::Table)
+            .and_where(Expr::expr(Func::greatest(vec![Expr::col(Font::Id), Expr::val(1)])).lt(Func::random()))
+            .and_where(Expr::expr(Func::coalesce(vec![created_at().into_simple_expr(), Func::now()])).gt(Func::current_date()))
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT UPPER(TRIM("name")), SUBSTRING(CONCAT("name", "variant"), 1), ROUND(ABS("id")),"#,
+            r#"DATE_TRUNC('day', "created_at"), EXTRACT(HOUR FROM "created_at")"#,
+            r#"FROM "font""#,
+            r#"WHERE GREATEST("id", 1) < RANDOM()"#,
+            r#"AND COALESCE("created_at", NOW()) > CURRENT_DATE"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_55() {
+    assert_eq!(
+        Query::select()
+            .column(Char::FontId)
+            .expr(Func::count(Expr::col(Char::Character)).distinct())
+            .expr(Func::count(Expr::cust("*")).filter(Expr::col(Char::SizeW).gt(10)))
+            .expr(Func::string_agg(Expr::col(Char::Character), ", ").order_by(Char::SizeW, Order::Desc).order_by(Char::Id, Order::Asc))
+            .expr(Func::array_agg(Expr::col(Char::Id)).filter(Expr::col(Char::SizeH).lt(5)))
+            .from(Char::Table)
+            .group_by_columns(vec![Char::FontId])
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "font_id", COUNT(DISTINCT "character"), COUNT(*) FILTER (WHERE "size_w" > 10),"#,
+            r#"STRING_AGG("character", ', ' ORDER BY "size_w" DESC, "id" ASC),"#,
+            r#"ARRAY_AGG("id") FILTER (WHERE "size_h" < 5)"#,
+            r#"FROM "character" GROUP BY "font_id""#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_56() {
+    let (statement, values) = Query::select()
+        .column(Font::Name)
+        .from(Font::Table)
+        .add_order_by(OrderExpr::col(Font::Language, Order::Asc).nulls(NullOrdering::First))
+        .add_order_by(OrderExpr::col(Font::Variant, Order::Asc).field(vec!["Bold".into(), "Regular".into()]).collate(Alias::new("C")))
+        .order_by(Font::Id, Order::Desc)
+        .build(PostgresQueryBuilder);
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT "name" FROM "font" ORDER BY "language" ASC NULLS FIRST,"#,
+            r#"CASE WHEN "variant" COLLATE "C" = $1 THEN 1 WHEN "variant" COLLATE "C" = $2 THEN 2 ELSE 0 END ASC,"#,
+            r#""id" DESC"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        values,
+        Values(vec![Value::String(Box::new("Bold".to_owned())), Value::String(Box::new("Regular".to_owned()))])
+    );
+}
+
+#[test]
+fn select_57() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(cust_with_exprs!(
+            "ARRAY[$1, $2] || $3",
+            Expr::col(Glyph::Aspect),
+            Expr::col(Glyph::Aspect).mul(2),
+            Query::select().expr(Func::array_agg(Expr::col(Glyph::Aspect))).from(Glyph::Table).to_owned(),
+        ))
+        .from(Glyph::Table)
+        .and_where(Expr::expr(Expr::cust_with_exprs("ST_Distance($1, $2)", vec![Expr::col(Glyph::Image), Expr::val("POINT(0 0)")])).lt(10))
+        .and_where(Expr::cust_with_exprs("'$1' <> $1", vec![Expr::val("$2")]))
+        .build(PostgresQueryBuilder);
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT "id", ARRAY["aspect", ("aspect" * $1)] || (SELECT ARRAY_AGG("aspect") FROM "glyph")"#,
+            r#"FROM "glyph""#,
+            r#"WHERE ST_Distance("image", $2) < $3 AND '$1' <> $4"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        values,
+        Values(vec![
+            Value::Int(2),
+            Value::String(Box::new("POINT(0 0)".to_owned())),
+            Value::Int(10),
+            Value::String(Box::new("$2".to_owned())),
+        ])
+    );
+}
+
+#[test]
+fn select_58() {
+    let (statement, values) = Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .and_where(Expr::col(Char::FontId).eq_any(
+            Query::select().column(Font::Id).from(Font::Table).and_where(Expr::col(Font::Language).eq("en")).take()
+        ))
+        .and_where(Expr::col(Char::SizeW).eq_all(
+            Query::select().column(Char::SizeH).from(Char::Table).and_where(Expr::col(Char::SizeH).gt(2)).take()
+        ))
+        .build(PostgresQueryBuilder);
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT "character" FROM "character""#,
+            r#"WHERE "font_id" = ANY(SELECT "id" FROM "font" WHERE "language" = $1)"#,
+            r#"AND "size_w" = ALL(SELECT "size_h" FROM "character" WHERE "size_h" > $2)"#,
+        ].join(" ")
+    );
+    assert_eq!(values.0, vec!["en".into(), 2.into()]);
+}
+
+#[test]
+fn select_59() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::col(Char::SizeW) - (Expr::col(Char::SizeH) + Expr::col(Char::FontSize) + 1))
+            .from(Char::Table)
+            .and_where((Expr::col(Char::FontId) & (Expr::col(Char::SizeW).eq(1) & Expr::col(Char::SizeH).eq(2) & Expr::col(Char::Id).eq(3))).into())
+            .and_where((Expr::col(Char::FontId) | (Expr::col(Char::SizeW).eq(1) | Expr::col(Char::SizeH).eq(2))).into())
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"SELECT "size_w" - ("size_h" + "font_size" + 1) FROM "character""#,
+            r#"WHERE "font_id" AND (("size_w" = 1) AND ("size_h" = 2) AND ("id" = 3))"#,
+            r#"AND ("font_id" OR (("size_w" = 1) OR ("size_h" = 2)))"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "CROSS JOIN does not take a join condition, use `cross_join` instead")]
+fn select_60() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .join(JoinType::CrossJoin, Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id));
+}
+
+#[test]
+#[should_panic(expected = "CROSS JOIN can not be natural")]
+fn select_61() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .natural_join(JoinType::CrossJoin, Font::Table);
+}
+
+#[test]
+#[should_panic(expected = "ROLLUP requires at least one column")]
+fn select_62() {
+    Query::select()
+        .column(Char::FontId)
+        .from(Char::Table)
+        .group_by_rollup(Vec::<Char>::new());
+}
+
+#[test]
+#[should_panic(expected = "CUBE requires at least one column")]
+fn select_63() {
+    Query::select()
+        .column(Char::FontId)
+        .from(Char::Table)
+        .group_by_cube(Vec::<Char>::new());
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 #[cfg(feature="with-json")]
@@ -627,6 +1252,19 @@ fn insert_5() {
     );
 }
 
+#[test]
+fn insert_6() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .values_panic(vec!["It's".into(), "a \\n\n".into()])
+            .values_panic(vec!["It's a \\".into(), vec![0x00u8, 0x27, 0xFF].into()])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\", \"aspect\") VALUES ('It''s', E'a \\\\n\n'), (E'It''s a \\\\', E'\\\\x0027FF')"
+    );
+}
+
 #[test]
 fn update_1() {
     assert_eq!(
@@ -673,6 +1311,74 @@ fn update_3() {
     );
 }
 
+#[test]
+fn update_4() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![
+                (Glyph::Aspect, 1.into()),
+            ])
+            .or_where(Expr::col(Glyph::Id).eq(1))
+            .or_where(Expr::col(Glyph::Id).eq(2))
+            .and_where(Expr::col(Glyph::Image).is_null())
+            .to_string(PostgresQueryBuilder),
+        r#"UPDATE "glyph" SET "aspect" = 1 WHERE ("id" = 1 OR "id" = 2) AND "image" IS NULL"#
+    );
+}
+
+#[test]
+fn update_5() {
+    assert_eq!(
+        Query::update()
+            .table(Char::Table)
+            .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .inner_join(Glyph::Table, Expr::tbl(Char::Table, Char::Character).equals(Glyph::Table, Glyph::Image))
+            .value(Char::FontSize, 8.into())
+            .or_where(Expr::tbl(Font::Table, Font::Language).eq("en"))
+            .or_where(Expr::tbl(Glyph::Table, Glyph::Aspect).gt(2))
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"UPDATE "character" SET "font_size" = 8"#,
+            r#"FROM "font", "glyph""#,
+            r#"WHERE "character"."font_id" = "font"."id""#,
+            r#"AND "character"."character" = "glyph"."image""#,
+            r#"AND ("font"."language" = 'en' OR "glyph"."aspect" > 2)"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres only supports inner joins in update and delete statements")]
+fn update_6() {
+    Query::update()
+        .table(Char::Table)
+        .left_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+        .value(Char::FontSize, 8.into())
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn update_7() {
+    assert_eq!(
+        Query::update()
+            .table(Char::Table)
+            .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .value(Char::FontSize, 8.into())
+            .returning(Query::select()
+                .column((Char::Table, Char::Id))
+                .expr_as(Expr::tbl(Font::Table, Font::Name), Alias::new("font"))
+                .take()
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"UPDATE "character" SET "font_size" = 8"#,
+            r#"FROM "font" WHERE "character"."font_id" = "font"."id""#,
+            r#"RETURNING "character"."id", "font"."name" AS "font""#,
+        ].join(" ")
+    );
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -682,4 +1388,43 @@ fn delete_1() {
             .to_string(PostgresQueryBuilder),
         r#"DELETE FROM "glyph" WHERE "id" = 1"#
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn delete_2() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .cond_where(Cond::all())
+            .cond_where(Cond::all().add(Expr::col(Glyph::Id).gt(10)))
+            .to_string(PostgresQueryBuilder),
+        r#"DELETE FROM "glyph" WHERE "id" > 10"#
+    );
+}
+
+#[test]
+fn delete_3() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Char::Table)
+            .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"DELETE FROM "character""#,
+            r#"USING "font""#,
+            r#"WHERE "character"."font_id" = "font"."id""#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn delete_4() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).lt(1))
+            .returning(Query::select().columns(vec![Glyph::Id, Glyph::Image]).take())
+            .to_string(PostgresQueryBuilder),
+        r#"DELETE FROM "glyph" WHERE "aspect" < 1 RETURNING "id", "image""#
+    );
+}
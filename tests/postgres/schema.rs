@@ -0,0 +1,147 @@
+use super::*;
+
+#[test]
+fn diff_1() {
+    let current = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().auto_increment().primary_key())
+        .col(ColumnDef::new(Char::Character).string().not_null())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .col(ColumnDef::new(Char::SizeW).integer())
+        .index(Index::create().name("idx-character-size_w").col(Char::SizeW))
+        .foreign_key(
+            ForeignKey::create()
+                .name("FK_character_font")
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+        )
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key().auto_increment())
+        .col(ColumnDef::new(Char::Character).string().not_null())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .col(ColumnDef::new(Char::SizeH).integer())
+        .index(Index::create().name("idx-character-font_id").col(Char::FontId))
+        .foreign_key(
+            ForeignKey::create()
+                .name("FK_character_font")
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id)
+                .on_delete(ForeignKeyAction::SetNull)
+        )
+        .to_owned();
+
+    assert_eq!(
+        diff_table(&desired, &current)
+            .unwrap()
+            .iter()
+            .map(|stat| stat.to_string(PostgresQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec![
+            r#"ALTER TABLE "character" DROP CONSTRAINT "FK_character_font""#,
+            r#"DROP INDEX "idx-character-size_w""#,
+            r#"ALTER TABLE "character" DROP COLUMN "size_w", ADD COLUMN "size_h" integer"#,
+            r#"CREATE INDEX "idx-character-font_id" ON "character" ("font_id")"#,
+            r#"ALTER TABLE "character" ADD CONSTRAINT "FK_character_font" FOREIGN KEY ("font_id") REFERENCES "font" ("id") ON DELETE SET NULL"#,
+        ]
+    );
+}
+
+#[test]
+fn diff_2() {
+    let table = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string_len(255).not_null())
+        .index(Index::create().name("idx-font-name").col(Font::Name))
+        .to_owned();
+
+    assert!(diff_table(&table, &table).unwrap().is_empty());
+}
+
+#[test]
+fn diff_3() {
+    let current = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .index(Index::create().col(Char::FontId))
+        .foreign_key(ForeignKey::create().from(Char::Table, Char::FontId).to(Font::Table, Font::Id))
+        .to_owned();
+
+    let mut desired = current.clone();
+    assert!(diff_table(&desired, &current).unwrap().is_empty());
+
+    desired.index(Index::create().col(Char::Id).col(Char::FontId));
+    assert_eq!(
+        diff_table(&desired, &current)
+            .unwrap()
+            .iter()
+            .map(|stat| stat.to_string(PostgresQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec![r#"CREATE INDEX ON "character" ("id", "font_id")"#]
+    );
+}
+
+#[test]
+fn diff_4() {
+    let current = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer())
+        .index(Index::create().col(Char::FontId))
+        .to_owned();
+    let desired = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer())
+        .to_owned();
+
+    assert_eq!(
+        diff_table(&desired, &current).unwrap_err().to_string(),
+        "Cannot drop the unnamed index on (font_id) of table `character`"
+    );
+}
+
+#[test]
+fn diff_5() {
+    let current = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer())
+        .foreign_key(ForeignKey::create().from(Char::Table, Char::FontId).to(Font::Table, Font::Id))
+        .to_owned();
+    let desired = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer())
+        .to_owned();
+
+    assert_eq!(
+        diff_table(&desired, &current).unwrap_err().to_string(),
+        "Cannot drop the unnamed foreign key on (font_id) of table `character`"
+    );
+}
+
+#[test]
+fn diff_6() {
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).big_integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .to_owned();
+
+    assert_eq!(
+        diff_table(&desired, &current)
+            .unwrap()
+            .iter()
+            .map(|stat| stat.to_string(PostgresQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec![r#"ALTER TABLE "font" ALTER COLUMN "id" TYPE bigint, ALTER COLUMN "id" SET NOT NULL"#]
+    );
+}
@@ -208,4 +208,58 @@ fn alter_5() {
 #[should_panic(expected = "No alter option found")]
 fn alter_6() {
     Table::alter().to_string(PostgresQueryBuilder);
-}
\ No newline at end of file
+}
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Char::Table)
+            .add_column(ColumnDef::new(Alias::new("size_d")).integer())
+            .drop_column(Char::SizeW)
+            .add_foreign_key(
+                ForeignKey::create()
+                    .name("FK_character_font")
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+            )
+            .drop_foreign_key("FK_character_glyph")
+            .add_index(Index::create().name("idx-character-font_id-character").col(Char::FontId).col(Char::Character).unique())
+            .add_index(Index::create().col(Char::Id).primary())
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"ALTER TABLE "character""#,
+                r#"ADD COLUMN "size_d" integer,"#,
+                r#"DROP COLUMN "size_w","#,
+                r#"ADD CONSTRAINT "FK_character_font" FOREIGN KEY ("font_id") REFERENCES "font" ("id"),"#,
+                r#"DROP CONSTRAINT "FK_character_glyph","#,
+                r#"ADD CONSTRAINT "idx-character-font_id-character" UNIQUE ("font_id", "character"),"#,
+                r#"ADD PRIMARY KEY ("id")"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn alter_8() {
+    let alter = Table::alter()
+        .table(Char::Table)
+        .add_index(Index::create().name("idx-character-size_h").col(Char::SizeH))
+        .add_column(ColumnDef::new(Alias::new("size_d")).integer())
+        .add_index(Index::create().name("idx-character-size_d").col(Alias::new("size_d")))
+        .to_owned();
+    assert_eq!(
+        alter.to_string(PostgresQueryBuilder),
+        vec![
+            r#"ALTER TABLE "character" ADD COLUMN "size_d" integer;"#,
+            r#"CREATE INDEX "idx-character-size_h" ON "character" ("size_h");"#,
+            r#"CREATE INDEX "idx-character-size_d" ON "character" ("size_d")"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        alter.build_statements(PostgresQueryBuilder),
+        vec![
+            r#"ALTER TABLE "character" ADD COLUMN "size_d" integer"#,
+            r#"CREATE INDEX "idx-character-size_h" ON "character" ("size_h")"#,
+            r#"CREATE INDEX "idx-character-size_d" ON "character" ("size_d")"#,
+        ]
+    );
+}
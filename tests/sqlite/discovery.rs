@@ -0,0 +1,115 @@
+use super::*;
+use sea_query::discovery::*;
+use rusqlite::{Connection, types::ValueRef};
+
+fn query_rows(conn: &Connection, select: &SelectStatement) -> Vec<Vec<Value>> {
+    let sql = select.to_string(SqliteQueryBuilder);
+    let mut stmt = conn.prepare(&sql).unwrap();
+    let column_count = stmt.column_count();
+    let mut rows = stmt.query([]).unwrap();
+    let mut result = Vec::new();
+    while let Some(row) = rows.next().unwrap() {
+        result.push((0..column_count).map(|i| match row.get_ref(i).unwrap() {
+            ValueRef::Null => Value::Null,
+            ValueRef::Integer(v) => Value::BigInt(v),
+            ValueRef::Real(v) => Value::Double(v),
+            ValueRef::Text(v) => String::from_utf8_lossy(v).into_owned().into(),
+            ValueRef::Blob(v) => v.to_vec().into(),
+        }).collect());
+    }
+    result
+}
+
+#[test]
+fn discover_1() {
+    assert_eq!(
+        SqliteQueryBuilder.query_table_exists("seaql_migrations").to_string(SqliteQueryBuilder),
+        "SELECT `name` FROM `sqlite_master` WHERE `type` = 'table' AND `name` = 'seaql_migrations'"
+    );
+    assert_eq!(
+        SqliteQueryBuilder.query_columns("main", "font").to_string(SqliteQueryBuilder),
+        vec![
+            "SELECT `name`, `type`, `notnull`, `dflt_value`, `pk`,",
+            "(SELECT `sql` FROM `sqlite_master` WHERE `type` = 'table' AND `name` = 'font')",
+            "FROM pragma_table_info('font') ORDER BY `cid` ASC",
+        ].join(" ")
+    );
+    assert_eq!(
+        SqliteQueryBuilder.query_indexes("main", "font").to_string(SqliteQueryBuilder),
+        vec![
+            "SELECT `il`.`name`, `il`.`unique`, `il`.`origin`, `ii`.`name`",
+            "FROM pragma_index_list('font') AS `il` JOIN pragma_index_info(`il`.`name`) AS `ii`",
+            "ORDER BY `il`.`seq` ASC, `ii`.`seqno` ASC",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn discover_2() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null().primary_key().auto_increment())
+            .col(ColumnDef::new(Font::Name).string_len(255).not_null().unique_key())
+            .col(ColumnDef::new(Font::Variant).text().default("Regular"))
+            .col(ColumnDef::new(Font::Language).text())
+            .to_string(SqliteQueryBuilder),
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null())
+            .col(ColumnDef::new(Char::FontId).integer())
+            .col(ColumnDef::new(Char::Character).text().not_null())
+            .primary_key(Index::create().col(Char::Id).col(Char::Character))
+            .foreign_key(
+                ForeignKey::create()
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+            )
+            .to_string(SqliteQueryBuilder),
+        Index::create()
+            .name("idx-character-font_id")
+            .table(Char::Table)
+            .col(Char::FontId)
+            .to_string(SqliteQueryBuilder),
+    ].join("; ")).unwrap();
+
+    let tables = SqliteQueryBuilder.discover("main", |select| query_rows(&conn, select));
+    assert_eq!(
+        tables.iter().map(|table| table.get_table_name()).collect::<Vec<_>>(),
+        vec!["character", "font"]
+    );
+
+    let character = &tables[0];
+    assert_eq!(
+        character.to_create_statement().to_string(SqliteQueryBuilder),
+        vec![
+            "CREATE TABLE `character` (",
+                "`id` integer NOT NULL,",
+                "`font_id` integer,",
+                "`character` text NOT NULL,",
+                "PRIMARY KEY (`id`, `character`),",
+                "FOREIGN KEY (`font_id`) REFERENCES `font` (`id`) ON DELETE CASCADE ON UPDATE NO ACTION",
+            ")",
+        ].join(" ")
+    );
+    assert_eq!(
+        character.to_index_create_statements().iter().map(|index| index.to_string(SqliteQueryBuilder)).collect::<Vec<_>>(),
+        vec!["CREATE INDEX `idx-character-font_id` ON `character` (`font_id`)"]
+    );
+
+    let font = &tables[1];
+    assert_eq!(
+        font.to_create_statement().to_string(SqliteQueryBuilder),
+        vec![
+            "CREATE TABLE `font` (",
+                "`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,",
+                "`name` text(255) NOT NULL UNIQUE,",
+                "`variant` text DEFAULT 'Regular',",
+                "`language` text",
+            ")",
+        ].join(" ")
+    );
+    assert!(font.to_index_create_statements().is_empty());
+}
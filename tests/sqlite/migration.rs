@@ -0,0 +1,292 @@
+use super::*;
+use sea_query::migration::*;
+use rusqlite::Connection;
+
+sea_query::sea_query_driver_rusqlite!();
+use sea_query_driver_rusqlite::RusqliteConnection;
+
+struct CreateFont;
+
+impl Migration for CreateFont {
+    fn name(&self) -> &str {
+        "m20210601_000001_create_font"
+    }
+
+    fn up(&self) -> Vec<MigrationStatement> {
+        vec![
+            Table::create()
+                .table(Font::Table)
+                .col(ColumnDef::new(Font::Id).integer().not_null().primary_key().auto_increment())
+                .col(ColumnDef::new(Font::Name).string().not_null())
+                .to_owned()
+                .into(),
+            Query::insert()
+                .into_table(Font::Table)
+                .columns(vec![Font::Name])
+                .values_panic(vec!["Arial".into()])
+                .to_owned()
+                .into(),
+        ]
+    }
+
+    fn down(&self) -> Vec<MigrationStatement> {
+        vec![Table::drop().table(Font::Table).to_owned().into()]
+    }
+}
+
+struct AddFontVariant;
+
+impl Migration for AddFontVariant {
+    fn name(&self) -> &str {
+        "m20210601_000002_add_font_variant"
+    }
+
+    fn up(&self) -> Vec<MigrationStatement> {
+        vec![
+            Table::alter()
+                .table(Font::Table)
+                .add_column(ColumnDef::new(Font::Variant).text())
+                .add_column(ColumnDef::new(Font::Language).text())
+                .to_owned()
+                .into(),
+        ]
+    }
+
+    fn down(&self) -> Vec<MigrationStatement> {
+        vec![
+            Table::create()
+                .table(Alias::new("font_new"))
+                .col(ColumnDef::new(Font::Id).integer().not_null().primary_key().auto_increment())
+                .col(ColumnDef::new(Font::Name).string().not_null())
+                .to_owned()
+                .into(),
+            Table::drop().table(Font::Table).to_owned().into(),
+            Table::rename().table(Alias::new("font_new"), Font::Table).to_owned().into(),
+        ]
+    }
+}
+
+struct Broken;
+
+impl Migration for Broken {
+    fn name(&self) -> &str {
+        "m20210601_000003_broken"
+    }
+
+    fn up(&self) -> Vec<MigrationStatement> {
+        vec![
+            Table::create()
+                .table(Glyph::Table)
+                .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+                .to_owned()
+                .into(),
+            Table::alter()
+                .table(Char::Table)
+                .add_column(ColumnDef::new(Char::FontSize).integer())
+                .to_owned()
+                .into(),
+        ]
+    }
+
+    fn down(&self) -> Vec<MigrationStatement> {
+        vec![]
+    }
+}
+
+struct AddCharacterFontKey;
+
+impl AddCharacterFontKey {
+    fn character() -> TableCreateStatement {
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::FontId).integer())
+            .to_owned()
+    }
+}
+
+impl Migration for AddCharacterFontKey {
+    fn name(&self) -> &str {
+        "m20210601_000004_add_character_font_key"
+    }
+
+    fn up(&self) -> Vec<MigrationStatement> {
+        SqliteQueryBuilder.rebuild_foreign_key_create(
+            &ForeignKey::create()
+                .name("FK_character_font")
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id),
+            &Self::character(),
+            &[],
+            &[]
+        ).into_iter().map(MigrationStatement::from).collect()
+    }
+
+    fn down(&self) -> Vec<MigrationStatement> {
+        vec![]
+    }
+
+    fn foreign_keys_off(&self) -> bool {
+        true
+    }
+}
+
+/// Connection whose statements, and rollback, fail after the history table is created
+struct FailingConnection {
+    executed: Vec<String>,
+}
+
+impl MigrationConnection for FailingConnection {
+    type Error = String;
+
+    fn execute(&mut self, sql: &str, _values: Values) -> Result<(), Self::Error> {
+        self.executed.push(sql.to_owned());
+        match sql {
+            "BEGIN" => Ok(()),
+            "ROLLBACK" => Err("cannot rollback".to_owned()),
+            _ if sql.starts_with("CREATE TABLE IF NOT EXISTS") => Ok(()),
+            _ => Err(format!("cannot execute {}", sql)),
+        }
+    }
+
+    fn query(&mut self, _sql: &str, _values: Values) -> Result<Vec<Vec<Value>>, Self::Error> {
+        Ok(Vec::new())
+    }
+}
+
+fn status(migrator: &Migrator<SqliteQueryBuilder>, conn: &Connection) -> Vec<(String, bool)> {
+    migrator.status(&mut RusqliteConnection(conn)).unwrap()
+        .into_iter()
+        .map(|status| (status.name, status.applied))
+        .collect()
+}
+
+#[test]
+fn migrate_1() {
+    let conn = Connection::open_in_memory().unwrap();
+    let migrator = Migrator::new(SqliteQueryBuilder)
+        .migration(CreateFont)
+        .migration(AddFontVariant);
+
+    assert_eq!(
+        status(&migrator, &conn),
+        vec![
+            ("m20210601_000001_create_font".to_owned(), false),
+            ("m20210601_000002_add_font_variant".to_owned(), false),
+        ]
+    );
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `sqlite_master` WHERE `name` = 'seaql_migrations'", [], |row| row.get(0)),
+        Ok(0)
+    );
+
+    assert_eq!(
+        migrator.up(&mut RusqliteConnection(&conn), Some(1)).unwrap(),
+        vec!["m20210601_000001_create_font"]
+    );
+    assert_eq!(
+        migrator.up(&mut RusqliteConnection(&conn), None).unwrap(),
+        vec!["m20210601_000002_add_font_variant"]
+    );
+    assert!(migrator.up(&mut RusqliteConnection(&conn), None).unwrap().is_empty());
+    assert_eq!(
+        status(&migrator, &conn),
+        vec![
+            ("m20210601_000001_create_font".to_owned(), true),
+            ("m20210601_000002_add_font_variant".to_owned(), true),
+        ]
+    );
+    assert_eq!(
+        conn.query_row("SELECT `name`, `variant`, `language` FROM `font`", [], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?))),
+        Ok(("Arial".to_owned(), None::<String>, None::<String>))
+    );
+
+    assert_eq!(
+        migrator.down(&mut RusqliteConnection(&conn), Some(1)).unwrap(),
+        vec!["m20210601_000002_add_font_variant"]
+    );
+    assert_eq!(
+        migrator.down(&mut RusqliteConnection(&conn), None).unwrap(),
+        vec!["m20210601_000001_create_font"]
+    );
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `sqlite_master` WHERE `name` = 'font'", [], |row| row.get(0)),
+        Ok(0)
+    );
+}
+
+#[test]
+fn migrate_2() {
+    let conn = Connection::open_in_memory().unwrap();
+    let migrator = Migrator::new(SqliteQueryBuilder)
+        .table(Alias::new("migration_history"))
+        .migration(CreateFont)
+        .migration(Broken);
+
+    assert!(migrator.up(&mut RusqliteConnection(&conn), None).is_err());
+    assert_eq!(
+        status(&migrator, &conn),
+        vec![
+            ("m20210601_000001_create_font".to_owned(), true),
+            ("m20210601_000003_broken".to_owned(), false),
+        ]
+    );
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `sqlite_master` WHERE `name` = 'glyph'", [], |row| row.get(0)),
+        Ok(0)
+    );
+}
+
+#[test]
+fn migrate_3() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        AddCharacterFontKey::character().to_string(SqliteQueryBuilder),
+        "INSERT INTO `character` VALUES (1, 2)".to_owned(),
+    ].join("; ")).unwrap();
+    let migrator = Migrator::new(SqliteQueryBuilder)
+        .migration(CreateFont)
+        .migration(AddCharacterFontKey);
+
+    assert!(migrator.up(&mut RusqliteConnection(&conn), None).is_err());
+    assert_eq!(
+        status(&migrator, &conn),
+        vec![
+            ("m20210601_000001_create_font".to_owned(), true),
+            ("m20210601_000004_add_character_font_key".to_owned(), false),
+        ]
+    );
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `pragma_foreign_key_list`('character')", [], |row| row.get(0)),
+        Ok(0)
+    );
+
+    conn.execute_batch("UPDATE `character` SET `font_id` = (SELECT `id` FROM `font`)").unwrap();
+    assert_eq!(
+        migrator.up(&mut RusqliteConnection(&conn), None).unwrap(),
+        vec!["m20210601_000004_add_character_font_key"]
+    );
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `pragma_foreign_key_list`('character')", [], |row| row.get(0)),
+        Ok(1)
+    );
+    assert_eq!(
+        conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0)),
+        Ok(1)
+    );
+}
+
+#[test]
+fn migrate_4() {
+    let mut conn = FailingConnection { executed: Vec::new() };
+    let migrator = Migrator::new(SqliteQueryBuilder).migration(CreateFont);
+
+    assert!(migrator.status(&mut conn).is_ok());
+    assert!(conn.executed.is_empty());
+
+    assert_eq!(
+        migrator.up(&mut conn, None),
+        Err("cannot execute CREATE TABLE `font` ( `id` integer NOT NULL PRIMARY KEY AUTOINCREMENT, `name` text NOT NULL )".to_owned())
+    );
+    assert_eq!(conn.executed.last().map(String::as_str), Some("ROLLBACK"));
+}
@@ -3,5 +3,9 @@ mod table;
 mod online;
 mod index;
 mod foreign_key;
+mod discovery;
+mod schema;
+#[cfg(feature = "rusqlite")]
+mod migration;
 
 use sea_query_test::*;
\ No newline at end of file
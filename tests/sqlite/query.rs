@@ -283,7 +283,7 @@ fn select_18() {
             .and_where(Expr::col(Glyph::Aspect).between(3, 5))
             .and_where(Expr::col(Glyph::Aspect).not_between(8, 10))
             .to_string(SqliteQueryBuilder),
-        "SELECT `aspect` FROM `glyph` WHERE (`aspect` BETWEEN 3 AND 5) AND (`aspect` NOT BETWEEN 8 AND 10)"
+        "SELECT `aspect` FROM `glyph` WHERE `aspect` BETWEEN 3 AND 5 AND `aspect` NOT BETWEEN 8 AND 10"
     );
 }
 
@@ -339,7 +339,7 @@ fn select_22() {
             .or_where(Expr::col(Char::Character).like("D").and(Expr::col(Char::Character).like("E")))
             .and_where(Expr::col(Char::Character).like("F").or(Expr::col(Char::Character).like("G")))
             .to_string(SqliteQueryBuilder),
-        "SELECT `character` FROM `character` WHERE `character` LIKE 'C' OR ((`character` LIKE 'D') AND (`character` LIKE 'E')) AND ((`character` LIKE 'F') OR (`character` LIKE 'G'))"
+        "SELECT `character` FROM `character` WHERE (`character` LIKE 'C' OR (`character` LIKE 'D') AND (`character` LIKE 'E')) AND ((`character` LIKE 'F') OR (`character` LIKE 'G'))"
     );
 }
 
@@ -443,7 +443,7 @@ fn select_29() {
             .or_where(Expr::col(Char::SizeH).eq(4))
             .and_where(Expr::col(Char::SizeH).eq(5))
             .to_string(SqliteQueryBuilder),
-        "SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` = 3 OR `size_h` = 4 AND `size_h` = 5"
+        "SELECT `character`, `size_w`, `size_h` FROM `character` WHERE (`size_w` = 3 OR `size_h` = 4) AND `size_h` = 5"
     );
 }
 
@@ -521,8 +521,7 @@ fn select_34() {
             .to_string(SqliteQueryBuilder),
         vec![
             "SELECT `aspect`, MAX(`image`) FROM `glyph` GROUP BY `aspect`",
-            "HAVING ((`aspect` > 2) OR (`aspect` < 8))",
-                "OR ((`aspect` > 12) AND (`aspect` < 18))",
+            "HAVING ((`aspect` > 2) OR (`aspect` < 8) OR (`aspect` > 12) AND (`aspect` < 18))",
                 "AND ((`aspect` > 22) OR (`aspect` < 28))",
                 "OR `aspect` > 32",
         ].join(" ")
@@ -543,6 +542,775 @@ fn select_35() {
     assert_eq!(values.0, vec![]);
 }
 
+#[test]
+fn select_36() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(
+            Expr::case(Expr::col(Glyph::Aspect).gt(2), "wide")
+                .case(Expr::col(Glyph::Aspect).is_null(), Expr::col(Glyph::Image))
+        )
+        .from(Glyph::Table)
+        .order_by_expr(
+            Expr::case(Expr::col(Glyph::Image).is_null(), 1).finally(0).into(),
+            Order::Desc
+        )
+        .build(SqliteQueryBuilder);
+
+    assert_eq!(statement, "SELECT `id`, CASE WHEN `aspect` > ? THEN ? WHEN `aspect` IS NULL THEN `image` END FROM `glyph` ORDER BY CASE WHEN `image` IS NULL THEN ? ELSE ? END DESC");
+    assert_eq!(values.0, vec![2.into(), "wide".into(), 1.into(), 0.into()]);
+}
+
+#[test]
+fn select_37() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .expr(Expr::col(Glyph::Aspect).cast_as(ColumnType::BigInteger(None)))
+        .from(Glyph::Table)
+        .and_where(Expr::expr(Expr::col(Glyph::Image).cast_as(ColumnType::String(Some(10)))).eq("24B0E11951B03B07F8300FD003983F03F0780060"))
+        .and_where(Expr::expr(Expr::col(Glyph::Aspect).cast_as(ColumnType::Decimal(Some((8, 2))))).lt(2.5))
+        .build(SqliteQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT `id`, CAST(`aspect` AS INTEGER) FROM `glyph` WHERE CAST(`image` AS TEXT) = ? AND CAST(`aspect` AS NUMERIC) < ?"#);
+    assert_eq!(values.0, vec!["24B0E11951B03B07F8300FD003983F03F0780060".into(), 2.5.into()]);
+}
+
+#[test]
+fn select_38() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .cond_where(
+            Cond::all()
+                .add(Expr::col(Glyph::Image).is_not_null())
+                .add(
+                    Cond::any()
+                        .add(Expr::col(Glyph::Aspect).lt(1))
+                        .add(Expr::col(Glyph::Aspect).gt(10))
+                )
+                .add(
+                    Cond::all()
+                        .add(Expr::col(Glyph::Aspect).eq(5))
+                        .add(Expr::col(Glyph::Image).like("A%"))
+                        .not()
+                )
+        )
+        .build(SqliteQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT `id` FROM `glyph` WHERE `image` IS NOT NULL AND (`aspect` < ? OR `aspect` > ?) AND NOT (`aspect` = ? AND `image` LIKE ?)"#);
+    assert_eq!(values.0, vec![1.into(), 10.into(), 5.into(), "A%".into()]);
+}
+
+#[test]
+fn select_39() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .column((Font::Table, Font::Name))
+            .from(Char::Table)
+            .left_join(
+                Font::Table,
+                Cond::all()
+                    .add(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+                    .add(
+                        Cond::any()
+                            .add(Expr::tbl(Font::Table, Font::Name).like("A%"))
+                            .add(Expr::tbl(Font::Table, Font::Name).is_null())
+                    )
+            )
+            .group_by_columns(vec![
+                Char::Character,
+            ])
+            .cond_having(
+                Cond::any()
+                    .add(Cond::all().add(Expr::col(Char::SizeW).gt(2)).not())
+                    .add(
+                        Cond::all()
+                            .add(Expr::col(Char::SizeH).eq(1))
+                            .add(Expr::col(Char::SizeW).eq(1))
+                    )
+                    .add(Cond::any())
+            )
+            .to_string(SqliteQueryBuilder),
+        vec![
+            r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id` AND (`font`.`name` LIKE 'A%' OR `font`.`name` IS NULL)"#,
+            r#"GROUP BY `character` HAVING NOT (`size_w` > 2) OR `size_h` = 1 AND `size_w` = 1 OR 1 = 2"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+fn select_40() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .or_where(Expr::col(Glyph::Aspect).lt(1))
+            .or_where(Expr::col(Glyph::Aspect).gt(10))
+            .cond_where(Expr::col(Glyph::Image).like("A%"))
+            .and_where(Expr::col(Glyph::Id).ne(5))
+            .to_string(SqliteQueryBuilder),
+        "SELECT `id` FROM `glyph` WHERE (`aspect` < 1 OR `aspect` > 10) AND `image` LIKE 'A%' AND `id` <> 5"
+    );
+}
+
+#[test]
+fn select_41() {
+    let (statement, values) = Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .and_where(Expr::exists(
+            Query::select()
+                .column(Font::Id)
+                .from(Font::Table)
+                .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+                .take()
+        ))
+        .and_where(Expr::not_exists(
+            Query::select()
+                .column(Glyph::Id)
+                .from(Glyph::Table)
+                .and_where(Expr::col(Glyph::Aspect).gt(2))
+                .take()
+        ))
+        .and_where(Expr::col(Char::SizeW).gt(
+            Query::select()
+                .expr(Expr::col(Char::SizeW).max())
+                .from(Char::Table)
+                .take()
+        ))
+        .build(SqliteQueryBuilder);
+
+    assert_eq!(
+        statement,
+        vec![
+            r#"SELECT `character` FROM `character` WHERE EXISTS(SELECT `id` FROM `font` WHERE `font`.`id` = `character`.`font_id`)"#,
+            r#"AND NOT EXISTS(SELECT `id` FROM `glyph` WHERE `aspect` > ?)"#,
+            r#"AND `size_w` > (SELECT MAX(`size_w`) FROM `character`)"#,
+        ].join(" ")
+    );
+    assert_eq!(values.0, vec![2.into()]);
+}
+
+#[test]
+fn select_42() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(Expr::some(
+                Query::select().column(Font::Id).from(Font::Table).take()
+            )))
+            .and_where(Expr::col(Char::SizeW).ne(Expr::all(
+                Query::select().column(Char::SizeH).from(Char::Table).take()
+            )))
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT `character` FROM `character` WHERE `font_id` IN (SELECT `id` FROM `font`) AND `size_w` NOT IN (SELECT `size_h` FROM `character`)"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite only supports `= ANY` and `<> ALL` sub-query comparisons")]
+fn select_43() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .and_where(Expr::col(Char::SizeW).gt(Expr::any(
+            Query::select().column(Char::SizeH).from(Char::Table).take()
+        )))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_44() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Aspect), Expr::col(Glyph::Id)])
+                .gte(Expr::tuple(vec![Expr::val(2.5), Expr::val(10)]))
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)])
+                .in_tuples(vec![(1, "a"), (2, "b")])
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)])
+                .not_in_tuples(Vec::<(i32, &str)>::new())
+        )
+        .build(SqliteQueryBuilder);
+
+    assert_eq!(statement, r#"SELECT `id` FROM `glyph` WHERE (`aspect`, `id`) >= (?, ?) AND (`id`, `image`) IN (VALUES (?, ?), (?, ?)) AND ? = ?"#);
+    assert_eq!(values.0, vec![2.5.into(), 10.into(), 1.into(), "a".into(), 2.into(), "b".into(), 1.into(), 1.into()]);
+}
+
+#[test]
+fn select_45() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Image).is_not_null())
+            .keyset_paginate(
+                vec![(Glyph::Aspect, Order::Desc), (Glyph::Image, Order::Asc), (Glyph::Id, Order::Asc)],
+                Some(vec![2.5.into(), "x".into(), 7.into()])
+            )
+            .limit(10)
+            .to_string(SqliteQueryBuilder),
+        vec![
+            r#"SELECT `id` FROM `glyph` WHERE `image` IS NOT NULL"#,
+            r#"AND ((`aspect` < 2.5) OR ((`aspect` = 2.5) AND ((`image` > 'x') OR ((`image` = 'x') AND (`id` > 7)))))"#,
+            r#"ORDER BY `aspect` DESC, `image` ASC, `id` ASC LIMIT 10"#,
+        ].join(" ")
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .keyset_paginate(vec![(Glyph::Id, Order::Desc)], None)
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT `id` FROM `glyph` ORDER BY `id` DESC"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .keyset_paginate(Vec::<(Glyph, Order)>::new(), Some(vec![]))
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT `id` FROM `glyph`"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .cond_where(Cond::any().add(Expr::col(Glyph::Image).is_null()).add(Expr::col(Glyph::Aspect).gt(1)))
+            .keyset_paginate(vec![(Glyph::Id, Order::Asc)], Some(vec![7.into()]))
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT `id` FROM `glyph` WHERE (`image` IS NULL OR `aspect` > 1) AND `id` > 7 ORDER BY `id` ASC"#
+    );
+}
+
+#[test]
+fn select_46() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Glyph::Aspect).integer().not_null())
+        .col(ColumnDef::new(Glyph::Image).text())
+        .to_string(SqliteQueryBuilder)
+    ).unwrap();
+    let mut insert = Query::insert();
+    insert
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Id, Glyph::Aspect, Glyph::Image]);
+    for id in 1..=7 {
+        insert.values_panic(vec![id.into(), (id % 3).into(), format!("{}", id % 2).into()]);
+    }
+    conn.execute_batch(&insert.to_string(SqliteQueryBuilder)).unwrap();
+
+    let mut cursor: Option<Vec<Value>> = None;
+    let mut ids = Vec::new();
+    loop {
+        let select = Query::select()
+            .columns(vec![Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .from(Glyph::Table)
+            .keyset_paginate(
+                vec![(Glyph::Aspect, Order::Desc), (Glyph::Image, Order::Asc), (Glyph::Id, Order::Asc)],
+                cursor.take()
+            )
+            .limit(2)
+            .to_string(SqliteQueryBuilder);
+        let mut stmt = conn.prepare(&select).unwrap();
+        let page: Vec<(i32, i32, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect();
+        match page.last() {
+            Some((id, aspect, image)) => cursor = Some(vec![(*aspect).into(), image.as_str().into(), (*id).into()]),
+            None => break,
+        }
+        ids.extend(page.iter().map(|(id, _, _)| *id));
+    }
+    assert_eq!(ids, vec![2, 5, 4, 1, 7, 6, 3]);
+
+    let select = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Aspect), Expr::col(Glyph::Image)])
+                .in_tuples(vec![(1, "1"), (0, "0")])
+        )
+        .and_where(
+            Expr::tuple(vec![Expr::col(Glyph::Aspect), Expr::col(Glyph::Id)])
+                .gt(Expr::tuple(vec![Expr::val(0), Expr::val(3)]))
+        )
+        .order_by(Glyph::Id, Order::Asc)
+        .to_string(SqliteQueryBuilder);
+    let mut stmt = conn.prepare(&select).unwrap();
+    let ids: Vec<i32> = stmt.query_map([], |row| row.get(0)).unwrap().map(|id| id.unwrap()).collect();
+    assert_eq!(ids, vec![1, 6, 7]);
+}
+
+#[test]
+fn select_47() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).gt(1))
+            .order_by(Glyph::Id, Order::Asc)
+            .limit(5)
+            .lock_with_tables_behavior(LockType::NoKeyUpdate, vec![Glyph::Table], LockBehavior::Nowait)
+            .lock_optional()
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT `id` FROM `glyph` WHERE `aspect` > 1 ORDER BY `id` ASC LIMIT 5"#
+    );
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .lock(LockType::KeyShare)
+            .lock_optional()
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT `character` FROM `character`"#
+    );
+}
+
+#[test]
+fn select_48() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Glyph::Image).text())
+            .to_string(SqliteQueryBuilder),
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::Character).text())
+            .to_string(SqliteQueryBuilder),
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Name).text())
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id, Glyph::Image])
+            .values_panic(vec![1.into(), "a.png".into()])
+            .values_panic(vec![2.into(), "b.png".into()])
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Char::Table)
+            .columns(vec![Char::Id, Char::Character])
+            .values_panic(vec![1.into(), "a".into()])
+            .values_panic(vec![3.into(), "c".into()])
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Font::Table)
+            .columns(vec![Font::Name])
+            .values_panic(vec!["Arial".into()])
+            .values_panic(vec!["Courier".into()])
+            .to_string(SqliteQueryBuilder),
+    ].join("; ")).unwrap();
+
+    let select = Query::select()
+        .column(Char::Character)
+        .column(Glyph::Image)
+        .column(Font::Name)
+        .from(Char::Table)
+        .join_using(JoinType::LeftJoin, Glyph::Table, vec![Char::Id])
+        .cross_join(Font::Table)
+        .order_by(Char::Character, Order::Asc)
+        .order_by(Font::Name, Order::Asc)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        vec![
+            "SELECT `character`, `image`, `name` FROM `character`",
+            "LEFT JOIN `glyph` USING (`id`)",
+            "CROSS JOIN `font`",
+            "ORDER BY `character` ASC, `name` ASC",
+        ].join(" ")
+    );
+    let mut stmt = conn.prepare(&select).unwrap();
+    let rows: Vec<(String, Option<String>, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .unwrap()
+        .map(|row| row.unwrap())
+        .collect();
+    assert_eq!(rows, vec![
+        ("a".to_owned(), Some("a.png".to_owned()), "Arial".to_owned()),
+        ("a".to_owned(), Some("a.png".to_owned()), "Courier".to_owned()),
+        ("c".to_owned(), None, "Arial".to_owned()),
+        ("c".to_owned(), None, "Courier".to_owned()),
+    ]);
+
+    let natural = Query::select()
+        .column(Glyph::Image)
+        .from(Char::Table)
+        .natural_join(JoinType::Join, Glyph::Table)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(natural, "SELECT `image` FROM `character` NATURAL JOIN `glyph`");
+    assert_eq!(conn.query_row(&natural, [], |row| row.get::<_, String>(0)), Ok("a.png".to_owned()));
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support FULL OUTER JOIN before 3.39")]
+fn select_49() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .join(JoinType::FullOuterJoin, Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support LATERAL joins")]
+fn select_50() {
+    Query::select()
+        .column(Font::Name)
+        .from(Font::Table)
+        .join_lateral(
+            JoinType::Join,
+            Query::select().column(Char::Character).from(Char::Table).take(),
+            Alias::new("c"),
+            Cond::all()
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support ROLLUP, CUBE and GROUPING SETS")]
+fn select_51() {
+    Query::select()
+        .column(Char::FontId)
+        .expr(Func::count(Expr::col(Char::Id)))
+        .from(Char::Table)
+        .group_by_rollup(vec![Char::FontId])
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support DISTINCT ON")]
+fn select_52() {
+    Query::select()
+        .distinct_on(vec![Char::FontId])
+        .column(Char::Character)
+        .from(Char::Table)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_53() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Alias::new("metadata")).text())
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id.into_iden(), Alias::new("metadata").into_iden()])
+            .values_panic(vec![1.into(), r#"{"font": "Arial", "tags": ["bold"]}"#.into()])
+            .values_panic(vec![2.into(), r#"{"font": "Arial", "tags": ["italic"]}"#.into()])
+            .to_string(SqliteQueryBuilder),
+    ].join("; ")).unwrap();
+
+    let metadata = || Expr::col(Alias::new("metadata"));
+    let select = Query::select()
+        .expr(Func::json_build_object(vec![
+            ("font", metadata().cast_json_field("font")),
+            ("ids", Func::json_agg(Expr::col(Glyph::Id))),
+        ]))
+        .from(Glyph::Table)
+        .and_where(Expr::expr(metadata().get_json_path(vec![Value::from("tags"), 0.into()])).ne("underline"))
+        .add_group_by(vec![metadata().get_json_field("font")])
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        vec![
+            "SELECT json_object('font', json_extract(`metadata`, '$.font'), 'ids', json_group_array(`id`))",
+            "FROM `glyph`",
+            "WHERE json_extract(`metadata`, '$.tags[0]') <> 'underline'",
+            "GROUP BY json_extract(`metadata`, '$.font')",
+        ].join(" ")
+    );
+    let object: String = conn.query_row(&select, [], |row| row.get(0)).unwrap();
+    assert_eq!(object, r#"{"font":"Arial","ids":[1,2]}"#);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support JSON containment and key existence operators")]
+fn select_54() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(Expr::col(Alias::new("metadata")).json_has_key("font"))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support full-text search on multiple columns, search the FTS5 table instead")]
+fn select_55() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Expr::tuple(vec![Expr::col(Char::Character), Expr::col(Char::FontId)]).matches("sea"))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_56() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Font::Name).text())
+            .col(ColumnDef::new(Font::Variant).text())
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Font::Table)
+            .columns(vec![Font::Id, Font::Name, Font::Variant])
+            .values_panic(vec![1.into(), "A_Sans".into(), "Bold".into()])
+            .values_panic(vec![2.into(), "Arial".into(), Value::Null])
+            .values_panic(vec![3.into(), "a_mono".into(), Value::Null])
+            .to_string(SqliteQueryBuilder),
+    ].join("; ")).unwrap();
+
+    let select = Query::select()
+        .expr(Expr::expr(Expr::col(Font::Name).concat(" ")).concat(Expr::col(Font::Variant)))
+        .expr(Expr::expr(Expr::col(Font::Id).bit_and(3)).left_shift(1))
+        .from(Font::Table)
+        .and_where(Expr::col(Font::Name).ilike(LikeExpr::str("a!_%").escape('!')))
+        .and_where(Expr::col(Font::Variant).is_distinct_from(Expr::val("Italic")))
+        .and_where(Expr::expr(Expr::col(Font::Id).modulo(2)).eq(1))
+        .order_by(Font::Id, Order::Asc)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        vec![
+            "SELECT `name` || ' ' || `variant`, (`id` & 3) << 1 FROM `font`",
+            "WHERE LOWER(`name`) LIKE LOWER('a!_%') ESCAPE '!'",
+            "AND `variant` IS NOT 'Italic'",
+            "AND `id` % 2 = 1",
+            "ORDER BY `id` ASC",
+        ].join(" ")
+    );
+    let rows: Vec<(Option<String>, i64)> = conn.prepare(&select).unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .map(|row| row.unwrap())
+        .collect();
+    assert_eq!(rows, vec![
+        (Some("A_Sans Bold".to_owned()), 2),
+        (None, 6),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support SIMILAR TO")]
+fn select_57() {
+    Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .and_where(Expr::col(Font::Name).similar_to("%(a|b)%"))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_58() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let select = Query::select()
+        .expr(-(Expr::val(1) + 2))
+        .expr(Expr::val(10) - (Expr::val(4) - 1))
+        .expr(Expr::val(2) * (Expr::val(3) + 1) % 5)
+        .expr(!(Expr::val(1).eq(1) & Expr::val(1).eq(2)))
+        .expr(-(-Expr::val(3)))
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        "SELECT - (1 + 2), 10 - (4 - 1), (2 * (3 + 1)) % 5, NOT ((1 = 1) AND (1 = 2)), - - 3"
+    );
+    let row: (i64, i64, i64, bool, i64) = conn
+        .query_row(&select, [], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)))
+        .unwrap();
+    assert_eq!(row, (-3, 7, 3, true, 3));
+}
+
+#[test]
+fn select_59() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let created_at = || Expr::val("2021-06-15 13:45:30");
+    let select = Query::select()
+        .expr(Func::concat(vec![Func::upper(Expr::val("sea")), Expr::val("-").into_simple_expr(), Func::substring(Expr::val("xquery"), 2, Some(5))]))
+        .expr(Func::greatest(vec![Expr::val(1), Expr::val(3), Expr::val(2)]))
+        .expr(Func::least(vec![Expr::val(1), Expr::val(3), Expr::val(2)]))
+        .expr(Func::round(Expr::val(2.567), Some(2)))
+        .expr(Func::date_trunc(DateTimeField::Hour, created_at()))
+        .expr(Func::extract(DateTimeField::Month, created_at()))
+        .expr(Expr::expr(Func::random()).between(0, 1))
+        .expr(Expr::expr(Func::coalesce(vec![SimpleExpr::Value(Value::Null), Func::now()])).gt(Func::current_date()))
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        vec![
+            "SELECT (UPPER('sea') || '-' || SUBSTR('xquery', 2, 5)), MAX(1, 3, 2), MIN(1, 3, 2), ROUND(2.567, 2),",
+            "strftime('%Y-%m-%d %H:00:00', '2021-06-15 13:45:30'), CAST(strftime('%m', '2021-06-15 13:45:30') AS INTEGER),",
+            "ABS(RANDOM() / 9223372036854775808.0) BETWEEN 0 AND 1,",
+            "COALESCE(NULL, CURRENT_TIMESTAMP) > CURRENT_DATE",
+        ].join(" ")
+    );
+    let row: (String, i64, i64, f64, String, i64, bool, bool) = conn
+        .query_row(&select, [], |row| Ok((
+            row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?, row.get(5)?, row.get(6)?, row.get(7)?,
+        )))
+        .unwrap();
+    assert_eq!(row, (
+        "SEA-query".to_owned(), 3, 1, 2.57, "2021-06-15 13:00:00".to_owned(), 6, true, true,
+    ));
+}
+
+#[test]
+fn select_60() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        "CREATE TABLE `character` (`id` integer, `character` text, `size_w` integer, `font_id` integer);",
+        "INSERT INTO `character` VALUES (1, 'a', 5, 1), (2, 'b', 20, 1), (3, 'a', 30, 1), (4, 'c', 40, 2);",
+    ].join(" ")).unwrap();
+    let select = Query::select()
+        .column(Char::FontId)
+        .expr(Func::count(Expr::col(Char::Character)).distinct())
+        .expr(Func::count(Expr::cust("*")).filter(Expr::col(Char::SizeW).gt(10)))
+        .expr(Func::sum(Expr::col(Char::SizeW)).filter(Expr::col(Char::Character).eq("a")))
+        .expr(Func::string_agg(Expr::col(Char::Character), "|"))
+        .from(Char::Table)
+        .group_by_columns(vec![Char::FontId])
+        .order_by(Char::FontId, Order::Asc)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        vec![
+            "SELECT `font_id`, COUNT(DISTINCT `character`), COUNT(*) FILTER (WHERE `size_w` > 10),",
+            "SUM(`size_w`) FILTER (WHERE `character` = 'a'), GROUP_CONCAT(`character`, '|')",
+            "FROM `character` GROUP BY `font_id` ORDER BY `font_id` ASC",
+        ].join(" ")
+    );
+    let mut stmt = conn.prepare(&select).unwrap();
+    let rows: Vec<(i64, i64, i64, Option<i64>, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)))
+        .unwrap()
+        .map(|row| row.unwrap())
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!((rows[0].0, rows[0].1, rows[0].2, rows[0].3), (1, 2, 2, Some(35)));
+    assert_eq!(rows[0].4.split('|').count(), 3);
+    assert_eq!(rows[1], (2, 1, 1, None, "c".to_owned()));
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support ORDER BY in aggregate functions before 3.44")]
+fn select_61() {
+    Query::select()
+        .expr(Func::group_concat(Expr::col(Char::Character)).order_by(Char::Id, Order::Asc))
+        .from(Char::Table)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_62() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        "CREATE TABLE `font` (`id` integer, `name` text, `variant` text, `language` text);",
+        "INSERT INTO `font` VALUES (1, 'b', 'Regular', NULL), (2, 'B', 'Bold', 'en'), (3, 'a', 'Italic', 'en'), (4, 'c', 'Bold', NULL);",
+    ].join(" ")).unwrap();
+    let select = Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .add_order_by(OrderExpr::col(Font::Language, Order::Desc).nulls(NullOrdering::First))
+        .add_order_by(OrderExpr::col(Font::Variant, Order::Asc).field(vec!["Regular".into(), "Bold".into()]))
+        .add_order_by(OrderExpr::col(Font::Name, Order::Asc).collate(Alias::new("NOCASE")))
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        vec![
+            "SELECT `id` FROM `font` ORDER BY `language` DESC NULLS FIRST,",
+            "CASE WHEN `variant` = 'Regular' THEN 1 WHEN `variant` = 'Bold' THEN 2 ELSE 0 END ASC,",
+            "`name` COLLATE `NOCASE` ASC",
+        ].join(" ")
+    );
+    let mut stmt = conn.prepare(&select).unwrap();
+    let ids: Vec<i64> = stmt
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(|id| id.unwrap())
+        .collect();
+    assert_eq!(ids, vec![1, 4, 3, 2]);
+}
+
+#[test]
+fn select_63() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let select = Query::select()
+        .expr(cust_with_exprs!("printf('%d-%s', $1, $2)", Expr::val(6).mul(7), Expr::val("$1")))
+        .expr(cust_with_exprs!("IIF($1, '$2', $1)", Expr::val(0)))
+        .expr(cust_with_exprs!("$1 IS NULL", Expr::val(1).not()))
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(select, "SELECT printf('%d-%s', (6 * 7), '$1'), IIF(0, '$2', 0), (NOT 1) IS NULL");
+    let row: (String, i64, bool) = conn.query_row(&select, [], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?))).unwrap();
+    assert_eq!(row, ("42-$1".to_owned(), 0, false));
+}
+
+#[test]
+fn select_64() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::col(Char::SizeW) - (Expr::col(Char::SizeH) + Expr::col(Char::FontSize) + 1))
+            .from(Char::Table)
+            .and_where((Expr::col(Char::FontId) & (Expr::col(Char::SizeW).eq(1) & Expr::col(Char::SizeH).eq(2) & Expr::col(Char::Id).eq(3))).into())
+            .and_where((Expr::col(Char::FontId) | (Expr::col(Char::SizeW).eq(1) | Expr::col(Char::SizeH).eq(2))).into())
+            .to_string(SqliteQueryBuilder),
+        vec![
+            r#"SELECT `size_w` - (`size_h` + `font_size` + 1) FROM `character`"#,
+            r#"WHERE `font_id` AND ((`size_w` = 1) AND (`size_h` = 2) AND (`id` = 3))"#,
+            r#"AND (`font_id` OR ((`size_w` = 1) OR (`size_h` = 2)))"#,
+        ].join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support RIGHT JOIN before 3.39")]
+fn select_65() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .join(JoinType::RightJoin, Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support row locking, use `lock_optional` to omit the lock clause")]
+fn select_66() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .lock(LockType::Update)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "GREATEST takes at least two expressions")]
+fn select_67() {
+    Query::select()
+        .expr(Func::greatest(vec![Expr::col(Char::SizeW)]))
+        .from(Char::Table)
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 #[cfg(feature="with-json")]
@@ -627,6 +1395,52 @@ fn insert_5() {
     );
 }
 
+#[test]
+fn insert_6() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .values_panic(vec!["It's a \\n\n".into(), vec![0x00u8, 0x27, 0xFF].into()])
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` (`image`, `aspect`) VALUES ('It''s a \\n\n', X'0027FF')"
+    );
+}
+
+#[test]
+fn insert_7() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Glyph::Image).text())
+        .col(ColumnDef::new(Glyph::Aspect).binary())
+        .to_string(SqliteQueryBuilder)
+    ).unwrap();
+
+    let strings = vec!["", "'", "''", "\\", "\\'", "'; DROP TABLE `glyph`; --", "\"quoted\"", "a\nb\r\tc", "\u{1F600} ü"];
+    for (id, string) in strings.iter().enumerate() {
+        let bytes: Vec<u8> = string.bytes().chain(vec![0x00, 0x5C, 0x27]).collect();
+        conn.execute_batch(&Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id, Glyph::Image, Glyph::Aspect])
+            .values_panic(vec![(id as i32).into(), (*string).into(), bytes.clone().into()])
+            .to_string(SqliteQueryBuilder)
+        ).unwrap();
+
+        let select = Query::select()
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Id).eq(id as i32))
+            .and_where(Expr::col(Glyph::Image).eq(*string))
+            .to_string(SqliteQueryBuilder);
+        assert_eq!(
+            conn.query_row(&select, [], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?))),
+            Ok((string.to_string(), bytes))
+        );
+    }
+}
+
 #[test]
 fn update_1() {
     assert_eq!(
@@ -673,6 +1487,120 @@ fn update_3() {
     );
 }
 
+#[test]
+fn update_4() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![
+                (Glyph::Aspect, 1.into()),
+            ])
+            .or_where(Expr::col(Glyph::Id).eq(1))
+            .or_where(Expr::col(Glyph::Id).eq(2))
+            .and_where(Expr::col(Glyph::Image).is_null())
+            .to_string(SqliteQueryBuilder),
+        r#"UPDATE `glyph` SET `aspect` = 1 WHERE (`id` = 1 OR `id` = 2) AND `image` IS NULL"#
+    );
+}
+
+#[test]
+fn update_5() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&[
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Font::Language).text())
+            .to_string(SqliteQueryBuilder),
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::FontSize).integer())
+            .col(ColumnDef::new(Char::FontId).integer())
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Font::Table)
+            .columns(vec![Font::Id, Font::Language])
+            .values_panic(vec![1.into(), "en".into()])
+            .values_panic(vec![2.into(), "fr".into()])
+            .to_string(SqliteQueryBuilder),
+        Query::insert()
+            .into_table(Char::Table)
+            .columns(vec![Char::Id, Char::FontSize, Char::FontId])
+            .values_panic(vec![1.into(), 10.into(), 1.into()])
+            .values_panic(vec![2.into(), 10.into(), 2.into()])
+            .values_panic(vec![3.into(), 10.into(), 1.into()])
+            .to_string(SqliteQueryBuilder),
+    ].join("; ")).unwrap();
+
+    let update = Query::update()
+        .table(Char::Table)
+        .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+        .value(Char::FontSize, 8.into())
+        .and_where(Expr::tbl(Font::Table, Font::Language).eq("en"))
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        update,
+        vec![
+            "UPDATE `character` SET `font_size` = 8",
+            "FROM `font`",
+            "WHERE `character`.`font_id` = `font`.`id` AND `font`.`language` = 'en'",
+        ].join(" ")
+    );
+    assert_eq!(conn.execute(&update, []).unwrap(), 2);
+
+    let mut stmt = conn.prepare(&Query::select()
+        .column(Char::FontSize)
+        .from(Char::Table)
+        .order_by(Char::Id, Order::Asc)
+        .to_string(SqliteQueryBuilder)
+    ).unwrap();
+    let sizes: Vec<i32> = stmt.query_map([], |row| row.get(0)).unwrap().map(|row| row.unwrap()).collect();
+    assert_eq!(sizes, vec![8, 10, 8]);
+}
+
+#[test]
+fn update_6() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(&Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key().auto_increment())
+        .col(ColumnDef::new(Glyph::Aspect).integer().not_null())
+        .col(ColumnDef::new(Glyph::Image).text())
+        .to_string(SqliteQueryBuilder)
+    ).unwrap();
+
+    let insert = Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Aspect, Glyph::Image])
+        .values_panic(vec![1.into(), "A".into()])
+        .returning_col(Glyph::Id)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(insert, "INSERT INTO `glyph` (`aspect`, `image`) VALUES (1, 'A') RETURNING `id`");
+    assert_eq!(conn.query_row(&insert, [], |row| row.get::<_, i32>(0)), Ok(1));
+    assert_eq!(conn.query_row(&insert, [], |row| row.get::<_, i32>(0)), Ok(2));
+
+    let update = Query::update()
+        .table(Glyph::Table)
+        .value_expr(Glyph::Aspect, Expr::col(Glyph::Aspect).add(1))
+        .and_where(Expr::col(Glyph::Id).eq(2))
+        .returning(Query::select().columns(vec![Glyph::Id, Glyph::Aspect]).take())
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        update,
+        "UPDATE `glyph` SET `aspect` = `aspect` + 1 WHERE `id` = 2 RETURNING `id`, `aspect`"
+    );
+    assert_eq!(conn.query_row(&update, [], |row| Ok((row.get::<_, i32>(0)?, row.get::<_, i32>(1)?))), Ok((2, 2)));
+
+    let delete = Query::delete()
+        .from_table(Glyph::Table)
+        .and_where(Expr::col(Glyph::Aspect).eq(1))
+        .returning_col(Glyph::Id)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(delete, "DELETE FROM `glyph` WHERE `aspect` = 1 RETURNING `id`");
+    assert_eq!(conn.query_row(&delete, [], |row| row.get::<_, i32>(0)), Ok(1));
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -682,4 +1610,25 @@ fn delete_1() {
             .to_string(SqliteQueryBuilder),
         "DELETE FROM `glyph` WHERE `id` = 1"
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn delete_2() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .cond_where(Cond::all())
+            .cond_where(Cond::all().add(Expr::col(Glyph::Id).gt(10)))
+            .to_string(SqliteQueryBuilder),
+        r#"DELETE FROM `glyph` WHERE `id` > 10"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support joins in delete statements")]
+fn delete_3() {
+    Query::delete()
+        .from_table(Char::Table)
+        .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+        .to_string(SqliteQueryBuilder);
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn diff_1() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .col(ColumnDef::new(Font::Variant).text())
+        .col(ColumnDef::new(Font::Language).text())
+        .index(Index::create().name("idx-font-language").col(Font::Language))
+        .to_owned();
+
+    let statements: Vec<String> = diff_table(&desired, &current)
+        .unwrap()
+        .iter()
+        .map(|stat| stat.to_string(SqliteQueryBuilder))
+        .collect();
+    assert_eq!(
+        statements,
+        vec![
+            "ALTER TABLE `font` ADD COLUMN `variant` text; ALTER TABLE `font` ADD COLUMN `language` text",
+            "CREATE INDEX `idx-font-language` ON `font` (`language`)",
+        ]
+    );
+
+    conn.execute_batch(&current.to_string(SqliteQueryBuilder)).unwrap();
+    conn.execute_batch(&statements.join("; ")).unwrap();
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `pragma_table_info`('font')", [], |row| row.get(0)),
+        Ok(4)
+    );
+}
@@ -74,6 +74,57 @@ fn create_3() {
     );
 }
 
+#[test]
+fn create_4() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let create = Table::create()
+        .table(Alias::new("glyph_fts"))
+        .col(ColumnDef::new(Glyph::Image))
+        .col(ColumnDef::new(Glyph::Id).extra("UNINDEXED".to_owned()))
+        .fts5_option("tokenize", "porter")
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        create,
+        "CREATE VIRTUAL TABLE `glyph_fts` USING fts5(`image`, `id` UNINDEXED, tokenize = 'porter')"
+    );
+    conn.execute_batch(&[
+        create,
+        Query::insert()
+            .into_table(Alias::new("glyph_fts"))
+            .columns(vec![Glyph::Image, Glyph::Id])
+            .values_panic(vec!["running glyphs".into(), 1.into()])
+            .values_panic(vec!["a standing glyph".into(), 2.into()])
+            .to_string(SqliteQueryBuilder),
+    ].join("; ")).unwrap();
+
+    let select = Query::select()
+        .column(Glyph::Id)
+        .from(Alias::new("glyph_fts"))
+        .and_where(Expr::col(Alias::new("glyph_fts")).matches("run"))
+        .order_by(Alias::new("rank"), Order::Asc)
+        .to_string(SqliteQueryBuilder);
+    assert_eq!(
+        select,
+        "SELECT `id` FROM `glyph_fts` WHERE `glyph_fts` MATCH 'run' ORDER BY `rank` ASC"
+    );
+    let ids: Vec<i64> = conn.prepare(&select).unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(|row| row.unwrap())
+        .collect();
+    assert_eq!(ids, vec![1]);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support FTS5 option `tokenize = 'porter', content`")]
+fn create_5() {
+    Table::create()
+        .table(Alias::new("glyph_fts"))
+        .col(ColumnDef::new(Glyph::Image))
+        .fts5_option("tokenize = 'porter', content", "")
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -150,4 +201,247 @@ fn alter_5() {
 #[should_panic(expected = "No alter option found")]
 fn alter_6() {
     Table::alter().to_string(SqliteQueryBuilder);
-}
\ No newline at end of file
+}
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Char::Table)
+            .add_column(ColumnDef::new(Alias::new("size_d")).integer())
+            .rename_column(Char::SizeW, Alias::new("size_width"))
+            .add_index(Index::create().name("idx-character-size_h").col(Char::SizeH))
+            .build_statements(SqliteQueryBuilder),
+        vec![
+            "ALTER TABLE `character` ADD COLUMN `size_d` integer",
+            "ALTER TABLE `character` RENAME COLUMN `size_w` TO `size_width`",
+            "CREATE INDEX `idx-character-size_h` ON `character` (`size_h`)",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite does not support modification of foreign key constraints to existing tables")]
+fn alter_8() {
+    Table::alter()
+        .table(Char::Table)
+        .drop_foreign_key("FK_character_font")
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn alter_9() {
+    assert_eq!(
+        Table::alter()
+            .table(Char::Table)
+            .add_column(ColumnDef::new(Alias::new("size_d")).integer())
+            .rename_column(Char::SizeW, Alias::new("size_width"))
+            .to_string(SqliteQueryBuilder),
+        vec![
+            "ALTER TABLE `character` ADD COLUMN `size_d` integer;",
+            "ALTER TABLE `character` RENAME COLUMN `size_w` TO `size_width`",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn rebuild_1() {
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key().auto_increment())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .col(ColumnDef::new(Font::Variant).text())
+        .to_owned();
+    let indexes = vec![
+        Index::create().name("idx-font-name").table(Font::Table).col(Font::Name).to_owned(),
+    ];
+
+    assert_eq!(
+        SqliteQueryBuilder.rebuild_table_alter(
+            &Table::alter()
+                .table(Font::Table)
+                .modify_column(ColumnDef::new(Font::Variant).text().not_null().default("Regular")),
+            &current,
+            &indexes,
+            &[]
+        ),
+        vec![
+            "PRAGMA legacy_alter_table = ON",
+            "CREATE TABLE `sea_query_new_font` ( `id` integer NOT NULL PRIMARY KEY AUTOINCREMENT, `name` text NOT NULL, `variant` text NOT NULL DEFAULT 'Regular' )",
+            "INSERT INTO `sea_query_new_font` (`id`, `name`, `variant`) SELECT `id`, `name`, `variant` FROM `font`",
+            "DROP TABLE `font`",
+            "ALTER TABLE `sea_query_new_font` RENAME TO `font`",
+            "PRAGMA legacy_alter_table = OFF",
+            "CREATE INDEX `idx-font-name` ON `font` (`name`)",
+            "CREATE TEMP TABLE `sea_query_foreign_key_check` ( `violations` integer CHECK (`violations` = 0) )",
+            "INSERT INTO `sea_query_foreign_key_check` SELECT COUNT(*) FROM `pragma_foreign_key_check`",
+            "DROP TABLE `sea_query_foreign_key_check`",
+        ]
+    );
+
+    assert_eq!(
+        SqliteQueryBuilder.rebuild_table_alter(
+            &Table::alter()
+                .table(Font::Table)
+                .drop_column(Font::Name),
+            &current,
+            &indexes,
+            &[]
+        ),
+        vec![
+            "PRAGMA legacy_alter_table = ON",
+            "CREATE TABLE `sea_query_new_font` ( `id` integer NOT NULL PRIMARY KEY AUTOINCREMENT, `variant` text )",
+            "INSERT INTO `sea_query_new_font` (`id`, `variant`) SELECT `id`, `variant` FROM `font`",
+            "DROP TABLE `font`",
+            "ALTER TABLE `sea_query_new_font` RENAME TO `font`",
+            "PRAGMA legacy_alter_table = OFF",
+            "CREATE TEMP TABLE `sea_query_foreign_key_check` ( `violations` integer CHECK (`violations` = 0) )",
+            "INSERT INTO `sea_query_foreign_key_check` SELECT COUNT(*) FROM `pragma_foreign_key_check`",
+            "DROP TABLE `sea_query_foreign_key_check`",
+        ]
+    );
+
+    assert_eq!(
+        SqliteQueryBuilder.rebuild_table_alter(
+            &Table::alter()
+                .table(Font::Table)
+                .rename_column(Font::Variant, Font::Language),
+            &current,
+            &indexes,
+            &[]
+        ),
+        vec!["ALTER TABLE `font` RENAME COLUMN `variant` TO `language`"]
+    );
+}
+
+#[test]
+fn rebuild_2() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let font = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .to_owned();
+    let character = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .col(ColumnDef::new(Char::Character).string().not_null())
+        .to_owned();
+    let indexes = vec![
+        Index::create().name("idx-character-font_id").table(Char::Table).col(Char::FontId).to_owned(),
+    ];
+    conn.execute_batch(&[
+        font.to_string(SqliteQueryBuilder),
+        character.to_string(SqliteQueryBuilder),
+        indexes[0].to_string(SqliteQueryBuilder),
+        "INSERT INTO `font` VALUES (1, 'Arial')".to_owned(),
+        "INSERT INTO `character` VALUES (1, 1, 'A'), (2, 1, 'B')".to_owned(),
+    ].join("; ")).unwrap();
+
+    let statements = SqliteQueryBuilder.rebuild_foreign_key_create(
+        &ForeignKey::create()
+            .name("FK_character_font")
+            .from(Char::Table, Char::FontId)
+            .to(Font::Table, Font::Id)
+            .on_delete(ForeignKeyAction::Cascade),
+        &character,
+        &indexes,
+        &[]
+    );
+    assert_eq!(
+        statements[1],
+        vec![
+            "CREATE TABLE `sea_query_new_character` (",
+                "`id` integer NOT NULL PRIMARY KEY,",
+                "`font_id` integer,",
+                "`character` text NOT NULL,",
+                "FOREIGN KEY (`font_id`) REFERENCES `font` (`id`) ON DELETE CASCADE",
+            ")",
+        ].join(" ")
+    );
+    conn.execute_batch(&format!("PRAGMA foreign_keys = OFF; BEGIN; {}; COMMIT; PRAGMA foreign_keys = ON", statements.join("; "))).unwrap();
+
+    conn.execute_batch("DELETE FROM `font` WHERE `id` = 1").unwrap();
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `character`", [], |row| row.get(0)),
+        Ok(0)
+    );
+    assert_eq!(
+        conn.query_row("SELECT COUNT(*) FROM `sqlite_master` WHERE `name` = 'idx-character-font_id'", [], |row| row.get(0)),
+        Ok(1)
+    );
+}
+
+#[test]
+fn rebuild_3() {
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .col(ColumnDef::new(Font::Variant).text())
+        .to_owned();
+    let indexes = vec![
+        Index::create().name("idx-font-name").table(Font::Table).col(Font::Name).to_owned(),
+    ];
+
+    assert_eq!(
+        SqliteQueryBuilder.rebuild_table_alter(
+            &Table::alter()
+                .table(Font::Table)
+                .rename_column(Font::Name, Alias::new("title"))
+                .drop_column(Font::Variant)
+                .add_column(ColumnDef::new(Font::Language).text())
+                .add_index(Index::create().name("idx-font-language").col(Font::Language)),
+            &current,
+            &indexes,
+            &[]
+        ),
+        vec![
+            "ALTER TABLE `font` RENAME COLUMN `name` TO `title`",
+            "PRAGMA legacy_alter_table = ON",
+            "CREATE TABLE `sea_query_new_font` ( `id` integer NOT NULL PRIMARY KEY, `title` text NOT NULL, `language` text )",
+            "INSERT INTO `sea_query_new_font` (`id`, `title`) SELECT `id`, `title` FROM `font`",
+            "DROP TABLE `font`",
+            "ALTER TABLE `sea_query_new_font` RENAME TO `font`",
+            "PRAGMA legacy_alter_table = OFF",
+            "CREATE INDEX `idx-font-name` ON `font` (`title`)",
+            "CREATE INDEX `idx-font-language` ON `font` (`language`)",
+            "CREATE TEMP TABLE `sea_query_foreign_key_check` ( `violations` integer CHECK (`violations` = 0) )",
+            "INSERT INTO `sea_query_foreign_key_check` SELECT COUNT(*) FROM `pragma_foreign_key_check`",
+            "DROP TABLE `sea_query_foreign_key_check`",
+        ]
+    );
+}
+
+#[test]
+fn rebuild_4() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let font = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .col(ColumnDef::new(Font::Variant).text())
+        .to_owned();
+    let trigger = "CREATE TRIGGER `font_name` AFTER INSERT ON `font` BEGIN UPDATE `font` SET `name` = upper(`name`) WHERE `id` = NEW.`id`; END";
+    conn.execute_batch(&[
+        font.to_string(SqliteQueryBuilder).as_str(),
+        "CREATE VIEW `font_names` AS SELECT `name` FROM `font`",
+        trigger,
+        "INSERT INTO `font` VALUES (1, 'Arial', 'Bold')",
+    ].join("; ")).unwrap();
+
+    let statements = SqliteQueryBuilder.rebuild_table_alter(
+        &Table::alter()
+            .table(Font::Table)
+            .drop_column(Font::Variant),
+        &font,
+        &[],
+        &[trigger.to_owned()]
+    );
+    conn.execute_batch(&format!("BEGIN; {}; COMMIT", statements.join("; "))).unwrap();
+
+    conn.execute_batch("INSERT INTO `font` VALUES (2, 'Courier')").unwrap();
+    let mut stmt = conn.prepare("SELECT `name` FROM `font_names` ORDER BY `name`").unwrap();
+    let names: Vec<String> = stmt.query_map([], |row| row.get(0)).unwrap().map(|name| name.unwrap()).collect();
+    assert_eq!(names, vec!["ARIAL", "COURIER"]);
+}